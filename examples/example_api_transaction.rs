@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use symbol_sdk::account::Account;
+use symbol_sdk::message::PlainMessage;
+use symbol_sdk::mosaic::{Mosaic, MosaicId};
+use symbol_sdk::network::FeeStrategy;
+use symbol_sdk::transaction::{
+    AggregateTransaction, FeeCalculator, HashLockTransaction, Transaction, TransferTransaction,
+};
+use symbol_sdk::{Client, Retry, Uint64};
+
+#[tokio::main]
+async fn main() {
+    let client = Client::from_url(
+        "http://ngl-dual-101.testnet.symboldev.network:3000",
+        Retry::default(),
+    )
+    .await
+    .unwrap();
+
+    let private_key: &str = "75027D85CE92E2C469297F4C91E4E88AE03868A91B23C835AEF7C5EFDAD0DBDB";
+    let account = Account::from_hex_private_key(private_key, client.network_type).unwrap();
+    let recipient = Account::random(client.network_type);
+
+    let transaction_fees = client
+        .network_routes()
+        .get_transaction_fees()
+        .await
+        .unwrap();
+    let fee_calculator = FeeCalculator::create(&transaction_fees, FeeStrategy::Average);
+
+    let network_configuration = client
+        .network_routes()
+        .get_network_properties()
+        .await
+        .unwrap();
+    let epoch_adjustment = network_configuration
+        .network
+        .get_epoch_adjustment()
+        .unwrap();
+    let deadline_builder = client
+        .node_routes()
+        .get_deadline_builder(epoch_adjustment)
+        .await
+        .unwrap();
+
+    let deadline = deadline_builder.build(chrono::Duration::hours(2)).unwrap();
+
+    let transfer_transaction = TransferTransaction::create(
+        deadline.clone(),
+        recipient.public_account.address,
+        vec![],
+        PlainMessage::create("Symbol is wonderful"),
+        client.network_type,
+        None,
+    )
+    .unwrap();
+
+    let mut aggregate_transaction = AggregateTransaction::create_bonded(
+        deadline.clone(),
+        vec![transfer_transaction.to_aggregate(recipient.public_account)],
+        vec![],
+        client.network_type,
+        None,
+    )
+    .unwrap();
+    // The recipient cosigns the aggregate bonded transaction once announced.
+    fee_calculator
+        .set_max_fee(&mut aggregate_transaction, 1)
+        .unwrap();
+
+    let signed_aggregate_transaction = account
+        .sign_transaction(aggregate_transaction.to_bytes(), client.generation_hash)
+        .unwrap();
+
+    let mut hash_lock_transaction = HashLockTransaction::create(
+        deadline,
+        Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), 10_000_000).unwrap(),
+        Uint64::from(480),
+        &signed_aggregate_transaction,
+        client.network_type,
+        None,
+    )
+    .unwrap();
+    fee_calculator
+        .set_max_fee(&mut hash_lock_transaction, 0)
+        .unwrap();
+
+    let signed_hash_lock_transaction = account
+        .sign_transaction(hash_lock_transaction.to_bytes(), client.generation_hash)
+        .unwrap();
+
+    match client
+        .transaction_routes()
+        .announce_hash_lock_aggregate_bonded(
+            &signed_hash_lock_transaction,
+            &signed_aggregate_transaction,
+            Duration::from_secs(120),
+        )
+        .await
+    {
+        Ok(response) => {
+            println!("{}", response)
+        }
+        Err(err) => {
+            println!("{}", err)
+        }
+    };
+
+    match client
+        .transaction_status_routes()
+        .get_transaction_status(signed_aggregate_transaction.hash)
+        .await
+    {
+        Ok(status) => {
+            println!("{}", status)
+        }
+        Err(err) => {
+            println!("{}", err)
+        }
+    };
+}
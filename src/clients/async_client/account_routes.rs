@@ -0,0 +1,152 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::account::{AccountInfo, Address, PublicAccount};
+use crate::blockchain::MerkleStateInfo;
+use crate::clients::request::Request;
+use crate::model_dto::{AccountInfoDto, AccountPageDto, MerkleStateInfoDto};
+use crate::{AccountSearchCriteria, Client, Error, Response, RetryStrategy};
+
+pub struct AccountApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> AccountApi<R> {
+    /// Gets the AccountInfo for an account.
+    ///
+    /// # Inputs
+    ///
+    /// * `address`: The account address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AccountInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_account_info(&self, address: Address) -> Result<AccountInfo, Error> {
+        let resp: Response<AccountInfoDto> = self
+            .as_ref()
+            .send(Request::get_account(address.address_str()))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the AccountInfo for an account from its public key.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account`: The account public account.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AccountInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_account_info_by_public_key(
+        &self,
+        public_account: &PublicAccount,
+    ) -> Result<AccountInfo, Error> {
+        let resp: Response<AccountInfoDto> = self
+            .as_ref()
+            .send(Request::get_account(public_account.public_key_to_hex()))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the AccountsInfo for an vec of accounts.
+    ///
+    /// # Inputs
+    ///
+    /// * `addresses`: The vec of account addresses.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<AccountInfo>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_accounts_info(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<Vec<AccountInfo>, Error> {
+        let resp: Response<Vec<AccountInfoDto>> = self
+            .as_ref()
+            .send(Request::get_accounts(addresses.into()))
+            .await?;
+
+        let mut accounts = vec![];
+        for account in &*resp {
+            accounts.push(
+                account
+                    .to_compact()
+                    .map_err(|e| Error::unexpected_uncategorized(e.to_string()))?,
+            )
+        }
+        Ok(accounts)
+    }
+
+    /// Gets an vec of `AccountInfo`.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search accounts.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<AccountInfo>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_accounts(
+        &self,
+        criteria: Option<AccountSearchCriteria>,
+    ) -> Result<Vec<AccountInfo>, Error> {
+        let resp: Response<AccountPageDto> = self
+            .as_ref()
+            .send(Request::search_accounts(criteria))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the account merkle state proof.
+    ///
+    /// # Inputs
+    ///
+    /// * `address`: The account address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MerkleStateInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_account_merkle(&self, address: Address) -> Result<MerkleStateInfo, Error> {
+        let resp: Response<MerkleStateInfoDto> = self
+            .as_ref()
+            .send(Request::get_account_merkle(address.address_str()))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for AccountApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AccountIds {
+    /// The vec of account addresses.
+    #[serde(rename = "addresses")]
+    pub addresses: Vec<String>,
+}
+
+impl From<Vec<Address>> for AccountIds {
+    fn from(e: Vec<Address>) -> Self {
+        let ids = e.into_iter().map(|a| a.address_str()).collect();
+        Self { addresses: ids }
+    }
+}
@@ -16,7 +16,10 @@ use serde::Deserialize;
 
 use crate::clients::{model_dto::BlockInfoDto, retry::RetryStrategy, Error, SymbolResponse};
 use crate::network::NetworkType;
-use crate::{BlockApi, ChainApi, GenerationHash, MosaicApi, NetworkApi, NodeApi};
+use crate::{
+    AccountApi, BlockApi, ChainApi, GenerationHash, MetadataApi, MosaicApi, MultisigApi,
+    NamespaceApi, NetworkApi, NodeApi, ReceiptApi, TransactionApi, TransactionStatusApi,
+};
 
 use super::{request::Request, HttpClient, Response, SimpleHttpClient};
 
@@ -129,8 +132,8 @@ impl<R: RetryStrategy> Client<R> {
     }
 
     /// Symbol client namespace routes api.
-    pub fn namespace_routes(&self) {
-        todo!()
+    pub fn namespace_routes(&self) -> NamespaceApi<R> {
+        NamespaceApi(self.clone())
     }
 
     /// Symbol client mosaic routes api.
@@ -139,8 +142,8 @@ impl<R: RetryStrategy> Client<R> {
     }
 
     /// Symbol client account routes api.
-    pub fn account_routes(&self) {
-        todo!()
+    pub fn account_routes(&self) -> AccountApi<R> {
+        AccountApi(self.clone())
     }
 
     /// Symbol client finalization routes api.
@@ -154,18 +157,18 @@ impl<R: RetryStrategy> Client<R> {
     }
 
     /// Symbol client metadata routes api.
-    pub fn metadata_routes(&self) {
-        todo!()
+    pub fn metadata_routes(&self) -> MetadataApi<R> {
+        MetadataApi(self.clone())
     }
 
     /// Symbol client multisig routes api.
-    pub fn multisig_routes(&self) {
-        todo!()
+    pub fn multisig_routes(&self) -> MultisigApi<R> {
+        MultisigApi(self.clone())
     }
 
     /// Symbol client receipt routes api.
-    pub fn receipt_routes(&self) {
-        todo!()
+    pub fn receipt_routes(&self) -> ReceiptApi<R> {
+        ReceiptApi(self.clone())
     }
 
     /// Symbol client restriction routes api.
@@ -184,12 +187,12 @@ impl<R: RetryStrategy> Client<R> {
     }
 
     /// Symbol client transaction routes api.
-    pub fn transaction_routes(&self) {
-        todo!()
+    pub fn transaction_routes(&self) -> TransactionApi<R> {
+        TransactionApi(self.clone())
     }
 
     /// Symbol client transaction_status routes api.
-    pub fn transaction_status_routes(&self) {
-        todo!()
+    pub fn transaction_status_routes(&self) -> TransactionStatusApi<R> {
+        TransactionStatusApi(self.clone())
     }
 }
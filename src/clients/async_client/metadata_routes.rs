@@ -0,0 +1,88 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::blockchain::MerkleStateInfo;
+use crate::clients::request::Request;
+use crate::metadata::Metadata;
+use crate::model_dto::{MerkleStateInfoDto, MetadataInfoDto, MetadataPageDto};
+use crate::{Client, Error, MetadataSearchCriteria, Response, RetryStrategy, H256};
+
+pub struct MetadataApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> MetadataApi<R> {
+    /// Gets an vec of metadata.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search metadata.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<Metadata>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_metadata(
+        &self,
+        criteria: Option<MetadataSearchCriteria>,
+    ) -> Result<Vec<Metadata>, Error> {
+        let resp: Response<MetadataPageDto> = self
+            .as_ref()
+            .send(Request::search_metadata(criteria))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the metadata for a given composite hash.
+    ///
+    /// # Inputs
+    ///
+    /// * `composite_hash`: The metadata composite hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Metadata` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_metadata(&self, composite_hash: H256) -> Result<Metadata, Error> {
+        let resp: Response<MetadataInfoDto> = self
+            .as_ref()
+            .send(Request::get_metadata(composite_hash))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the metadata merkle state proof.
+    ///
+    /// # Inputs
+    ///
+    /// * `composite_hash`: The metadata composite hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MerkleStateInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_metadata_merkle(
+        &self,
+        composite_hash: H256,
+    ) -> Result<MerkleStateInfo, Error> {
+        let resp: Response<MerkleStateInfoDto> = self
+            .as_ref()
+            .send(Request::get_metadata_merkle(composite_hash))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for MetadataApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
@@ -8,19 +8,33 @@
  * // except according to those terms.
  */
 
+pub use self::account_routes::*;
 pub use self::block_routes::*;
 pub use self::chain_routes::*;
 pub use self::client::*;
 pub use self::http_client::*;
+pub use self::metadata_routes::*;
 pub use self::mosaic_routes::*;
+pub use self::multisig_routes::*;
+pub use self::namespace_routes::*;
 pub use self::network_routes::*;
 pub use self::node_routes::*;
+pub use self::receipt_routes::*;
+pub use self::transaction_routes::*;
+pub use self::transaction_status_routes::*;
 
+mod account_routes;
 mod block_routes;
 mod chain_routes;
 mod client;
 mod http_client;
+mod metadata_routes;
 mod mosaic_routes;
+mod multisig_routes;
+mod namespace_routes;
 mod network_routes;
 mod node_routes;
+mod receipt_routes;
 pub(crate) mod request;
+mod transaction_routes;
+mod transaction_status_routes;
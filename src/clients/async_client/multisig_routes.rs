@@ -0,0 +1,102 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::collections::BTreeMap;
+
+use crate::account::{Address, MultisigAccountGraphInfo, MultisigAccountInfo};
+use crate::blockchain::MerkleStateInfo;
+use crate::clients::request::Request;
+use crate::model_dto::{MerkleStateInfoDto, MultisigAccountGraphInfoDto, MultisigAccountInfoDto};
+use crate::{Client, Error, Response, RetryStrategy};
+
+pub struct MultisigApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> MultisigApi<R> {
+    /// Gets the multisig account information.
+    ///
+    /// # Inputs
+    ///
+    /// * `address`: The account address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MultisigAccountInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_multisig_account_info(
+        &self,
+        address: Address,
+    ) -> Result<MultisigAccountInfo, Error> {
+        let resp: Response<MultisigAccountInfoDto> = self
+            .as_ref()
+            .send(Request::get_multisig_account_info(address))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the multisig account graph information, the multisig accounts and cosignatories
+    /// of every level the account is involved in.
+    ///
+    /// # Inputs
+    ///
+    /// * `address`: The account address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MultisigAccountGraphInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_multisig_account_graph_info(
+        &self,
+        address: Address,
+    ) -> Result<MultisigAccountGraphInfo, Error> {
+        let resp: Response<Vec<MultisigAccountGraphInfoDto>> = self
+            .as_ref()
+            .send(Request::get_multisig_account_graph_info(address))
+            .await?;
+
+        let mut multisig_entries = BTreeMap::new();
+        for level in &*resp {
+            let (level, entries) = level
+                .to_compact()
+                .map_err(|e| Error::unexpected_uncategorized(e.to_string()))?;
+            multisig_entries.insert(level, entries);
+        }
+        Ok(MultisigAccountGraphInfo { multisig_entries })
+    }
+
+    /// Gets the multisig account merkle state proof.
+    ///
+    /// # Inputs
+    ///
+    /// * `address`: The account address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MerkleStateInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_multisig_account_info_merkle(
+        &self,
+        address: Address,
+    ) -> Result<MerkleStateInfo, Error> {
+        let resp: Response<MerkleStateInfoDto> = self
+            .as_ref()
+            .send(Request::get_multisig_account_info_merkle(address))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for MultisigApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
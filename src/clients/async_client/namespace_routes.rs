@@ -0,0 +1,181 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::account::{AccountNames, Address};
+use crate::blockchain::MerkleStateInfo;
+use crate::clients::request::Request;
+use crate::model_dto::{
+    AccountsNamesDto, MerkleStateInfoDto, MosaicsNamesDto, NamespaceInfoDto, NamespaceNameDto,
+    NamespacePageDto,
+};
+use crate::mosaic::{MosaicId, MosaicNames};
+use crate::namespace::{NamespaceId, NamespaceInfo, NamespaceName};
+use crate::{Client, Error, NamespaceSearchCriteria, Response, RetryStrategy};
+
+pub struct NamespaceApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> NamespaceApi<R> {
+    /// Gets the NamespaceInfo for a given namespaceId.
+    ///
+    /// # Inputs
+    ///
+    /// * `namespace_id`: The namespace id.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `NamespaceInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_namespace(&self, namespace_id: NamespaceId) -> Result<NamespaceInfo, Error> {
+        let resp: Response<NamespaceInfoDto> = self
+            .as_ref()
+            .send(Request::get_namespace(namespace_id))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets an vec of `NamespaceInfo`.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search namespaces.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<NamespaceInfo>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_namespaces(
+        &self,
+        criteria: Option<NamespaceSearchCriteria>,
+    ) -> Result<Vec<NamespaceInfo>, Error> {
+        let resp: Response<NamespacePageDto> = self
+            .as_ref()
+            .send(Request::search_namespaces(criteria))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the names of an vec of namespaces.
+    ///
+    /// # Inputs
+    ///
+    /// * `namespace_ids`: The vec of namespace ids.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<NamespaceName>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_namespaces_names(
+        &self,
+        namespace_ids: Vec<NamespaceId>,
+    ) -> Result<Vec<NamespaceName>, Error> {
+        let resp: Response<Vec<NamespaceNameDto>> = self
+            .as_ref()
+            .send(Request::get_namespaces_names(namespace_ids.into()))
+            .await?;
+
+        let mut names = vec![];
+        for name in &*resp {
+            names.push(
+                name.to_compact()
+                    .map_err(|e| Error::unexpected_uncategorized(e.to_string()))?,
+            )
+        }
+        Ok(names)
+    }
+
+    /// Gets the names of the namespaces linked to an vec of mosaics.
+    ///
+    /// # Inputs
+    ///
+    /// * `mosaic_ids`: The vec of mosaic ids.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<MosaicNames>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_mosaics_names(
+        &self,
+        mosaic_ids: Vec<MosaicId>,
+    ) -> Result<Vec<MosaicNames>, Error> {
+        let resp: Response<MosaicsNamesDto> = self
+            .as_ref()
+            .send(Request::get_mosaics_names(mosaic_ids.into()))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the names of the namespaces linked to an vec of accounts.
+    ///
+    /// # Inputs
+    ///
+    /// * `addresses`: The vec of account addresses.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<AccountNames>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_accounts_names(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<Vec<AccountNames>, Error> {
+        let resp: Response<AccountsNamesDto> = self
+            .as_ref()
+            .send(Request::get_accounts_names(addresses.into()))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets the namespace merkle state proof.
+    ///
+    /// # Inputs
+    ///
+    /// * `namespace_id`: The namespace id.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MerkleStateInfo` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_namespace_merkle(
+        &self,
+        namespace_id: NamespaceId,
+    ) -> Result<MerkleStateInfo, Error> {
+        let resp: Response<MerkleStateInfoDto> = self
+            .as_ref()
+            .send(Request::get_namespace_merkle(namespace_id))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for NamespaceApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct NamespaceIds {
+    /// The vec of namespace identifiers.
+    #[serde(rename = "namespaceIds")]
+    pub namespace_ids: Vec<String>,
+}
+
+impl From<Vec<NamespaceId>> for NamespaceIds {
+    fn from(e: Vec<NamespaceId>) -> Self {
+        let ids = e.into_iter().map(|n| n.id.to_hex()).collect();
+        Self { namespace_ids: ids }
+    }
+}
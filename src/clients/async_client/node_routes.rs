@@ -8,6 +8,8 @@
  * // except according to those terms.
  */
 
+use chrono::Local;
+
 use crate::account::PublicAccount;
 use crate::blockchain::StorageInfo;
 use crate::clients::request::Request;
@@ -15,6 +17,7 @@ use crate::model_dto::{
     NodeHealthInfoDto, NodeInfoDto, NodeTimeDto, ServerInfoDto, UnlockedAccountDto,
 };
 use crate::node::{NodeHealth, NodeInfo, NodeTime, ServerInfo};
+use crate::transaction::DeadlineBuilder;
 use crate::{Client, Error, Response, RetryStrategy};
 
 pub struct NodeApi<R: RetryStrategy>(pub(crate) Client<R>);
@@ -103,6 +106,38 @@ impl<R: RetryStrategy> NodeApi<R> {
         resp.to_compact().map_err(Into::into)
     }
 
+    /// Get a `DeadlineBuilder` synchronized with the node clock.
+    ///
+    /// # Info
+    /// Requests the node time and caches the offset between the local and the network clock,
+    /// so the deadlines are valid even if the local clock is skewed.
+    ///
+    /// # Inputs
+    ///
+    /// * `epoch_adjustment`: The network's epoch adjustment in seconds,
+    ///   see `NetworkProperties::get_epoch_adjustment`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `DeadlineBuilder` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_deadline_builder(
+        &self,
+        epoch_adjustment: u64,
+    ) -> Result<DeadlineBuilder, Error> {
+        let request_sent = Local::now();
+        let node_time = self.get_node_time().await?;
+        let response_received = Local::now();
+
+        Ok(DeadlineBuilder::from_node_time(
+            epoch_adjustment,
+            &node_time,
+            request_sent,
+            response_received,
+        ))
+    }
+
     /// Get the version of the running REST component.
     ///
     /// # Returns
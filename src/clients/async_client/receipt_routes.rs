@@ -0,0 +1,93 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::clients::request::Request;
+use crate::model_dto::{ResolutionStatementPageDto, TransactionStatementPageDto};
+use crate::receipt::{AddressResolutionStatement, MosaicResolutionStatement, TransactionStatement};
+use crate::{
+    Client, Error, ResolutionStatementSearchCriteria, Response, RetryStrategy,
+    TransactionStatementSearchCriteria,
+};
+
+pub struct ReceiptApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> ReceiptApi<R> {
+    /// Gets an vec of transaction statements, the receipts recorded in the blocks.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search transaction statements.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<TransactionStatement>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_receipts(
+        &self,
+        criteria: Option<TransactionStatementSearchCriteria>,
+    ) -> Result<Vec<TransactionStatement>, Error> {
+        let resp: Response<TransactionStatementPageDto> = self
+            .as_ref()
+            .send(Request::search_receipts(criteria))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+
+    /// Gets an vec of address resolution statements.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search resolution statements.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<AddressResolutionStatement>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_address_resolution_statements(
+        &self,
+        criteria: Option<ResolutionStatementSearchCriteria>,
+    ) -> Result<Vec<AddressResolutionStatement>, Error> {
+        let resp: Response<ResolutionStatementPageDto> = self
+            .as_ref()
+            .send(Request::search_address_resolution_statements(criteria))
+            .await?;
+        resp.to_address_resolution_statements().map_err(Into::into)
+    }
+
+    /// Gets an vec of mosaic resolution statements.
+    ///
+    /// # Inputs
+    ///
+    /// * `criteria`: Defines the params used to search resolution statements.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `Vec<MosaicResolutionStatement>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn search_mosaic_resolution_statements(
+        &self,
+        criteria: Option<ResolutionStatementSearchCriteria>,
+    ) -> Result<Vec<MosaicResolutionStatement>, Error> {
+        let resp: Response<ResolutionStatementPageDto> = self
+            .as_ref()
+            .send(Request::search_mosaic_resolution_statements(criteria))
+            .await?;
+        resp.to_mosaic_resolution_statements().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for ReceiptApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
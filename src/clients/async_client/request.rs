@@ -13,9 +13,16 @@ use std::collections::HashMap;
 use hex::ToHex;
 use reqwest::Method;
 
+use crate::account::Address;
 use crate::clients::search_criteria::BlockSearchCriteria;
 use crate::mosaic::MosaicId;
-use crate::{MosaicIds, MosaicSearchCriteria, H256};
+use crate::namespace::NamespaceId;
+use crate::transaction::TransactionGroup;
+use crate::{
+    AccountIds, AccountSearchCriteria, CosignaturePayload, MetadataSearchCriteria, MosaicIds,
+    MosaicSearchCriteria, NamespaceIds, NamespaceSearchCriteria, ResolutionStatementSearchCriteria,
+    TransactionIds, TransactionPayload, TransactionStatementSearchCriteria, H256,
+};
 
 /// Type alias to improve readability.
 pub(crate) type RoutePathName = &'static str;
@@ -69,6 +76,14 @@ impl Request {
     }
 
     fn from_serialized_body<T: serde::Serialize>(base_path: &'static str, body: T) -> Self {
+        Self::from_serialized_body_and_method(base_path, body, Method::POST)
+    }
+
+    fn from_serialized_body_and_method<T: serde::Serialize>(
+        base_path: &'static str,
+        body: T,
+        method: Method,
+    ) -> Self {
         let serialized_body = Some(serde_json::to_string(&body).unwrap());
 
         Request {
@@ -76,11 +91,75 @@ impl Request {
             query_params: Default::default(),
             path_params: Default::default(),
             serialized_body,
-            method: Method::POST,
+            method,
+        }
+    }
+
+    fn from_path_params_and_serialized_body<T: serde::Serialize>(
+        base_path: &'static str,
+        path_params: HashMap<&'static str, String>,
+        body: T,
+    ) -> Self {
+        Request {
+            path_params,
+            ..Self::from_serialized_body(base_path, body)
         }
     }
 }
 
+// Account requests
+impl Request {
+    pub const ACCOUNT_INFO_PATH: RoutePathName = "/accounts/{accountId}";
+    pub const ACCOUNT_INFO_MERKLE_PATH: RoutePathName = "/accounts/{accountId}/merkle";
+    pub const ACCOUNTS_INFO_PATH: RoutePathName = "/accounts";
+
+    pub fn get_account(account_id: String) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("accountId", account_id);
+        Self::from_path_params(Self::ACCOUNT_INFO_PATH, path_params, Method::GET)
+    }
+
+    pub fn get_accounts(account_ids: AccountIds) -> Self {
+        Self::from_serialized_body(Self::ACCOUNTS_INFO_PATH, account_ids)
+    }
+
+    pub fn search_accounts(criteria: Option<AccountSearchCriteria>) -> Self {
+        let mut query_params = HashMap::new();
+
+        if let Some(c) = criteria {
+            if let Some(value) = c.mosaic_id {
+                query_params.insert("mosaicId", value.to_hex());
+            }
+            if let Some(value) = c.order_by {
+                query_params.insert("orderBy", value.to_string());
+            }
+
+            if let Some(param) = c.param {
+                if let Some(value) = param.page_size {
+                    query_params.insert("pageSize", value.to_string());
+                }
+                if let Some(value) = param.page_number {
+                    query_params.insert("pageNumber", value.to_string());
+                }
+                if let Some(value) = param.offset {
+                    query_params.insert("offset", value.to_string());
+                }
+                if let Some(value) = param.order {
+                    query_params.insert("order", value.to_string());
+                }
+            }
+        }
+
+        Self::from_query_params(Self::ACCOUNTS_INFO_PATH, query_params, Method::GET)
+    }
+
+    pub fn get_account_merkle(account_id: String) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("accountId", account_id);
+        Self::from_path_params(Self::ACCOUNT_INFO_MERKLE_PATH, path_params, Method::GET)
+    }
+}
+
 // Blocks requests
 impl Request {
     pub const BLOCKS_SEARCH_PATH: RoutePathName = "/blocks";
@@ -184,6 +263,64 @@ impl Request {
     }
 }
 
+// Metadata requests
+impl Request {
+    pub const METADATA_INFO_PATH: RoutePathName = "/metadata/{compositeHash}";
+    pub const METADATA_INFO_MERKLE_PATH: RoutePathName = "/metadata/{compositeHash}/merkle";
+    pub const METADATA_SEARCH_PATH: RoutePathName = "/metadata";
+
+    pub fn search_metadata(criteria: Option<MetadataSearchCriteria>) -> Self {
+        let mut query_params = HashMap::new();
+
+        if let Some(c) = criteria {
+            if let Some(value) = c.source_address {
+                query_params.insert("sourceAddress", value.address_str());
+            }
+            if let Some(value) = c.target_address {
+                query_params.insert("targetAddress", value.address_str());
+            }
+            if let Some(value) = c.scoped_metadata_key {
+                query_params.insert("scopedMetadataKey", value.to_hex());
+            }
+            if let Some(value) = c.target_id {
+                query_params.insert("targetId", value.to_uint64().to_hex());
+            }
+            if let Some(value) = c.metadata_type {
+                query_params.insert("metadataType", value.value().to_string());
+            }
+
+            if let Some(param) = c.param {
+                if let Some(value) = param.page_size {
+                    query_params.insert("pageSize", value.to_string());
+                }
+                if let Some(value) = param.page_number {
+                    query_params.insert("pageNumber", value.to_string());
+                }
+                if let Some(value) = param.offset {
+                    query_params.insert("offset", value.to_string());
+                }
+                if let Some(value) = param.order {
+                    query_params.insert("order", value.to_string());
+                }
+            }
+        }
+
+        Self::from_query_params(Self::METADATA_SEARCH_PATH, query_params, Method::GET)
+    }
+
+    pub fn get_metadata(composite_hash: H256) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("compositeHash", composite_hash.encode_hex_upper::<String>());
+        Self::from_path_params(Self::METADATA_INFO_PATH, path_params, Method::GET)
+    }
+
+    pub fn get_metadata_merkle(composite_hash: H256) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("compositeHash", composite_hash.encode_hex_upper::<String>());
+        Self::from_path_params(Self::METADATA_INFO_MERKLE_PATH, path_params, Method::GET)
+    }
+}
+
 // Mosaic requests
 impl Request {
     pub const MOSAIC_INFO_PATH: RoutePathName = "/mosaics/{mosaicId}";
@@ -234,6 +371,110 @@ impl Request {
     }
 }
 
+// Multisig requests
+impl Request {
+    pub const MULTISIG_ACCOUNT_INFO_PATH: RoutePathName = "/account/{address}/multisig";
+    pub const MULTISIG_ACCOUNT_GRAPH_INFO_PATH: RoutePathName = "/account/{address}/multisig/graph";
+    pub const MULTISIG_ACCOUNT_INFO_MERKLE_PATH: RoutePathName =
+        "/account/{address}/multisig/merkle";
+
+    pub fn get_multisig_account_info(address: Address) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("address", address.address_str());
+        Self::from_path_params(Self::MULTISIG_ACCOUNT_INFO_PATH, path_params, Method::GET)
+    }
+
+    pub fn get_multisig_account_graph_info(address: Address) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("address", address.address_str());
+        Self::from_path_params(
+            Self::MULTISIG_ACCOUNT_GRAPH_INFO_PATH,
+            path_params,
+            Method::GET,
+        )
+    }
+
+    pub fn get_multisig_account_info_merkle(address: Address) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("address", address.address_str());
+        Self::from_path_params(
+            Self::MULTISIG_ACCOUNT_INFO_MERKLE_PATH,
+            path_params,
+            Method::GET,
+        )
+    }
+}
+
+// Namespace requests
+impl Request {
+    pub const NAMESPACE_INFO_PATH: RoutePathName = "/namespaces/{namespaceId}";
+    pub const NAMESPACE_INFO_MERKLE_PATH: RoutePathName = "/namespaces/{namespaceId}/merkle";
+    pub const NAMESPACES_INFO_PATH: RoutePathName = "/namespaces";
+    pub const NAMESPACES_NAMES_PATH: RoutePathName = "/namespaces/names";
+    pub const NAMESPACES_MOSAIC_NAMES_PATH: RoutePathName = "/namespaces/mosaic/names";
+    pub const NAMESPACES_ACCOUNT_NAMES_PATH: RoutePathName = "/namespaces/account/names";
+
+    pub fn get_namespace(namespace_id: NamespaceId) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("namespaceId", namespace_id.id.to_hex());
+        Self::from_path_params(Self::NAMESPACE_INFO_PATH, path_params, Method::GET)
+    }
+
+    pub fn search_namespaces(criteria: Option<NamespaceSearchCriteria>) -> Self {
+        let mut query_params = HashMap::new();
+
+        if let Some(c) = criteria {
+            if let Some(value) = c.owner_address {
+                query_params.insert("ownerAddress", value.address_str());
+            }
+            if let Some(value) = c.registration_type {
+                query_params.insert("registrationType", value.value().to_string());
+            }
+            if let Some(value) = c.level0 {
+                query_params.insert("level0", value.id.to_hex());
+            }
+            if let Some(value) = c.alias_type {
+                query_params.insert("aliasType", value.value().to_string());
+            }
+
+            if let Some(param) = c.param {
+                if let Some(value) = param.page_size {
+                    query_params.insert("pageSize", value.to_string());
+                }
+                if let Some(value) = param.page_number {
+                    query_params.insert("pageNumber", value.to_string());
+                }
+                if let Some(value) = param.offset {
+                    query_params.insert("offset", value.to_string());
+                }
+                if let Some(value) = param.order {
+                    query_params.insert("order", value.to_string());
+                }
+            }
+        }
+
+        Self::from_query_params(Self::NAMESPACES_INFO_PATH, query_params, Method::GET)
+    }
+
+    pub fn get_namespaces_names(namespace_ids: NamespaceIds) -> Self {
+        Self::from_serialized_body(Self::NAMESPACES_NAMES_PATH, namespace_ids)
+    }
+
+    pub fn get_mosaics_names(mosaic_ids: MosaicIds) -> Self {
+        Self::from_serialized_body(Self::NAMESPACES_MOSAIC_NAMES_PATH, mosaic_ids)
+    }
+
+    pub fn get_accounts_names(account_ids: AccountIds) -> Self {
+        Self::from_serialized_body(Self::NAMESPACES_ACCOUNT_NAMES_PATH, account_ids)
+    }
+
+    pub fn get_namespace_merkle(namespace_id: NamespaceId) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("namespaceId", namespace_id.id.to_hex());
+        Self::from_path_params(Self::NAMESPACE_INFO_MERKLE_PATH, path_params, Method::GET)
+    }
+}
+
 // Node requests
 impl Request {
     pub const NODE_HEALTH_PATH: RoutePathName = "/node/health";
@@ -272,3 +513,166 @@ impl Request {
         Self::new_path(Self::NODE_UNLOCKED_ACCOUNTS_PATH)
     }
 }
+
+// Receipt requests
+impl Request {
+    pub const RECEIPT_TRANSACTION_STATEMENTS_PATH: RoutePathName = "/statements/transaction";
+    pub const RECEIPT_ADDRESS_RESOLUTION_STATEMENTS_PATH: RoutePathName =
+        "/statements/resolutions/address";
+    pub const RECEIPT_MOSAIC_RESOLUTION_STATEMENTS_PATH: RoutePathName =
+        "/statements/resolutions/mosaic";
+
+    pub fn search_receipts(criteria: Option<TransactionStatementSearchCriteria>) -> Self {
+        let mut query_params = HashMap::new();
+
+        if let Some(c) = criteria {
+            if let Some(value) = c.height {
+                query_params.insert("height", value.to_string());
+            }
+            if let Some(value) = c.from_height {
+                query_params.insert("fromHeight", value.to_string());
+            }
+            if let Some(value) = c.to_height {
+                query_params.insert("toHeight", value.to_string());
+            }
+            if let Some(value) = c.receipt_type {
+                query_params.insert("receiptType", value.value().to_string());
+            }
+            if let Some(value) = c.recipient_address {
+                query_params.insert("recipientAddress", value.address_str());
+            }
+            if let Some(value) = c.sender_address {
+                query_params.insert("senderAddress", value.address_str());
+            }
+            if let Some(value) = c.target_address {
+                query_params.insert("targetAddress", value.address_str());
+            }
+            if let Some(value) = c.artifact_id {
+                query_params.insert("artifactId", value.to_uint64().to_hex());
+            }
+
+            if let Some(param) = c.param {
+                if let Some(value) = param.page_size {
+                    query_params.insert("pageSize", value.to_string());
+                }
+                if let Some(value) = param.page_number {
+                    query_params.insert("pageNumber", value.to_string());
+                }
+                if let Some(value) = param.offset {
+                    query_params.insert("offset", value.to_string());
+                }
+                if let Some(value) = param.order {
+                    query_params.insert("order", value.to_string());
+                }
+            }
+        }
+
+        Self::from_query_params(
+            Self::RECEIPT_TRANSACTION_STATEMENTS_PATH,
+            query_params,
+            Method::GET,
+        )
+    }
+
+    pub fn search_address_resolution_statements(
+        criteria: Option<ResolutionStatementSearchCriteria>,
+    ) -> Self {
+        Self::from_query_params(
+            Self::RECEIPT_ADDRESS_RESOLUTION_STATEMENTS_PATH,
+            Self::resolution_statement_query_params(criteria),
+            Method::GET,
+        )
+    }
+
+    pub fn search_mosaic_resolution_statements(
+        criteria: Option<ResolutionStatementSearchCriteria>,
+    ) -> Self {
+        Self::from_query_params(
+            Self::RECEIPT_MOSAIC_RESOLUTION_STATEMENTS_PATH,
+            Self::resolution_statement_query_params(criteria),
+            Method::GET,
+        )
+    }
+
+    fn resolution_statement_query_params(
+        criteria: Option<ResolutionStatementSearchCriteria>,
+    ) -> HashMap<&'static str, String> {
+        let mut query_params = HashMap::new();
+
+        if let Some(c) = criteria {
+            if let Some(value) = c.height {
+                query_params.insert("height", value.to_string());
+            }
+
+            if let Some(param) = c.param {
+                if let Some(value) = param.page_size {
+                    query_params.insert("pageSize", value.to_string());
+                }
+                if let Some(value) = param.page_number {
+                    query_params.insert("pageNumber", value.to_string());
+                }
+                if let Some(value) = param.offset {
+                    query_params.insert("offset", value.to_string());
+                }
+                if let Some(value) = param.order {
+                    query_params.insert("order", value.to_string());
+                }
+            }
+        }
+
+        query_params
+    }
+}
+
+// Transaction requests
+impl Request {
+    pub const TRANSACTIONS_PATH: RoutePathName = "/transactions";
+    pub const TRANSACTIONS_PARTIAL_PATH: RoutePathName = "/transactions/partial";
+    pub const TRANSACTIONS_COSIGNATURE_PATH: RoutePathName = "/transactions/cosignature";
+    pub const TRANSACTIONS_GROUP_PATH: RoutePathName = "/transactions/{group}";
+    pub const TRANSACTIONS_GROUP_ID_PATH: RoutePathName = "/transactions/{group}/{transactionId}";
+
+    pub fn announce_transaction(payload: TransactionPayload) -> Self {
+        Self::from_serialized_body_and_method(Self::TRANSACTIONS_PATH, payload, Method::PUT)
+    }
+
+    pub fn announce_aggregate_bonded_transaction(payload: TransactionPayload) -> Self {
+        Self::from_serialized_body_and_method(Self::TRANSACTIONS_PARTIAL_PATH, payload, Method::PUT)
+    }
+
+    pub fn announce_aggregate_bonded_cosignature(payload: CosignaturePayload) -> Self {
+        Self::from_serialized_body_and_method(
+            Self::TRANSACTIONS_COSIGNATURE_PATH,
+            payload,
+            Method::PUT,
+        )
+    }
+
+    pub fn get_transaction(transaction_id: &str, group: TransactionGroup) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("group", group.as_str().to_owned());
+        path_params.insert("transactionId", transaction_id.to_owned());
+        Self::from_path_params(Self::TRANSACTIONS_GROUP_ID_PATH, path_params, Method::GET)
+    }
+
+    pub fn get_transactions(transaction_ids: TransactionIds, group: TransactionGroup) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("group", group.as_str().to_owned());
+        Self::from_path_params_and_serialized_body(
+            Self::TRANSACTIONS_GROUP_PATH,
+            path_params,
+            transaction_ids,
+        )
+    }
+}
+
+// Transaction status requests
+impl Request {
+    pub const TRANSACTION_STATUS_PATH: RoutePathName = "/transactionStatus/{hash}";
+
+    pub fn get_transaction_status(hash: H256) -> Self {
+        let mut path_params = HashMap::new();
+        path_params.insert("hash", hash.encode_hex_upper::<String>());
+        Self::from_path_params(Self::TRANSACTION_STATUS_PATH, path_params, Method::GET)
+    }
+}
@@ -0,0 +1,365 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::time::{Duration, Instant};
+
+use hex::ToHex;
+
+use crate::clients::consts::TRANSACTION_STATUS_POLL_INTERVAL;
+use crate::clients::request::Request;
+use crate::model_dto::TransactionInfoDto;
+use crate::transaction::{
+    CosignatureSignedTransaction, SignedTransaction, Transaction, TransactionAnnounceResponse,
+    TransactionGroup, TransactionInfo, TransactionType,
+};
+use crate::{
+    Client, Error, Response, RetryStrategy, TransactionHash, TransactionStatusApi,
+    WaitForTransactionError,
+};
+
+pub struct TransactionApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> TransactionApi<R> {
+    /// Announces a transaction to the network.
+    ///
+    /// # Info
+    /// Aggregate bonded transactions must be announced with `announce_aggregate_bonded`.
+    ///
+    /// # Inputs
+    ///
+    /// * `signed_transaction`: The signed transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `TransactionAnnounceResponse` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn announce(
+        &self,
+        signed_transaction: &SignedTransaction,
+    ) -> Result<TransactionAnnounceResponse, Error> {
+        if signed_transaction.transaction_type == TransactionType::AggregateBonded {
+            return Err(Error::unexpected_uncategorized(
+                "Aggregate bonded transactions must be announced with announce_aggregate_bonded."
+                    .to_owned(),
+            ));
+        }
+
+        let resp: Response<TransactionAnnounceResponse> = self
+            .as_ref()
+            .send(Request::announce_transaction(signed_transaction.into()))
+            .await?;
+        Ok((*resp).clone())
+    }
+
+    /// Announces an aggregate bonded transaction to the partial transactions cache.
+    ///
+    /// # Info
+    /// The transaction is only accepted once its hash lock is confirmed,
+    /// see `announce_hash_lock_aggregate_bonded`.
+    ///
+    /// # Inputs
+    ///
+    /// * `signed_transaction`: The signed aggregate bonded transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `TransactionAnnounceResponse` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn announce_aggregate_bonded(
+        &self,
+        signed_transaction: &SignedTransaction,
+    ) -> Result<TransactionAnnounceResponse, Error> {
+        if signed_transaction.transaction_type != TransactionType::AggregateBonded {
+            return Err(Error::unexpected_uncategorized(format!(
+                "Only aggregate bonded transactions can be announced as partial, got {}.",
+                signed_transaction.transaction_type
+            )));
+        }
+
+        let resp: Response<TransactionAnnounceResponse> = self
+            .as_ref()
+            .send(Request::announce_aggregate_bonded_transaction(
+                signed_transaction.into(),
+            ))
+            .await?;
+        Ok((*resp).clone())
+    }
+
+    /// Announces a cosignature of an aggregate bonded transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `cosignature`: The cosignature signed transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `TransactionAnnounceResponse` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn announce_aggregate_bonded_cosignature(
+        &self,
+        cosignature: &CosignatureSignedTransaction,
+    ) -> Result<TransactionAnnounceResponse, Error> {
+        let resp: Response<TransactionAnnounceResponse> = self
+            .as_ref()
+            .send(Request::announce_aggregate_bonded_cosignature(
+                cosignature.into(),
+            ))
+            .await?;
+        Ok((*resp).clone())
+    }
+
+    /// Gets a transaction from the given group.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction_id`: The transaction id or hash.
+    /// * `group`: The transaction group, either confirmed, unconfirmed or partial.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `Box<dyn Transaction>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_transaction(
+        &self,
+        transaction_id: &str,
+        group: TransactionGroup,
+    ) -> Result<Box<dyn Transaction>, Error> {
+        check_transaction_group(group)?;
+
+        let resp: Response<TransactionInfoDto> = self
+            .as_ref()
+            .send(Request::get_transaction(transaction_id, group))
+            .await?;
+        resp.to_compact()
+            .map_err(|e| Error::unexpected_uncategorized(e.to_string()))
+    }
+
+    /// Gets a transaction from the given group by its hash.
+    ///
+    /// # Inputs
+    ///
+    /// * `hash`: The transaction hash.
+    /// * `group`: The transaction group, either confirmed, unconfirmed or partial.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `Box<dyn Transaction>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_transaction_by_hash(
+        &self,
+        hash: TransactionHash,
+        group: TransactionGroup,
+    ) -> Result<Box<dyn Transaction>, Error> {
+        self.get_transaction(&hash.encode_hex_upper::<String>(), group)
+            .await
+    }
+
+    /// Gets an vec of transactions from the given group.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction_ids`: The transaction ids or hashes.
+    /// * `group`: The transaction group, either confirmed, unconfirmed or partial.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `Vec<Box<dyn Transaction>>` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_transactions(
+        &self,
+        transaction_ids: Vec<String>,
+        group: TransactionGroup,
+    ) -> Result<Vec<Box<dyn Transaction>>, Error> {
+        check_transaction_group(group)?;
+
+        let resp: Response<Vec<TransactionInfoDto>> = self
+            .as_ref()
+            .send(Request::get_transactions(transaction_ids.into(), group))
+            .await?;
+
+        let mut transactions = vec![];
+        for transaction in &*resp {
+            transactions.push(
+                transaction
+                    .to_compact()
+                    .map_err(|e| Error::unexpected_uncategorized(e.to_string()))?,
+            )
+        }
+        Ok(transactions)
+    }
+
+    /// Announces a hash lock transaction, waits for it to be confirmed
+    /// and then announces the locked aggregate bonded transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `signed_hash_lock_transaction`: The signed hash lock transaction.
+    /// * `signed_aggregate_transaction`: The signed aggregate bonded transaction locked by the hash lock.
+    /// * `timeout`: The maximum time to wait for the hash lock confirmation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the `TransactionAnnounceResponse` of the aggregate bonded
+    /// transaction or whose error value is an `Error` describing the error that occurred.
+    ///
+    pub async fn announce_hash_lock_aggregate_bonded(
+        &self,
+        signed_hash_lock_transaction: &SignedTransaction,
+        signed_aggregate_transaction: &SignedTransaction,
+        timeout: Duration,
+    ) -> Result<TransactionAnnounceResponse, Error> {
+        if signed_hash_lock_transaction.transaction_type != TransactionType::Hash_Lock {
+            return Err(Error::unexpected_uncategorized(format!(
+                "Expected a hash lock transaction, got {}.",
+                signed_hash_lock_transaction.transaction_type
+            )));
+        }
+
+        self.announce(signed_hash_lock_transaction).await?;
+        self.wait_for_transaction(signed_hash_lock_transaction.hash, timeout)
+            .await?;
+        self.announce_aggregate_bonded(signed_aggregate_transaction)
+            .await
+    }
+
+    /// Waits for a transaction to be confirmed.
+    ///
+    /// Polls the transaction status until the transaction is included in a block,
+    /// rejected by the node or the timeout is reached.
+    ///
+    /// # Inputs
+    ///
+    /// * `hash`: The hash of the announced transaction.
+    /// * `timeout`: The maximum time to wait for the confirmation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the confirmed height together with the `TransactionInfo`
+    /// or whose error value is a `WaitForTransactionError` describing the error that occurred.
+    ///
+    pub async fn wait_for_transaction(
+        &self,
+        hash: TransactionHash,
+        timeout: Duration,
+    ) -> Result<(u64, TransactionInfo), WaitForTransactionError> {
+        let status_api = TransactionStatusApi(self.as_ref().clone());
+        let start = Instant::now();
+        loop {
+            match status_api.get_transaction_status(hash).await {
+                Ok(status) if status.is_confirmed() => break,
+                Ok(status) if status.is_failed() => {
+                    return Err(WaitForTransactionError::Rejected(
+                        status.code.unwrap_or_default(),
+                    ))
+                }
+                // the transaction is still unconfirmed or partial.
+                Ok(_) => {}
+                // the node does not know the transaction yet.
+                Err(Error::SymbolError(err)) if err.is_resource_not_found() => {}
+                Err(err) => return Err(WaitForTransactionError::GetTransactionError(err)),
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Err(WaitForTransactionError::Timeout(elapsed));
+            }
+            tokio::time::sleep(TRANSACTION_STATUS_POLL_INTERVAL).await;
+        }
+
+        let transaction = self
+            .get_transaction_by_hash(hash, TransactionGroup::Confirmed)
+            .await
+            .map_err(WaitForTransactionError::GetTransactionError)?;
+
+        match transaction.common().transaction_info.clone() {
+            Some(transaction_info) => Ok((transaction_info.height, transaction_info)),
+            None => Err(WaitForTransactionError::GetTransactionError(
+                Error::unexpected_uncategorized(format!(
+                    "Transaction {:X} has no transaction info",
+                    hash
+                )),
+            )),
+        }
+    }
+}
+
+/// Failed transactions are only kept by the transaction status routes.
+///
+fn check_transaction_group(group: TransactionGroup) -> Result<(), Error> {
+    if group == TransactionGroup::Failed {
+        return Err(Error::unexpected_uncategorized(
+            "Failed transactions can only be queried with get_transaction_status.".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for TransactionApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct TransactionPayload {
+    /// The signed transaction payload in hex.
+    pub payload: String,
+}
+
+impl From<&SignedTransaction> for TransactionPayload {
+    fn from(e: &SignedTransaction) -> Self {
+        Self {
+            payload: e.payload.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosignaturePayload {
+    /// The hash of the aggregate bonded transaction in hex.
+    pub parent_hash: String,
+    /// The cosignature in hex.
+    pub signature: String,
+    /// The cosigner public key in hex.
+    pub signer_public_key: String,
+    /// The cosignature version.
+    pub version: String,
+}
+
+impl From<&CosignatureSignedTransaction> for CosignaturePayload {
+    fn from(e: &CosignatureSignedTransaction) -> Self {
+        Self {
+            parent_hash: e.parent_hash.encode_hex_upper::<String>(),
+            signature: e.signature.clone(),
+            signer_public_key: e.signer_public_key.clone(),
+            version: e.version.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct TransactionIds {
+    /// The vec of transaction ids or hashes.
+    #[serde(rename = "transactionIds")]
+    pub transaction_ids: Vec<String>,
+}
+
+impl From<Vec<String>> for TransactionIds {
+    fn from(e: Vec<String>) -> Self {
+        Self { transaction_ids: e }
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::clients::request::Request;
+use crate::model_dto::TransactionStatusDto;
+use crate::transaction::TransactionStatus;
+use crate::{Client, Error, Response, RetryStrategy, TransactionHash};
+
+pub struct TransactionStatusApi<R: RetryStrategy>(pub(crate) Client<R>);
+
+impl<R: RetryStrategy> TransactionStatusApi<R> {
+    /// Gets the status of a transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `hash`: The transaction hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `TransactionStatus` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub async fn get_transaction_status(
+        &self,
+        hash: TransactionHash,
+    ) -> Result<TransactionStatus, Error> {
+        let resp: Response<TransactionStatusDto> = self
+            .as_ref()
+            .send(Request::get_transaction_status(hash))
+            .await?;
+        resp.to_compact().map_err(Into::into)
+    }
+}
+
+impl<R: RetryStrategy> AsRef<Client<R>> for TransactionStatusApi<R> {
+    fn as_ref(&self) -> &Client<R> {
+        &self.0
+    }
+}
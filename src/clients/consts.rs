@@ -14,3 +14,4 @@ pub const MAX_RETRIES: u32 = 5;
 pub const WAIT_DELAY: Duration = Duration::from_millis(50);
 pub const TIMEOUT: Duration = Duration::from_secs(5);
 pub const HTTP_REQUEST_TIMEOUT: Duration = TIMEOUT;
+pub const TRANSACTION_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
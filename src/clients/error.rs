@@ -102,6 +102,8 @@ impl StdError for UnexpectedError {
 pub enum WaitForTransactionError {
     // Get account transaction error
     GetTransactionError(Error),
+    // The transaction was rejected, value is the status code.
+    Rejected(String),
     // Wait timeout, value is waited duration.
     Timeout(std::time::Duration),
 }
@@ -112,6 +114,21 @@ impl std::fmt::Display for WaitForTransactionError {
     }
 }
 
+impl From<WaitForTransactionError> for Error {
+    fn from(e: WaitForTransactionError) -> Self {
+        match e {
+            WaitForTransactionError::GetTransactionError(e) => e,
+            WaitForTransactionError::Rejected(code) => {
+                Error::unexpected_uncategorized(format!("Transaction rejected: {}", code))
+            }
+            WaitForTransactionError::Timeout(duration) => Error::ResponseTimeout(format!(
+                "Transaction was not confirmed after {:?}",
+                duration
+            )),
+        }
+    }
+}
+
 impl StdError for WaitForTransactionError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
@@ -0,0 +1,39 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::model_dto::{ActivityBucketDto, MosaicAmountDto, SupplementalPublicKeysDto};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountDto {
+    /// The version of the state
+    pub version: u16,
+    /// Address expressed in hexadecimal base.
+    pub address: String,
+    /// Height of the blockchain.
+    pub address_height: String,
+    /// Public key.
+    pub public_key: String,
+    /// Height of the blockchain.
+    pub public_key_height: String,
+    /// - 0 (unlinked) - Unlinked.
+    /// - 1 (main) - Balance-holding account that is linked to a remote harvester account.
+    /// - 2 (remote) - Remote harvester account that is linked to a balance-holding account.
+    /// - 3 (remoteUnlinked) - Remote harvester eligible account that is unlinked.
+    pub account_type: u8,
+    pub supplemental_public_keys: SupplementalPublicKeysDto,
+    pub activity_buckets: Vec<ActivityBucketDto>,
+    /// Mosaics owned by an account.
+    pub mosaics: Vec<MosaicAmountDto>,
+    /// Probability of an account to harvest the next block.
+    pub importance: String,
+    /// Height of the blockchain.
+    pub importance_height: String,
+}
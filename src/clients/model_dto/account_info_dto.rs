@@ -0,0 +1,67 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::{AccountInfo, AccountType, Address, PublicAccount};
+use crate::model_dto::AccountDto;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountInfoDto {
+    /// Internal resource identifier.
+    pub id: String,
+    pub account: AccountDto,
+}
+
+impl AccountInfoDto {
+    pub fn to_compact(&self) -> Result<AccountInfo> {
+        let dto = self.account.clone();
+        let address = Address::from_encoded(&dto.address)?;
+        let public_key_height = u64::from_str(&dto.public_key_height)?;
+
+        let public_account = if public_key_height > 0 {
+            Some(PublicAccount::from_public_key(
+                &dto.public_key,
+                address.network_type,
+            )?)
+        } else {
+            None
+        };
+
+        let mut activity_buckets = vec![];
+        for bucket in dto.activity_buckets.iter() {
+            activity_buckets.push(bucket.to_compact()?)
+        }
+
+        let mut mosaics = vec![];
+        for mosaic in dto.mosaics.iter() {
+            mosaics.push(mosaic.to_compact()?)
+        }
+
+        Ok(AccountInfo {
+            version: dto.version,
+            record_id: self.id.clone(),
+            address,
+            address_height: u64::from_str(&dto.address_height)?,
+            public_account,
+            public_key_height,
+            account_type: AccountType::try_from(dto.account_type)?,
+            supplemental_public_keys: dto.supplemental_public_keys.to_compact()?,
+            activity_buckets,
+            mosaics,
+            importance: u64::from_str(&dto.importance)?,
+            importance_height: u64::from_str(&dto.importance_height)?,
+        })
+    }
+}
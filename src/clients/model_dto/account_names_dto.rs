@@ -0,0 +1,56 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::account::{AccountNames, Address};
+use crate::namespace::NamespaceName;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountsNamesDto {
+    /// Array of account names.
+    pub account_names: Vec<AccountNamesDto>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountNamesDto {
+    /// Address expressed in hexadecimal base.
+    pub address: String,
+    /// Account linked namespace names.
+    pub names: Vec<String>,
+}
+
+impl AccountsNamesDto {
+    pub fn to_compact(&self) -> Result<Vec<AccountNames>> {
+        let mut account_names_vec = vec![];
+
+        for account_names in self.account_names.iter() {
+            account_names_vec.push(account_names.to_compact()?)
+        }
+
+        Ok(account_names_vec)
+    }
+}
+
+impl AccountNamesDto {
+    pub fn to_compact(&self) -> Result<AccountNames> {
+        let mut names = vec![];
+        for name in self.names.iter() {
+            names.push(NamespaceName::from_full_name(name)?)
+        }
+
+        Ok(AccountNames {
+            address: Address::from_encoded(&self.address)?,
+            names,
+        })
+    }
+}
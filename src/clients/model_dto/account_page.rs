@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::account::AccountInfo;
+use crate::model_dto::{AccountInfoDto, Pagination};
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountPageDto {
+    /// Array of accounts.
+    pub data: Vec<AccountInfoDto>,
+    pub pagination: Pagination,
+}
+
+impl AccountPageDto {
+    pub fn to_compact(&self) -> Result<Vec<AccountInfo>> {
+        let mut account_info_vec = vec![];
+
+        for info in self.data.iter() {
+            account_info_vec.push(info.to_compact()?)
+        }
+
+        Ok(account_info_vec)
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::ActivityBucket;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActivityBucketDto {
+    /// Height of the blockchain.
+    pub start_height: String,
+    /// Absolute amount. An amount of 123456789 (absolute) for a mosaic with divisibility 6 means 123.456789 (relative).
+    pub total_fees_paid: String,
+    /// Number of times in which the account was the beneficiary of a harvested block.
+    pub beneficiary_count: u32,
+    /// A number that allows uint 64 values represented with a string.
+    pub raw_score: String,
+}
+
+impl ActivityBucketDto {
+    pub fn to_compact(&self) -> Result<ActivityBucket> {
+        Ok(ActivityBucket {
+            start_height: u64::from_str(&self.start_height)?,
+            total_fees_paid: u64::from_str(&self.total_fees_paid)?,
+            beneficiary_count: self.beneficiary_count,
+            raw_score: u64::from_str(&self.raw_score)?,
+        })
+    }
+}
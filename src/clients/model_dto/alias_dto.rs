@@ -0,0 +1,55 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, Result};
+
+use crate::account::Address;
+use crate::mosaic::MosaicId;
+use crate::namespace::{Alias, AliasType};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AliasDto {
+    /// - 0 (none) - No alias.
+    /// - 1 (mosaicId) - Mosaic id alias.
+    /// - 2 (address) - Address alias.
+    #[serde(rename = "type")]
+    pub type_field: u8,
+    /// Mosaic identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<String>,
+    /// Address expressed in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+impl AliasDto {
+    pub fn to_compact(&self) -> Result<Alias> {
+        match AliasType::try_from(self.type_field)? {
+            AliasType::None => Ok(Alias::empty()),
+            AliasType::Mosaic => {
+                let mosaic_id = self
+                    .mosaic_id
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Missing mosaic id of the mosaic alias"))?;
+                Ok(Alias::from_mosaic_id(MosaicId::from_hex(mosaic_id)?))
+            }
+            AliasType::Address => {
+                let address = self
+                    .address
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Missing address of the address alias"))?;
+                Ok(Alias::from_address(Address::from_encoded(address)?))
+            }
+        }
+    }
+}
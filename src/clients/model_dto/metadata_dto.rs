@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::Address;
+use crate::metadata::{Metadata, MetadataType, MetadataValue};
+use crate::mosaic::{MosaicId, UnresolvedMosaicId};
+use crate::namespace::NamespaceId;
+use crate::{Uint64, H256};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MetadataInfoDto {
+    /// Internal resource identifier.
+    pub id: String,
+    pub metadata_entry: MetadataEntryDto,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MetadataEntryDto {
+    pub version: u16,
+    pub composite_hash: String,
+    /// Address expressed in hexadecimal base.
+    pub source_address: String,
+    /// Address expressed in hexadecimal base.
+    pub target_address: String,
+    /// Metadata key scoped to source, target and type expressed in hexadecimal.
+    pub scoped_metadata_key: String,
+    /// Mosaic or namespace identifier, zero for account metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    pub metadata_type: u8,
+    /// Metadata value size in bytes.
+    pub value_size: u16,
+    /// Metadata value expressed in hexadecimal.
+    pub value: String,
+}
+
+impl MetadataInfoDto {
+    pub fn to_compact(&self) -> Result<Metadata> {
+        let dto = &self.metadata_entry;
+
+        let metadata_type = MetadataType::try_from(dto.metadata_type)?;
+
+        let target_id: Option<Box<dyn UnresolvedMosaicId>> = match (metadata_type, &dto.target_id) {
+            (MetadataType::Mosaic, Some(id)) => Some(Box::new(MosaicId::from_hex(id)?)),
+            (MetadataType::Namespace, Some(id)) => Some(Box::new(NamespaceId::from_hex(id)?)),
+            _ => None,
+        };
+
+        Ok(Metadata {
+            record_id: self.id.clone(),
+            version: dto.version,
+            composite_hash: H256::from_str(&dto.composite_hash)?,
+            source_address: Address::from_encoded(&dto.source_address)?,
+            target_address: Address::from_encoded(&dto.target_address)?,
+            scoped_metadata_key: Uint64::try_from(dto.scoped_metadata_key.as_str())?,
+            metadata_type,
+            target_id,
+            value: MetadataValue::from(hex::decode(&dto.value)?),
+        })
+    }
+}
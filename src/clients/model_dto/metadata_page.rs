@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::metadata::Metadata;
+use crate::model_dto::{MetadataInfoDto, Pagination};
+
+#[derive(Serialize, Deserialize)]
+pub struct MetadataPageDto {
+    /// Array of metadata entries.
+    pub data: Vec<MetadataInfoDto>,
+    pub pagination: Pagination,
+}
+
+impl MetadataPageDto {
+    pub fn to_compact(&self) -> Result<Vec<Metadata>> {
+        let mut metadata_vec = vec![];
+
+        for info in self.data.iter() {
+            metadata_vec.push(info.to_compact()?)
+        }
+
+        Ok(metadata_vec)
+    }
+}
@@ -8,6 +8,12 @@
  * // except according to those terms.
  */
 
+pub use self::account_dto::*;
+pub use self::account_info_dto::*;
+pub use self::account_names_dto::*;
+pub use self::account_page::*;
+pub use self::activity_bucket_dto::*;
+pub use self::alias_dto::*;
 pub use self::block_dto::*;
 pub use self::block_info_dto::*;
 pub use self::block_meta_dto::*;
@@ -21,17 +27,44 @@ pub use self::merkle_state_info_dto::*;
 pub use self::merkle_tree_branch_dto::*;
 pub use self::merkle_tree_branch_link_dto::*;
 pub use self::merkle_tree_leaf_dto::*;
+pub use self::metadata_dto::*;
+pub use self::metadata_page::*;
+pub use self::mosaic_amount_dto::*;
 pub use self::mosaic_dto::*;
 pub use self::mosaic_info_dto::*;
+pub use self::mosaic_names_dto::*;
 pub use self::mosaic_page::*;
+pub use self::multisig_account_graph_info_dto::*;
+pub use self::multisig_dto::*;
+pub use self::namespace_dto::*;
+pub use self::namespace_info_dto::*;
+pub use self::namespace_meta_dto::*;
+pub use self::namespace_name_dto::*;
+pub use self::namespace_page::*;
 pub use self::node_health_info_dto::*;
 pub use self::node_info_dto::*;
 pub use self::node_time_dto::*;
 pub use self::pagination::*;
+pub use self::receipt_dto::*;
+pub use self::receipt_source_dto::*;
 pub use self::rental_fees_dto::*;
+pub use self::resolution_statement_dto::*;
+pub use self::resolution_statement_page::*;
 pub use self::server_info_dto::*;
+pub use self::supplemental_public_keys_dto::*;
+pub use self::transaction_dto::*;
+pub use self::transaction_info_dto::*;
+pub use self::transaction_statement_dto::*;
+pub use self::transaction_statement_page::*;
+pub use self::transaction_status_dto::*;
 pub use self::unlocked_account_dto::*;
 
+mod account_dto;
+mod account_info_dto;
+mod account_names_dto;
+mod account_page;
+mod activity_bucket_dto;
+mod alias_dto;
 mod block_dto;
 mod block_info_dto;
 mod block_meta_dto;
@@ -45,13 +78,34 @@ mod merkle_state_info_dto;
 mod merkle_tree_branch_dto;
 mod merkle_tree_branch_link_dto;
 mod merkle_tree_leaf_dto;
+mod metadata_dto;
+mod metadata_page;
+mod mosaic_amount_dto;
 mod mosaic_dto;
 mod mosaic_info_dto;
+mod mosaic_names_dto;
 mod mosaic_page;
+mod multisig_account_graph_info_dto;
+mod multisig_dto;
+mod namespace_dto;
+mod namespace_info_dto;
+mod namespace_meta_dto;
+mod namespace_name_dto;
+mod namespace_page;
 mod node_health_info_dto;
 mod node_info_dto;
 mod node_time_dto;
 mod pagination;
+mod receipt_dto;
+mod receipt_source_dto;
 mod rental_fees_dto;
+mod resolution_statement_dto;
+mod resolution_statement_page;
 mod server_info_dto;
+mod supplemental_public_keys_dto;
+mod transaction_dto;
+mod transaction_info_dto;
+mod transaction_statement_dto;
+mod transaction_statement_page;
+mod transaction_status_dto;
 mod unlocked_account_dto;
@@ -0,0 +1,30 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::mosaic::{Mosaic, MosaicId};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MosaicAmountDto {
+    /// Mosaic identifier.
+    pub id: String,
+    /// Absolute amount. An amount of 123456789 (absolute) for a mosaic with divisibility 6 means 123.456789 (relative).
+    pub amount: String,
+}
+
+impl MosaicAmountDto {
+    pub fn to_compact(&self) -> Result<Mosaic> {
+        Mosaic::create(MosaicId::from_hex(&self.id)?, u64::from_str(&self.amount)?)
+    }
+}
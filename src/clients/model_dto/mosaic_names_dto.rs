@@ -0,0 +1,56 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::mosaic::{MosaicId, MosaicNames};
+use crate::namespace::NamespaceName;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MosaicsNamesDto {
+    /// Array of mosaic names.
+    pub mosaic_names: Vec<MosaicNamesDto>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MosaicNamesDto {
+    /// Mosaic identifier.
+    pub mosaic_id: String,
+    /// Mosaic linked namespace names.
+    pub names: Vec<String>,
+}
+
+impl MosaicsNamesDto {
+    pub fn to_compact(&self) -> Result<Vec<MosaicNames>> {
+        let mut mosaic_names_vec = vec![];
+
+        for mosaic_names in self.mosaic_names.iter() {
+            mosaic_names_vec.push(mosaic_names.to_compact()?)
+        }
+
+        Ok(mosaic_names_vec)
+    }
+}
+
+impl MosaicNamesDto {
+    pub fn to_compact(&self) -> Result<MosaicNames> {
+        let mut names = vec![];
+        for name in self.names.iter() {
+            names.push(NamespaceName::from_full_name(name)?)
+        }
+
+        Ok(MosaicNames {
+            mosaic_id: MosaicId::from_hex(&self.mosaic_id)?,
+            names,
+        })
+    }
+}
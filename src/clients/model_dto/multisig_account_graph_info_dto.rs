@@ -0,0 +1,36 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::account::MultisigAccountInfo;
+use crate::model_dto::MultisigAccountInfoDto;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultisigAccountGraphInfoDto {
+    /// Multisig graph level.
+    pub level: i32,
+    /// Array of multisig accounts for this level.
+    pub multisig_entries: Vec<MultisigAccountInfoDto>,
+}
+
+impl MultisigAccountGraphInfoDto {
+    /// Returns the level together with its multisig accounts info.
+    ///
+    pub fn to_compact(&self) -> Result<(i32, Vec<MultisigAccountInfo>)> {
+        let mut multisig_entries = vec![];
+        for entry in self.multisig_entries.iter() {
+            multisig_entries.push(entry.to_compact()?)
+        }
+
+        Ok((self.level, multisig_entries))
+    }
+}
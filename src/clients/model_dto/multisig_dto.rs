@@ -0,0 +1,60 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::account::{Address, MultisigAccountInfo};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultisigAccountInfoDto {
+    pub multisig: MultisigDto,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultisigDto {
+    pub version: u16,
+    /// Address expressed in hexadecimal base.
+    pub account_address: String,
+    /// Number of signatures needed to approve a transaction.
+    pub min_approval: u32,
+    /// Number of signatures needed to remove a cosignatory.
+    pub min_removal: u32,
+    /// Cosignatory accounts addresses.
+    pub cosignatory_addresses: Vec<String>,
+    /// Multisig accounts addresses.
+    pub multisig_addresses: Vec<String>,
+}
+
+impl MultisigAccountInfoDto {
+    pub fn to_compact(&self) -> Result<MultisigAccountInfo> {
+        let dto = &self.multisig;
+
+        let mut cosignatory_addresses = vec![];
+        for address in dto.cosignatory_addresses.iter() {
+            cosignatory_addresses.push(Address::from_encoded(address)?)
+        }
+
+        let mut multisig_addresses = vec![];
+        for address in dto.multisig_addresses.iter() {
+            multisig_addresses.push(Address::from_encoded(address)?)
+        }
+
+        Ok(MultisigAccountInfo {
+            version: dto.version,
+            account_address: Address::from_encoded(&dto.account_address)?,
+            min_approval: dto.min_approval,
+            min_removal: dto.min_removal,
+            cosignatory_addresses,
+            multisig_addresses,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::model_dto::AliasDto;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NamespaceDto {
+    /// The version of the state
+    pub version: u16,
+    /// - 0 (rootNamespace) - Root namespace.
+    /// - 1 (subNamespace) - Subnamespace.
+    pub registration_type: u8,
+    /// Level of the namespace.
+    pub depth: u8,
+    /// Namespace identifier.
+    pub level0: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level2: Option<String>,
+    pub alias: AliasDto,
+    /// Namespace identifier.
+    pub parent_id: String,
+    /// Address expressed in hexadecimal base.
+    pub owner_address: String,
+    /// Height of the blockchain.
+    pub start_height: String,
+    /// Height of the blockchain.
+    pub end_height: String,
+}
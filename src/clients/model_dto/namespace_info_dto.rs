@@ -0,0 +1,53 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::Address;
+use crate::model_dto::{NamespaceDto, NamespaceMetaDto};
+use crate::namespace::{NamespaceId, NamespaceInfo, NamespaceRegistrationType};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NamespaceInfoDto {
+    /// Internal resource identifier.
+    pub id: String,
+    pub meta: NamespaceMetaDto,
+    pub namespace: NamespaceDto,
+}
+
+impl NamespaceInfoDto {
+    pub fn to_compact(&self) -> Result<NamespaceInfo> {
+        let dto = self.namespace.clone();
+
+        let mut levels = vec![NamespaceId::from_hex(&dto.level0)?];
+        for level in [&dto.level1, &dto.level2].iter().copied().flatten() {
+            levels.push(NamespaceId::from_hex(level)?);
+        }
+
+        Ok(NamespaceInfo {
+            version: dto.version,
+            record_id: self.id.clone(),
+            active: self.meta.active,
+            index: self.meta.index,
+            registration_type: NamespaceRegistrationType::try_from(dto.registration_type)?,
+            depth: dto.depth,
+            levels,
+            parent_id: NamespaceId::from_hex(&dto.parent_id)?,
+            owner_address: Address::from_encoded(&dto.owner_address)?,
+            start_height: u64::from_str(&dto.start_height)?,
+            end_height: u64::from_str(&dto.end_height)?,
+            alias: dto.alias.to_compact()?,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::namespace::{NamespaceId, NamespaceName};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NamespaceNameDto {
+    /// Namespace identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Namespace identifier.
+    pub id: String,
+    /// Namespace name.
+    pub name: String,
+}
+
+impl NamespaceNameDto {
+    pub fn to_compact(&self) -> Result<NamespaceName> {
+        Ok(NamespaceName {
+            namespace_id: NamespaceId::from_hex(&self.id)?,
+            name: self.name.clone(),
+            parent_id: match &self.parent_id {
+                Some(parent_id) => Some(NamespaceId::from_hex(parent_id)?),
+                None => None,
+            },
+        })
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::model_dto::{NamespaceInfoDto, Pagination};
+use crate::namespace::NamespaceInfo;
+
+#[derive(Serialize, Deserialize)]
+pub struct NamespacePageDto {
+    /// Array of namespaces.
+    pub data: Vec<NamespaceInfoDto>,
+    pub pagination: Pagination,
+}
+
+impl NamespacePageDto {
+    pub fn to_compact(&self) -> Result<Vec<NamespaceInfo>> {
+        let mut namespace_info_vec = vec![];
+
+        for info in self.data.iter() {
+            namespace_info_vec.push(info.to_compact()?)
+        }
+
+        Ok(namespace_info_vec)
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+
+use crate::account::Address;
+use crate::core::utils::unresolved_mapping::to_unresolved_mosaic;
+use crate::mosaic::{Mosaic, MosaicId};
+use crate::receipt::{
+    ArtifactExpiryReceipt, BalanceChangeReceipt, BalanceTransferReceipt, InflationReceipt, Receipt,
+    ReceiptType,
+};
+
+/// The fields of a receipt, only the fields of the receipt type are present.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReceiptDto {
+    /// Version of the receipt.
+    pub version: u16,
+    #[serde(rename = "type")]
+    pub _type: u16,
+    /// Mosaic identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<String>,
+    /// Absolute amount. An amount of 123456789 (absolute) for a mosaic with divisibility 6 means 123.456789 (relative).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Address encoded using a 32-character set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address: Option<String>,
+    /// Address encoded using a 32-character set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_address: Option<String>,
+    /// Address encoded using a 32-character set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_address: Option<String>,
+    /// Mosaic or namespace identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifact_id: Option<String>,
+}
+
+impl ReceiptDto {
+    pub fn to_compact(&self) -> Result<Receipt> {
+        let receipt_type = ReceiptType::try_from(self._type)?;
+
+        if BalanceTransferReceipt::RECEIPT_TYPES.contains(&receipt_type) {
+            return Ok(BalanceTransferReceipt::create(
+                self.version,
+                receipt_type,
+                self.mosaic()?,
+                Address::from_encoded(Self::field(&self.sender_address, "senderAddress")?)?,
+                Address::from_encoded(Self::field(&self.recipient_address, "recipientAddress")?)?,
+            )?
+            .into());
+        }
+
+        if BalanceChangeReceipt::RECEIPT_TYPES.contains(&receipt_type) {
+            return Ok(BalanceChangeReceipt::create(
+                self.version,
+                receipt_type,
+                self.mosaic()?,
+                Address::from_encoded(Self::field(&self.target_address, "targetAddress")?)?,
+            )?
+            .into());
+        }
+
+        if ArtifactExpiryReceipt::RECEIPT_TYPES.contains(&receipt_type) {
+            return Ok(ArtifactExpiryReceipt::create(
+                self.version,
+                receipt_type,
+                to_unresolved_mosaic(Self::field(&self.artifact_id, "artifactId")?)?,
+            )?
+            .into());
+        }
+
+        match receipt_type {
+            ReceiptType::Inflation => {
+                Ok(InflationReceipt::new(self.version, self.mosaic()?).into())
+            }
+            _ => Err(anyhow!("Receipt type {} is not supported.", receipt_type)),
+        }
+    }
+
+    fn mosaic(&self) -> Result<Mosaic> {
+        Mosaic::create(
+            MosaicId::from_hex(Self::field(&self.mosaic_id, "mosaicId")?)?,
+            u64::from_str(Self::field(&self.amount, "amount")?)?,
+        )
+    }
+
+    fn field<'a>(value: &'a Option<String>, name: &str) -> Result<&'a str> {
+        value
+            .as_deref()
+            .ok_or_else(|| anyhow!("Receipt field {} is missing.", name))
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::receipt::ReceiptSource;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReceiptSourceDto {
+    /// Transaction index within the block.
+    pub primary_id: u32,
+    /// Transaction index inside within the aggregate transaction.
+    /// If the transaction is not an inner transaction, then the secondary identifier is set to 0.
+    pub secondary_id: u32,
+}
+
+impl ReceiptSourceDto {
+    pub fn to_compact(&self) -> ReceiptSource {
+        ReceiptSource::new(self.primary_id, self.secondary_id)
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::Address;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::model_dto::ReceiptSourceDto;
+use crate::mosaic::MosaicId;
+use crate::receipt::{
+    AddressResolutionEntry, AddressResolutionStatement, MosaicResolutionEntry,
+    MosaicResolutionStatement,
+};
+
+/// A resolution statement, the unresolved and resolved values are either
+/// addresses or mosaic ids depending on the statement kind.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResolutionStatementInfoDto {
+    /// Internal resource identifier.
+    pub id: String,
+    pub statement: ResolutionStatementDto,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResolutionStatementDto {
+    /// Height of the blockchain.
+    pub height: String,
+    /// Unresolved address or mosaic id.
+    pub unresolved: String,
+    /// Array of resolution entries linked to the unresolved value.
+    pub resolution_entries: Vec<ResolutionEntryDto>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResolutionEntryDto {
+    pub source: ReceiptSourceDto,
+    /// Resolved address or mosaic id.
+    pub resolved: String,
+}
+
+impl ResolutionStatementInfoDto {
+    pub fn to_address_resolution_statement(&self) -> Result<AddressResolutionStatement> {
+        let dto = &self.statement;
+
+        let mut resolution_entries = vec![];
+        for entry in dto.resolution_entries.iter() {
+            resolution_entries.push(AddressResolutionEntry {
+                source: entry.source.to_compact(),
+                resolved: Address::from_encoded(&entry.resolved)?,
+            })
+        }
+
+        Ok(AddressResolutionStatement {
+            height: u64::from_str(&dto.height)?,
+            unresolved: to_unresolved_address(&dto.unresolved)?,
+            resolution_entries,
+        })
+    }
+
+    pub fn to_mosaic_resolution_statement(&self) -> Result<MosaicResolutionStatement> {
+        let dto = &self.statement;
+
+        let mut resolution_entries = vec![];
+        for entry in dto.resolution_entries.iter() {
+            resolution_entries.push(MosaicResolutionEntry {
+                source: entry.source.to_compact(),
+                resolved: MosaicId::from_hex(&entry.resolved)?,
+            })
+        }
+
+        Ok(MosaicResolutionStatement {
+            height: u64::from_str(&dto.height)?,
+            unresolved: to_unresolved_mosaic(&dto.unresolved)?,
+            resolution_entries,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::model_dto::{Pagination, ResolutionStatementInfoDto};
+use crate::receipt::{AddressResolutionStatement, MosaicResolutionStatement};
+
+#[derive(Serialize, Deserialize)]
+pub struct ResolutionStatementPageDto {
+    /// Array of resolution statements.
+    pub data: Vec<ResolutionStatementInfoDto>,
+    pub pagination: Pagination,
+}
+
+impl ResolutionStatementPageDto {
+    pub fn to_address_resolution_statements(&self) -> Result<Vec<AddressResolutionStatement>> {
+        let mut statements = vec![];
+
+        for info in self.data.iter() {
+            statements.push(info.to_address_resolution_statement()?)
+        }
+
+        Ok(statements)
+    }
+
+    pub fn to_mosaic_resolution_statements(&self) -> Result<Vec<MosaicResolutionStatement>> {
+        let mut statements = vec![];
+
+        for info in self.data.iter() {
+            statements.push(info.to_mosaic_resolution_statement()?)
+        }
+
+        Ok(statements)
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::account::{AccountLinkVotingKey, SupplementalPublicKeys};
+use crate::H256;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SupplementalPublicKeysDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked: Option<AccountLinkPublicKeyDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node: Option<AccountLinkPublicKeyDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vrf: Option<AccountLinkPublicKeyDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voting: Option<AccountLinkVotingKeysDto>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountLinkPublicKeyDto {
+    /// Public key.
+    pub public_key: String,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountLinkVotingKeysDto {
+    pub public_keys: Vec<AccountLinkVotingKeyDto>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountLinkVotingKeyDto {
+    /// Public key.
+    pub public_key: String,
+    /// Finalization Epoch.
+    pub start_epoch: u32,
+    /// Finalization Epoch.
+    pub end_epoch: u32,
+}
+
+impl SupplementalPublicKeysDto {
+    pub fn to_compact(&self) -> Result<SupplementalPublicKeys> {
+        let mut voting = vec![];
+        if let Some(dto) = &self.voting {
+            for key in dto.public_keys.iter() {
+                voting.push(AccountLinkVotingKey {
+                    public_key: H256::from_str(&key.public_key)?,
+                    start_epoch: key.start_epoch,
+                    end_epoch: key.end_epoch,
+                })
+            }
+        }
+
+        Ok(SupplementalPublicKeys {
+            linked: link_public_key_to_compact(&self.linked)?,
+            node: link_public_key_to_compact(&self.node)?,
+            vrf: link_public_key_to_compact(&self.vrf)?,
+            voting,
+        })
+    }
+}
+
+fn link_public_key_to_compact(dto: &Option<AccountLinkPublicKeyDto>) -> Result<Option<H256>> {
+    match dto {
+        Some(dto) => Ok(Some(H256::from_str(&dto.public_key)?)),
+        None => Ok(None),
+    }
+}
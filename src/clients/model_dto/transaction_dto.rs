@@ -0,0 +1,649 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use crypto::prelude::PublicKey;
+
+use crate::account::{Address, PublicAccount, UnresolvedAddress};
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::model_dto::{MosaicAmountDto, TransactionInfoDto};
+use crate::mosaic::{Mosaic, MosaicFlags, MosaicId, MosaicNonce, MosaicSupplyChangeAction};
+use crate::namespace::{AliasAction, NamespaceId, NamespaceRegistrationType};
+use crate::network::NetworkType;
+use crate::restriction::{AccountRestrictionFlags, MosaicRestrictionType};
+use crate::transaction::{
+    message_from_bytes, AccountAddressRestrictionTransaction, AccountKeyLinkTransaction,
+    AccountMetadataTransaction, AccountMosaicRestrictionTransaction,
+    AccountOperationRestrictionTransaction, AddressAliasTransaction, AggregateTransaction,
+    AggregateTransactionCosignature, CommonTransaction, HashLockTransaction, LinkAction,
+    LockHashAlgorithm, MosaicAddressRestrictionTransaction, MosaicAliasTransaction,
+    MosaicDefinitionTransaction, MosaicGlobalRestrictionTransaction, MosaicMetadataTransaction,
+    MosaicSupplyChangeTransaction, MultisigAccountModificationTransaction,
+    NamespaceMetadataTransaction, NamespaceRegistrationTransaction, NodeKeyLinkTransaction,
+    SecretLockTransaction, SecretProofTransaction, Transaction, TransactionType,
+    TransactionVersion, TransferTransaction, VotingKeyLinkTransaction, VrfKeyLinkTransaction,
+};
+use crate::{Deadline, Uint64, H256};
+
+/// The fields of a transaction, only the fields of the transaction type are present.
+///
+/// Embedded transactions of an aggregate have no signature, max fee and deadline.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionDto {
+    /// Entity's signature generated by the signer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// Public key of the signer.
+    pub signer_public_key: String,
+    /// Entity version.
+    pub version: u8,
+    /// Network type.
+    pub network: u8,
+    #[serde(rename = "type")]
+    pub _type: u16,
+    /// Absolute amount. An amount of 123456789 (absolute) for a mosaic with divisibility 6 means 123.456789 (relative).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee: Option<String>,
+    /// Number of milliseconds elapsed since the creation of the nemesis block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+    /// Address expressed in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaics: Option<Vec<MosaicAmountDto>>,
+    /// Message type and hexadecimal payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// - 0 (rootNamespace) - Root namespace.
+    /// - 1 (subNamespace) - Subnamespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_type: Option<u8>,
+    /// Duration expressed in number of blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    /// Namespace identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Namespace or mosaic identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Namespace name in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespace identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace_id: Option<String>,
+    /// Address expressed in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Mosaic identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<String>,
+    /// - 0 (unlink) - Unlink alias.
+    /// - 1 (link) - Link alias.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias_action: Option<u8>,
+    /// Random nonce used to generate the mosaic id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u32>,
+    /// Mosaic flags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u8>,
+    /// Determines up to what decimal place the mosaic can be divided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divisibility: Option<u8>,
+    /// Absolute amount of the supply change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<String>,
+    /// - 0 (decrease) - Decrease the supply.
+    /// - 1 (increase) - Increase the supply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<u8>,
+    /// Number of signatures needed to remove a cosignatory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_removal_delta: Option<i8>,
+    /// Number of signatures needed to approve a transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_approval_delta: Option<i8>,
+    /// Cosignatory addresses to add.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_additions: Option<Vec<String>>,
+    /// Cosignatory addresses to remove.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_deletions: Option<Vec<String>>,
+    /// Absolute amount of the locked mosaic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Hash of the aggregate bonded transaction locked by a hash lock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Secret of a secret lock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Algorithm used to hash the proof of a secret lock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_algorithm: Option<u8>,
+    /// Original random set of bytes in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<String>,
+    /// Account restriction flags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction_flags: Option<u16>,
+    /// Account restriction values to add.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction_additions: Option<Vec<AccountRestrictionValueDto>>,
+    /// Account restriction values to remove.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction_deletions: Option<Vec<AccountRestrictionValueDto>>,
+    /// Mosaic identifier providing the restriction value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_mosaic_id: Option<String>,
+    /// Mosaic restriction key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_restriction_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_restriction_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_restriction_type: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_restriction_type: Option<u8>,
+    /// Address expressed in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_address: Option<String>,
+    /// Metadata key scoped to source, target and type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoped_metadata_key: Option<String>,
+    /// Mosaic identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_mosaic_id: Option<String>,
+    /// Namespace identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_namespace_id: Option<String>,
+    /// Change in value size in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_size_delta: Option<i16>,
+    /// Metadata value in hexadecimal base.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Linked public key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_public_key: Option<String>,
+    /// - 0 (unlink) - Unlink account.
+    /// - 1 (link) - Link account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_action: Option<u8>,
+    /// Finalization epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_epoch: Option<u32>,
+    /// Finalization epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_epoch: Option<u32>,
+    /// Embedded transactions of an aggregate transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Vec<TransactionInfoDto>>,
+    /// Cosignatures of an aggregate transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cosignatures: Option<Vec<CosignatureDto>>,
+}
+
+/// An account restriction value, an address or mosaic id string or a transaction type.
+///
+#[serde(untagged)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum AccountRestrictionValueDto {
+    TransactionType(u16),
+    Value(String),
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CosignatureDto {
+    /// Version of the cosignature.
+    pub version: String,
+    /// Public key of the cosigner.
+    pub signer_public_key: String,
+    /// Cosignature of the aggregate transaction hash.
+    pub signature: String,
+}
+
+impl TransactionDto {
+    pub fn to_compact(&self) -> Result<Box<dyn Transaction>> {
+        let common = self.common()?;
+
+        let transaction: Box<dyn Transaction> = match common.transaction_type {
+            TransactionType::Transfer => Box::new(TransferTransaction {
+                recipient: to_unresolved_address(Self::field(
+                    &self.recipient_address,
+                    "recipientAddress",
+                )?)?,
+                mosaics: self
+                    .mosaics
+                    .iter()
+                    .flatten()
+                    .map(|mosaic| Self::mosaic(&mosaic.id, &mosaic.amount))
+                    .collect::<Result<_>>()?,
+                message: message_from_bytes(&match &self.message {
+                    Some(message) => hex::decode(message)?,
+                    None => vec![],
+                }),
+                common,
+            }),
+            TransactionType::RegisterNamespace => {
+                let registration_type = NamespaceRegistrationType::try_from(*Self::field(
+                    &self.registration_type,
+                    "registrationType",
+                )?)?;
+                let (duration, parent_id) = match registration_type {
+                    NamespaceRegistrationType::RootNamespace => (
+                        Some(Uint64::from_str(Self::field(&self.duration, "duration")?)?),
+                        None,
+                    ),
+                    NamespaceRegistrationType::SubNamespace => (
+                        None,
+                        Some(NamespaceId::from_hex(Self::field(
+                            &self.parent_id,
+                            "parentId",
+                        )?)?),
+                    ),
+                };
+
+                Box::new(NamespaceRegistrationTransaction {
+                    registration_type,
+                    namespace_name: String::from_utf8(hex::decode(Self::field(
+                        &self.name, "name",
+                    )?)?)?,
+                    id: NamespaceId::from_hex(Self::field(&self.id, "id")?)?,
+                    duration,
+                    parent_id,
+                    common,
+                })
+            }
+            TransactionType::AddressAlias => Box::new(AddressAliasTransaction {
+                alias_action: self.alias_action()?,
+                namespace_id: self.namespace_id()?,
+                address: Address::from_encoded(Self::field(&self.address, "address")?)?,
+                common,
+            }),
+            TransactionType::MosaicAlias => Box::new(MosaicAliasTransaction {
+                alias_action: self.alias_action()?,
+                namespace_id: self.namespace_id()?,
+                mosaic_id: MosaicId::from_hex(Self::field(&self.mosaic_id, "mosaicId")?)?,
+                common,
+            }),
+            TransactionType::MosaicDefinition => Box::new(MosaicDefinitionTransaction {
+                nonce: MosaicNonce::from(Self::field(&self.nonce, "nonce")?.to_le_bytes()),
+                mosaic_id: MosaicId::from_hex(Self::field(&self.id, "id")?)?,
+                flags: MosaicFlags::from(*Self::field(&self.flags, "flags")?),
+                divisibility: *Self::field(&self.divisibility, "divisibility")?,
+                duration: Uint64::from_str(Self::field(&self.duration, "duration")?)?,
+                common,
+            }),
+            TransactionType::MosaicSupplyChange => Box::new(MosaicSupplyChangeTransaction {
+                mosaic_id: to_unresolved_mosaic(Self::field(&self.mosaic_id, "mosaicId")?)?,
+                action: MosaicSupplyChangeAction::try_from(*Self::field(&self.action, "action")?)?,
+                delta: Uint64::from_str(Self::field(&self.delta, "delta")?)?,
+                common,
+            }),
+            TransactionType::MultisigAccountModify => {
+                Box::new(MultisigAccountModificationTransaction {
+                    min_approval_delta: *Self::field(&self.min_approval_delta, "minApprovalDelta")?,
+                    min_removal_delta: *Self::field(&self.min_removal_delta, "minRemovalDelta")?,
+                    address_additions: Self::addresses(&self.address_additions)?,
+                    address_deletions: Self::addresses(&self.address_deletions)?,
+                    common,
+                })
+            }
+            TransactionType::Hash_Lock => Box::new(HashLockTransaction {
+                mosaic: self.locked_mosaic()?,
+                duration: Uint64::from_str(Self::field(&self.duration, "duration")?)?,
+                hash: H256::from_str(Self::field(&self.hash, "hash")?)?,
+                common,
+            }),
+            TransactionType::SecretLock => Box::new(SecretLockTransaction {
+                mosaic: self.locked_mosaic()?,
+                duration: Uint64::from_str(Self::field(&self.duration, "duration")?)?,
+                hash_algorithm: self.hash_algorithm()?,
+                secret: H256::from_str(Self::field(&self.secret, "secret")?)?,
+                recipient: to_unresolved_address(Self::field(
+                    &self.recipient_address,
+                    "recipientAddress",
+                )?)?,
+                common,
+            }),
+            TransactionType::SecretProof => Box::new(SecretProofTransaction {
+                hash_algorithm: self.hash_algorithm()?,
+                secret: H256::from_str(Self::field(&self.secret, "secret")?)?,
+                recipient: to_unresolved_address(Self::field(
+                    &self.recipient_address,
+                    "recipientAddress",
+                )?)?,
+                proof: hex::decode(Self::field(&self.proof, "proof")?)?,
+                common,
+            }),
+            TransactionType::AccountRestrictionAddress => {
+                Box::new(AccountAddressRestrictionTransaction {
+                    restriction_flags: self.restriction_flags()?,
+                    restriction_additions: Self::restriction_values(
+                        &self.restriction_additions,
+                        |value| to_unresolved_address(value.as_str()?),
+                    )?,
+                    restriction_deletions: Self::restriction_values(
+                        &self.restriction_deletions,
+                        |value| to_unresolved_address(value.as_str()?),
+                    )?,
+                    common,
+                })
+            }
+            TransactionType::AccountRestrictionMosaic => {
+                Box::new(AccountMosaicRestrictionTransaction {
+                    restriction_flags: self.restriction_flags()?,
+                    restriction_additions: Self::restriction_values(
+                        &self.restriction_additions,
+                        |value| to_unresolved_mosaic(value.as_str()?),
+                    )?,
+                    restriction_deletions: Self::restriction_values(
+                        &self.restriction_deletions,
+                        |value| to_unresolved_mosaic(value.as_str()?),
+                    )?,
+                    common,
+                })
+            }
+            TransactionType::AccountRestrictionOperation => {
+                Box::new(AccountOperationRestrictionTransaction {
+                    restriction_flags: self.restriction_flags()?,
+                    restriction_additions: Self::restriction_values(
+                        &self.restriction_additions,
+                        |value| TransactionType::try_from(value.as_transaction_type()?),
+                    )?,
+                    restriction_deletions: Self::restriction_values(
+                        &self.restriction_deletions,
+                        |value| TransactionType::try_from(value.as_transaction_type()?),
+                    )?,
+                    common,
+                })
+            }
+            TransactionType::MosaicGlobalRestriction => {
+                Box::new(MosaicGlobalRestrictionTransaction {
+                    mosaic_id: to_unresolved_mosaic(Self::field(&self.mosaic_id, "mosaicId")?)?,
+                    reference_mosaic_id: to_unresolved_mosaic(Self::field(
+                        &self.reference_mosaic_id,
+                        "referenceMosaicId",
+                    )?)?,
+                    restriction_key: self.restriction_key()?,
+                    previous_restriction_value: self.previous_restriction_value()?,
+                    new_restriction_value: self.new_restriction_value()?,
+                    previous_restriction_type: MosaicRestrictionType::try_from(*Self::field(
+                        &self.previous_restriction_type,
+                        "previousRestrictionType",
+                    )?)?,
+                    new_restriction_type: MosaicRestrictionType::try_from(*Self::field(
+                        &self.new_restriction_type,
+                        "newRestrictionType",
+                    )?)?,
+                    common,
+                })
+            }
+            TransactionType::MosaicAddressRestriction => {
+                Box::new(MosaicAddressRestrictionTransaction {
+                    mosaic_id: to_unresolved_mosaic(Self::field(&self.mosaic_id, "mosaicId")?)?,
+                    restriction_key: self.restriction_key()?,
+                    previous_restriction_value: self.previous_restriction_value()?,
+                    new_restriction_value: self.new_restriction_value()?,
+                    target_address: self.target_address()?,
+                    common,
+                })
+            }
+            TransactionType::AccountMetadata => Box::new(AccountMetadataTransaction {
+                target_address: self.target_address()?,
+                scoped_metadata_key: self.scoped_metadata_key()?,
+                value_size_delta: self.value_size_delta()?,
+                value: self.metadata_value()?,
+                common,
+            }),
+            TransactionType::MosaicMetadata => Box::new(MosaicMetadataTransaction {
+                target_address: self.target_address()?,
+                scoped_metadata_key: self.scoped_metadata_key()?,
+                target_mosaic_id: to_unresolved_mosaic(Self::field(
+                    &self.target_mosaic_id,
+                    "targetMosaicId",
+                )?)?,
+                value_size_delta: self.value_size_delta()?,
+                value: self.metadata_value()?,
+                common,
+            }),
+            TransactionType::NamespaceMetadata => Box::new(NamespaceMetadataTransaction {
+                target_address: self.target_address()?,
+                scoped_metadata_key: self.scoped_metadata_key()?,
+                target_namespace_id: NamespaceId::from_hex(Self::field(
+                    &self.target_namespace_id,
+                    "targetNamespaceId",
+                )?)?,
+                value_size_delta: self.value_size_delta()?,
+                value: self.metadata_value()?,
+                common,
+            }),
+            TransactionType::AccountKeyLink => Box::new(AccountKeyLinkTransaction {
+                linked_public_key: self.linked_public_key()?,
+                link_action: self.link_action()?,
+                common,
+            }),
+            TransactionType::NodeKeyLink => Box::new(NodeKeyLinkTransaction {
+                linked_public_key: self.linked_public_key()?,
+                link_action: self.link_action()?,
+                common,
+            }),
+            TransactionType::VrfKeyLink => Box::new(VrfKeyLinkTransaction {
+                linked_public_key: self.linked_public_key()?,
+                link_action: self.link_action()?,
+                common,
+            }),
+            TransactionType::VotingKeyLink => Box::new(VotingKeyLinkTransaction {
+                linked_public_key: self.linked_public_key()?,
+                start_epoch: *Self::field(&self.start_epoch, "startEpoch")?,
+                end_epoch: *Self::field(&self.end_epoch, "endEpoch")?,
+                link_action: self.link_action()?,
+                common,
+            }),
+            TransactionType::AggregateComplete | TransactionType::AggregateBonded => {
+                let network_type = common.network_type;
+
+                Box::new(AggregateTransaction {
+                    inner_transactions: self
+                        .transactions
+                        .iter()
+                        .flatten()
+                        .map(TransactionInfoDto::to_compact)
+                        .collect::<Result<_>>()?,
+                    cosignatures: self
+                        .cosignatures
+                        .iter()
+                        .flatten()
+                        .map(|cosignature| cosignature.to_compact(network_type))
+                        .collect::<Result<_>>()?,
+                    common,
+                })
+            }
+            transaction_type => bail!(
+                "Transaction type {} is not supported yet.",
+                transaction_type
+            ),
+        };
+
+        Ok(transaction)
+    }
+
+    fn common(&self) -> Result<CommonTransaction> {
+        let network_type = NetworkType::try_from(self.network)?;
+
+        let mut common = CommonTransaction::create_from_type(
+            TransactionType::try_from(self._type)?,
+            network_type,
+            TransactionVersion::from(self.version),
+            match &self.deadline {
+                Some(deadline) => Deadline::from(u64::from_str(deadline)?),
+                None => Deadline::default(),
+            },
+            match &self.max_fee {
+                Some(max_fee) => u64::from_str(max_fee)?,
+                None => 0,
+            },
+        );
+        common.signature = self.signature.clone();
+        common.signer = Some(PublicAccount::from_public_key(
+            &self.signer_public_key,
+            network_type,
+        )?);
+
+        Ok(common)
+    }
+
+    fn mosaic(id: &str, amount: &str) -> Result<Mosaic> {
+        Ok(Mosaic {
+            id: to_unresolved_mosaic(id)?,
+            amount: Uint64::from_str(amount)?,
+        })
+    }
+
+    fn locked_mosaic(&self) -> Result<Mosaic> {
+        Self::mosaic(
+            Self::field(&self.mosaic_id, "mosaicId")?,
+            Self::field(&self.amount, "amount")?,
+        )
+    }
+
+    fn addresses(addresses: &Option<Vec<String>>) -> Result<Vec<Box<dyn UnresolvedAddress>>> {
+        addresses
+            .iter()
+            .flatten()
+            .map(|address| to_unresolved_address(address))
+            .collect()
+    }
+
+    fn restriction_values<T>(
+        values: &Option<Vec<AccountRestrictionValueDto>>,
+        to_value: impl Fn(&AccountRestrictionValueDto) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        values.iter().flatten().map(to_value).collect()
+    }
+
+    fn alias_action(&self) -> Result<AliasAction> {
+        AliasAction::try_from(*Self::field(&self.alias_action, "aliasAction")?)
+    }
+
+    fn namespace_id(&self) -> Result<NamespaceId> {
+        NamespaceId::from_hex(Self::field(&self.namespace_id, "namespaceId")?)
+    }
+
+    fn hash_algorithm(&self) -> Result<LockHashAlgorithm> {
+        LockHashAlgorithm::try_from(*Self::field(&self.hash_algorithm, "hashAlgorithm")?)
+    }
+
+    fn restriction_flags(&self) -> Result<AccountRestrictionFlags> {
+        AccountRestrictionFlags::try_from(*Self::field(
+            &self.restriction_flags,
+            "restrictionFlags",
+        )?)
+    }
+
+    fn restriction_key(&self) -> Result<Uint64> {
+        Ok(Uint64::try_from(
+            Self::field(&self.restriction_key, "restrictionKey")?.as_str(),
+        )?)
+    }
+
+    fn previous_restriction_value(&self) -> Result<Uint64> {
+        Ok(Uint64::from_str(Self::field(
+            &self.previous_restriction_value,
+            "previousRestrictionValue",
+        )?)?)
+    }
+
+    fn new_restriction_value(&self) -> Result<Uint64> {
+        Ok(Uint64::from_str(Self::field(
+            &self.new_restriction_value,
+            "newRestrictionValue",
+        )?)?)
+    }
+
+    fn target_address(&self) -> Result<Box<dyn UnresolvedAddress>> {
+        to_unresolved_address(Self::field(&self.target_address, "targetAddress")?)
+    }
+
+    fn scoped_metadata_key(&self) -> Result<Uint64> {
+        Ok(Uint64::try_from(
+            Self::field(&self.scoped_metadata_key, "scopedMetadataKey")?.as_str(),
+        )?)
+    }
+
+    fn value_size_delta(&self) -> Result<i16> {
+        Ok(*Self::field(&self.value_size_delta, "valueSizeDelta")?)
+    }
+
+    fn metadata_value(&self) -> Result<Vec<u8>> {
+        Ok(hex::decode(Self::field(&self.value, "value")?)?)
+    }
+
+    fn linked_public_key(&self) -> Result<PublicKey> {
+        let linked_public_key = Self::field(&self.linked_public_key, "linkedPublicKey")?;
+        PublicKey::from_str(linked_public_key)
+            .map_err(|_| anyhow!("Invalid linked public key {}.", linked_public_key))
+    }
+
+    fn link_action(&self) -> Result<LinkAction> {
+        LinkAction::try_from(*Self::field(&self.link_action, "linkAction")?)
+    }
+
+    fn field<'a, T>(value: &'a Option<T>, name: &str) -> Result<&'a T> {
+        value
+            .as_ref()
+            .ok_or_else(|| anyhow!("Transaction field {} is missing.", name))
+    }
+}
+
+impl AccountRestrictionValueDto {
+    fn as_str(&self) -> Result<&str> {
+        match self {
+            Self::Value(value) => Ok(value),
+            Self::TransactionType(value) => Err(anyhow!(
+                "Invalid account restriction value {}, expected an address or mosaic id.",
+                value
+            )),
+        }
+    }
+
+    fn as_transaction_type(&self) -> Result<u16> {
+        match self {
+            Self::TransactionType(value) => Ok(*value),
+            Self::Value(value) => Err(anyhow!(
+                "Invalid account restriction value {}, expected a transaction type.",
+                value
+            )),
+        }
+    }
+}
+
+impl CosignatureDto {
+    pub fn to_compact(&self, network_type: NetworkType) -> Result<AggregateTransactionCosignature> {
+        let signer = PublicAccount::from_public_key(&self.signer_public_key, network_type)?;
+
+        Ok(AggregateTransactionCosignature {
+            version: u64::from_str(&self.version)?,
+            ..AggregateTransactionCosignature::create(&self.signature, signer)?
+        })
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::model_dto::TransactionDto;
+use crate::transaction::{Transaction, TransactionInfo};
+use crate::H256;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionInfoDto {
+    pub id: String,
+    pub meta: TransactionMetaDto,
+    pub transaction: TransactionDto,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionMetaDto {
+    /// Height of the blockchain.
+    pub height: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merkle_component_hash: Option<String>,
+    /// Transaction index within the block or within the aggregate transaction.
+    pub index: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_id: Option<String>,
+}
+
+impl TransactionInfoDto {
+    pub fn to_compact(&self) -> Result<Box<dyn Transaction>> {
+        let mut transaction = self.transaction.to_compact()?;
+        transaction.common_mut().transaction_info = Some(self.meta.to_compact(&self.id)?);
+
+        Ok(transaction)
+    }
+}
+
+impl TransactionMetaDto {
+    pub fn to_compact(&self, id: &str) -> Result<TransactionInfo> {
+        Ok(TransactionInfo {
+            height: self.height.parse()?,
+            index: self.index,
+            id: id.to_owned(),
+            hash: match &self.hash {
+                Some(hash) => Some(H256::from_str(hash)?),
+                None => None,
+            },
+            merkle_component_hash: match &self.merkle_component_hash {
+                Some(hash) => Some(H256::from_str(hash)?),
+                None => None,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::Value;
+
+    use crate::transaction::{create_transaction_hash, AggregateTransaction, TransactionType};
+    use crate::GenerationHash;
+
+    use super::TransactionInfoDto;
+
+    const GENERATION_HASH: &str =
+        "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+    /// TestNet transactions signed offline with the SDK test account, written in the REST JSON
+    /// format together with their catbuffer payload. They are not captured from a node.
+    ///
+    const FIXTURES: &str = include_str!("fixtures/transaction_info.json");
+
+    fn fixture(name: &str) -> (TransactionInfoDto, String) {
+        let fixtures: Vec<Value> = serde_json::from_str(FIXTURES).unwrap();
+        let fixture = fixtures
+            .into_iter()
+            .find(|fixture| fixture["name"] == name)
+            .unwrap();
+
+        (
+            serde_json::from_value(fixture["info"].clone()).unwrap(),
+            fixture["payload"].as_str().unwrap().to_owned(),
+        )
+    }
+
+    fn assert_fixture(name: &str, transaction_type: TransactionType) {
+        let (dto, payload) = fixture(name);
+        let generation_hash = GenerationHash::from_str(GENERATION_HASH).unwrap();
+
+        let transaction = dto.to_compact().unwrap();
+        assert_eq!(transaction.common().transaction_type, transaction_type);
+        assert_eq!(transaction.serialize(), payload);
+
+        let hash = create_transaction_hash(&payload, generation_hash).unwrap();
+        assert_eq!(
+            format!("{:X}", hash),
+            dto.meta.hash.as_deref().unwrap().to_owned()
+        );
+        assert_eq!(
+            transaction.common().transaction_info.as_ref().unwrap().hash,
+            Some(hash)
+        );
+    }
+
+    #[test]
+    fn test_should_map_transfer_transaction_info() {
+        assert_fixture("transfer", TransactionType::Transfer);
+    }
+
+    #[test]
+    fn test_should_map_namespace_registration_transaction_info() {
+        assert_fixture(
+            "namespace_registration_root",
+            TransactionType::RegisterNamespace,
+        );
+    }
+
+    #[test]
+    fn test_should_map_account_operation_restriction_transaction_info() {
+        assert_fixture(
+            "account_operation_restriction",
+            TransactionType::AccountRestrictionOperation,
+        );
+    }
+
+    #[test]
+    fn test_should_map_aggregate_transaction_info() {
+        assert_fixture("aggregate_complete", TransactionType::AggregateComplete);
+
+        let (dto, _) = fixture("aggregate_complete");
+
+        let aggregate = dto
+            .to_compact()
+            .unwrap()
+            .try_downcast::<AggregateTransaction>()
+            .unwrap();
+
+        assert_eq!(aggregate.inner_transactions.len(), 3);
+        assert_eq!(aggregate.cosignatures.len(), 1);
+
+        let inner_types: Vec<TransactionType> = aggregate
+            .inner_transactions
+            .iter()
+            .map(|transaction| transaction.common().transaction_type)
+            .collect();
+        assert_eq!(
+            inner_types,
+            vec![
+                TransactionType::Transfer,
+                TransactionType::AccountMetadata,
+                TransactionType::MosaicAlias
+            ]
+        );
+
+        for (index, inner_transaction) in aggregate.inner_transactions.iter().enumerate() {
+            let info = inner_transaction
+                .common()
+                .transaction_info
+                .as_ref()
+                .unwrap();
+            assert_eq!(info.index, index as u32);
+            assert_eq!(info.height, 1234);
+        }
+    }
+
+    #[test]
+    fn test_should_fail_if_transaction_field_is_missing() {
+        let (mut dto, _) = fixture("transfer");
+        dto.transaction.recipient_address = None;
+
+        assert_eq!(
+            dto.to_compact().unwrap_err().to_string(),
+            "Transaction field recipientAddress is missing."
+        );
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::model_dto::{ReceiptDto, ReceiptSourceDto};
+use crate::receipt::TransactionStatement;
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionStatementInfoDto {
+    /// Internal resource identifier.
+    pub id: String,
+    pub statement: TransactionStatementDto,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionStatementDto {
+    /// Height of the blockchain.
+    pub height: String,
+    pub source: ReceiptSourceDto,
+    /// Array of receipts.
+    pub receipts: Vec<ReceiptDto>,
+}
+
+impl TransactionStatementInfoDto {
+    pub fn to_compact(&self) -> Result<TransactionStatement> {
+        let dto = &self.statement;
+
+        let mut receipts = vec![];
+        for receipt in dto.receipts.iter() {
+            receipts.push(receipt.to_compact()?)
+        }
+
+        Ok(TransactionStatement {
+            height: u64::from_str(&dto.height)?,
+            source: dto.source.to_compact(),
+            receipts,
+        })
+    }
+}
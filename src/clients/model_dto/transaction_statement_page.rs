@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::Result;
+
+use crate::model_dto::{Pagination, TransactionStatementInfoDto};
+use crate::receipt::TransactionStatement;
+
+#[derive(Serialize, Deserialize)]
+pub struct TransactionStatementPageDto {
+    /// Array of transaction statements.
+    pub data: Vec<TransactionStatementInfoDto>,
+    pub pagination: Pagination,
+}
+
+impl TransactionStatementPageDto {
+    pub fn to_compact(&self) -> Result<Vec<TransactionStatement>> {
+        let mut statements = vec![];
+
+        for info in self.data.iter() {
+            statements.push(info.to_compact()?)
+        }
+
+        Ok(statements)
+    }
+}
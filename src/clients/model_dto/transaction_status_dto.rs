@@ -0,0 +1,47 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::transaction::{TransactionGroup, TransactionStatus};
+use crate::{Deadline, H256};
+
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionStatusDto {
+    pub group: TransactionGroup,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<String>,
+}
+
+impl TransactionStatusDto {
+    pub fn to_compact(&self) -> Result<TransactionStatus> {
+        Ok(TransactionStatus {
+            group: self.group,
+            code: self.code.clone(),
+            hash: H256::from_str(&self.hash)?,
+            deadline: match &self.deadline {
+                Some(deadline) => Some(Deadline::from(deadline.parse::<u64>()?)),
+                None => None,
+            },
+            height: match &self.height {
+                Some(height) => Some(height.parse::<u64>()?),
+                None => None,
+            },
+        })
+    }
+}
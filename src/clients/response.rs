@@ -14,6 +14,18 @@ pub struct SymbolError {
     pub message: String,
 }
 
+impl SymbolError {
+    /// The error code returned by the node with http status 404.
+    ///
+    pub const RESOURCE_NOT_FOUND: &'static str = "ResourceNotFound";
+
+    /// Returns true if the node does not know the requested resource.
+    ///
+    pub fn is_resource_not_found(&self) -> bool {
+        self.code == Self::RESOURCE_NOT_FOUND
+    }
+}
+
 impl std::error::Error for SymbolError {}
 
 impl std::fmt::Display for SymbolError {
@@ -0,0 +1,28 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub enum AccountOrderBy {
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "balance")]
+    Balance,
+}
+
+impl fmt::Display for AccountOrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccountOrderBy::Id => write!(f, "id"),
+            AccountOrderBy::Balance => write!(f, "balance"),
+        }
+    }
+}
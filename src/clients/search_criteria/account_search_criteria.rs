@@ -0,0 +1,34 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::mosaic::MosaicId;
+use crate::ParamSearchCriteria;
+
+use super::account_order_by::AccountOrderBy;
+
+/// Defines the params used to search accounts. With this criteria, you can sort and filter
+/// account queries using rest.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccountSearchCriteria {
+    /// Filter by accounts owning the mosaic.
+    /// Required to order by balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<MosaicId>,
+
+    /// Order by account id or balance.
+    /// Sort responses by the property set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<AccountOrderBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<ParamSearchCriteria>,
+}
@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::account::Address;
+use crate::metadata::MetadataType;
+use crate::mosaic::UnresolvedMosaicId;
+use crate::{ParamSearchCriteria, Uint64};
+
+/// Defines the params used to search metadata. With this criteria, you can sort and filter
+/// metadata queries using rest.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MetadataSearchCriteria {
+    /// Filter by the address of the account that created the metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_address: Option<Address>,
+
+    /// Filter by the address of the account the metadata is attached to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_address: Option<Address>,
+
+    /// Filter by metadata key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scoped_metadata_key: Option<Uint64>,
+
+    /// Filter by the mosaic or namespace id the metadata is attached to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Box<dyn UnresolvedMosaicId>>,
+
+    /// Filter by metadata type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_type: Option<MetadataType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<ParamSearchCriteria>,
+}
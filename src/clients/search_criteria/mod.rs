@@ -8,12 +8,24 @@
  * // except according to those terms.
  */
 
+pub use self::account_order_by::*;
+pub use self::account_search_criteria::*;
 pub use self::block_order_by::*;
 pub use self::block_search_criteria::*;
+pub use self::metadata_search_criteria::*;
 pub use self::mosaic_search_criteria::*;
+pub use self::namespace_search_criteria::*;
 pub use self::param_search_criteria::*;
+pub use self::resolution_statement_search_criteria::*;
+pub use self::transaction_statement_search_criteria::*;
 
+mod account_order_by;
+mod account_search_criteria;
 mod block_order_by;
 mod block_search_criteria;
+mod metadata_search_criteria;
 mod mosaic_search_criteria;
+mod namespace_search_criteria;
 mod param_search_criteria;
+mod resolution_statement_search_criteria;
+mod transaction_statement_search_criteria;
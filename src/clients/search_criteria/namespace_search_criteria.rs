@@ -0,0 +1,39 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::account::Address;
+use crate::namespace::{AliasType, NamespaceId, NamespaceRegistrationType};
+use crate::ParamSearchCriteria;
+
+/// Defines the params used to search namespaces. With this criteria, you can sort and filter
+/// namespace queries using rest.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NamespaceSearchCriteria {
+    /// Filter by owner address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_address: Option<Address>,
+
+    /// Filter by root namespaces or sub namespaces.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_type: Option<NamespaceRegistrationType>,
+
+    /// Filter by root namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level0: Option<NamespaceId>,
+
+    /// Filter by alias type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias_type: Option<AliasType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<ParamSearchCriteria>,
+}
@@ -0,0 +1,25 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::ParamSearchCriteria;
+
+/// Defines the params used to search address and mosaic resolution statements. With this criteria,
+/// you can sort and filter resolution statement queries using rest.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ResolutionStatementSearchCriteria {
+    /// Filter by block height.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<ParamSearchCriteria>,
+}
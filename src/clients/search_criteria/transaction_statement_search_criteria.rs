@@ -0,0 +1,56 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use crate::account::Address;
+use crate::mosaic::UnresolvedMosaicId;
+use crate::receipt::ReceiptType;
+use crate::ParamSearchCriteria;
+
+/// Defines the params used to search transaction statements. With this criteria, you can sort
+/// and filter receipt queries using rest.
+///
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TransactionStatementSearchCriteria {
+    /// Filter by block height.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+
+    /// Only blocks with height greater or equal than this one are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_height: Option<u64>,
+
+    /// Only blocks with height smaller or equal than this one are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_height: Option<u64>,
+
+    /// Filter by receipt type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_type: Option<ReceiptType>,
+
+    /// Filter by address of the account receiving the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_address: Option<Address>,
+
+    /// Filter by address of the account sending the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address: Option<Address>,
+
+    /// Filter by target address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_address: Option<Address>,
+
+    /// Filter by the mosaic or namespace id of an artifact expiry receipt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifact_id: Option<Box<dyn UnresolvedMosaicId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<ParamSearchCriteria>,
+}
@@ -0,0 +1,108 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use sha3::{Digest, Sha3_256};
+
+use crate::H256;
+
+/// Merkle hash builder, calculates the merkle root hash of a list of hashes
+/// the same way catapult-server does.
+///
+#[derive(Debug, Clone, Default)]
+pub struct MerkleHashBuilder {
+    hashes: Vec<H256>,
+}
+
+impl MerkleHashBuilder {
+    /// Adds a hash to the merkle tree.
+    ///
+    pub fn update(&mut self, hash: H256) {
+        self.hashes.push(hash);
+    }
+
+    /// Calculates the merkle root hash.
+    ///
+    /// An empty tree results in a zero hash. When a level has an odd number of hashes
+    /// the last hash is paired with itself.
+    ///
+    pub fn finalize(self) -> H256 {
+        let mut hashes = self.hashes;
+
+        if hashes.is_empty() {
+            return H256::zero();
+        }
+
+        while hashes.len() > 1 {
+            hashes = hashes
+                .chunks(2)
+                .map(|pair| {
+                    let mut hash = Sha3_256::new();
+                    hash.update(pair[0].as_bytes());
+                    hash.update(pair.get(1).unwrap_or(&pair[0]).as_bytes());
+                    H256::from_slice(&hash.finalize())
+                })
+                .collect();
+        }
+
+        hashes[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::core::crypto::MerkleHashBuilder;
+    use crate::H256;
+
+    fn calculate_merkle_root(hashes: &[&str]) -> String {
+        let mut builder = MerkleHashBuilder::default();
+        hashes
+            .iter()
+            .for_each(|hash| builder.update(H256::from_str(hash).unwrap()));
+        format!("{:X}", builder.finalize())
+    }
+
+    #[test]
+    fn test_should_return_zero_hash_when_empty() {
+        assert_eq!(calculate_merkle_root(&[]), "0".repeat(64));
+    }
+
+    #[test]
+    fn test_should_return_the_hash_when_single() {
+        let hash = "215B158F0BD416B596271BCE527CD9DC8E4A639CC271D896F9156AF6F441EEB9";
+        assert_eq!(calculate_merkle_root(&[hash]), hash);
+    }
+
+    #[test]
+    fn test_should_calculate_root_of_balanced_tree() {
+        let root = calculate_merkle_root(&[
+            "215B158F0BD416B596271BCE527CD9DC8E4A639CC271D896F9156AF6F441EEB9",
+            "976C5CE6BF3F797113E5A3A094C7801C885DAF783C50563FFD3CA6A5EF580E25",
+        ]);
+        assert_eq!(
+            root,
+            "1C704E3AC99B124F92D2648649EC72C7A19EA4E2BB24F669B976180A295876FA"
+        );
+    }
+
+    #[test]
+    fn test_should_calculate_root_of_unbalanced_tree() {
+        let root = calculate_merkle_root(&[
+            "215B158F0BD416B596271BCE527CD9DC8E4A639CC271D896F9156AF6F441EEB9",
+            "976C5CE6BF3F797113E5A3A094C7801C885DAF783C50563FFD3CA6A5EF580E25",
+            "E926CC323886D47234BB0B49219C81E280E8A65748B437C2AE83B09B37A5AAF2",
+        ]);
+        assert_eq!(
+            root,
+            "15646372A7FD76613EAFA6FCEB5A7605C895E7F46FC59C00E5C61073F2481D81"
+        );
+    }
+}
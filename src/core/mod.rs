@@ -8,5 +8,6 @@
  * // except according to those terms.
  */
 
+pub(crate) mod crypto;
 pub(crate) mod format;
 pub(crate) mod utils;
\ No newline at end of file
@@ -40,24 +40,23 @@ pub fn to_unresolved_address(address: &str) -> Result<Box<dyn UnresolvedAddress>
         "Input string is not in valid hexadecimal notation."
     );
 
-    let bytes = hex_decode(&address[0..2]);
+    let bytes = hex_decode(address);
     let byte0 = &bytes[0];
 
     // If bit 0 of byte 0 is not set (like in 0x90), then it is a regular address.
     // Else (e.g. 0x91) it represents a namespace id which starts at byte 1.
-    if (byte0 & 16) == 16 {
+    if (byte0 & 1) == 1 {
         // namespaceId encoded hexadecimal notation provided
         // only 8 bytes are relevant to resolve the NamespaceId
-        let relevant_part = &address[2..16];
-        let mut relevant_part_vec = hex_decode(relevant_part);
-        relevant_part_vec.reverse();
+        let end = bytes.len().min(9);
+        let mut relevant_part = [0u8; 8];
+        relevant_part[..end - 1].copy_from_slice(&bytes[1..end]);
 
-        let higher: u32 = u32::from_str_radix(&hex::encode(&relevant_part_vec)[0..8], 16)?;
-        let lower: u32 = u32::from_str_radix(&hex::encode(relevant_part_vec)[8..], 16)?;
-
-        Ok(Box::new(NamespaceId::from([higher, lower])))
+        Ok(Box::new(NamespaceId::from(u64::from_le_bytes(
+            relevant_part,
+        ))))
     } else {
-        // most significant bit of byte 0 is not set => mosaicId
+        // bit 0 of byte 0 is not set => address
         Ok(Box::new(Address::from_encoded(address)?))
     }
 }
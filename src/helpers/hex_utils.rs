@@ -25,9 +25,12 @@ pub fn hex_decode(data: &str) -> Vec<u8> {
         .unwrap()
 }
 
-pub fn hex_to_utf8(hex: &str) -> String {
-    let decode = hex_decode(hex);
-    String::from_utf8(decode).unwrap()
+/// Decodes a hex string into UTF-8 text, returns an error if the input is not valid hex
+/// or the decoded bytes are not valid UTF-8.
+///
+pub fn try_hex_to_utf8(hex: &str) -> anyhow::Result<String> {
+    let decode = hex::decode(hex)?;
+    Ok(String::from_utf8(decode)?)
 }
 
 #[cfg(test)]
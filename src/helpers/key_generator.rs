@@ -0,0 +1,60 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{ensure, Result};
+use sha3::{Digest, Sha3_256};
+
+use crate::Uint64;
+
+/// Generates a `Uint64` key from a string, used as restriction and metadata key.
+///
+/// The key is the first 8 bytes of the Sha3-256 hash of the input in little endian
+/// with the most significant bit set.
+///
+/// # Inputs
+///
+/// * `input`: The string to derive the key from.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is an `Uint64` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn generate_uint64_key(input: &str) -> Result<Uint64> {
+    ensure!(!input.is_empty(), "Input must not be empty.");
+
+    let hash = Sha3_256::digest(input.as_bytes());
+    let mut key = [0u8; 8];
+    key.copy_from_slice(&hash[..8]);
+
+    Ok(Uint64::from(u64::from_le_bytes(key) | 1 << 63))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_uint64_key, Uint64};
+
+    #[test]
+    fn test_should_generate_uint64_key_from_string() {
+        assert_eq!(
+            generate_uint64_key("KYC").unwrap(),
+            Uint64::from(0x8112_637C_907B_DD77)
+        );
+        assert_eq!(
+            generate_uint64_key("abc").unwrap().to_hex(),
+            "B225E24FA75D983A"
+        );
+    }
+
+    #[test]
+    fn test_should_fail_for_empty_input() {
+        assert!(generate_uint64_key("").is_err());
+    }
+}
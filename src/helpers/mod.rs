@@ -12,10 +12,12 @@ pub use self::bytes_utils::*;
 pub use self::hashes::*;
 pub(crate) use self::hex_utils::*;
 pub(crate) use self::int_utils::*;
+pub use self::key_generator::*;
 pub(crate) use self::ser_der_utils::*;
 
 mod bytes_utils;
 mod hashes;
 mod hex_utils;
 mod int_utils;
+mod key_generator;
 mod ser_der_utils;
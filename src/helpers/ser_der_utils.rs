@@ -19,6 +19,14 @@ where
     PublicKey::from_str(&s).map_err(serde::de::Error::custom)
 }
 
+pub fn der_bytes_from_hex<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = serde::Deserialize::deserialize(deserializer)?;
+    hex::decode(&s).map_err(serde::de::Error::custom)
+}
+
 pub fn ser_to_id<S>(data: &Box<dyn UnresolvedMosaicId + 'static>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
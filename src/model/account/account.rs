@@ -8,6 +8,7 @@
  * // except according to those terms.
  */
 
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Debug;
 
@@ -21,7 +22,11 @@ use hex::ToHex;
 use crate::account::PublicAccount;
 use crate::message::{EncryptedMessage, PlainMessage};
 use crate::network::NetworkType;
-use crate::{is_hex, GenerationHash};
+use crate::transaction::{
+    create_transaction_hash_from_bytes, is_aggregate_payload, signing_bytes,
+    CosignatureSignedTransaction, SignedTransaction, TransactionType,
+};
+use crate::{hex_decode, is_hex, GenerationHash, TransactionHash};
 
 /// The `Account` struct contains account's `Keypair` and `PublicAccount`.
 ///
@@ -282,38 +287,151 @@ impl Account {
             .verify_signature(data.as_ref(), signature)
     }
 
+    /// Sign a serialized transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The serialized transaction to be signed.
+    /// * `generation_hash`: The network generation hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SignedTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
     pub fn sign_transaction(
         &self,
-        _transaction: Vec<u8>,
-        _generation_hash: GenerationHash,
-    ) -> Result<Vec<u8>> {
-        unimplemented!()
+        transaction: Vec<u8>,
+        generation_hash: GenerationHash,
+    ) -> Result<SignedTransaction> {
+        let mut payload = transaction;
+
+        let signing_bytes = signing_bytes(&payload, generation_hash)?;
+        let signature = self.key_pair.sign(&signing_bytes);
+
+        payload[8..72].copy_from_slice(signature.as_bytes());
+        payload[72..104].copy_from_slice(self.public_account.public_key.as_bytes());
+
+        let hash = create_transaction_hash_from_bytes(&payload, generation_hash)?;
+        let network_type = NetworkType::try_from(payload[109])?;
+        let transaction_type = TransactionType::try_from([payload[110], payload[111]])?;
+
+        Ok(SignedTransaction {
+            payload: hex::encode_upper(&payload),
+            hash,
+            signer: self.public_account,
+            transaction_type,
+            network_type,
+        })
     }
 
+    /// Sign a serialized aggregate transaction with the given cosignatories.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The serialized aggregate transaction to be signed.
+    /// * `cosignatories`: The vec of `Account` that cosign the transaction.
+    /// * `generation_hash`: The network generation hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SignedTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
     pub fn sign_transaction_with_cosignatories(
         &self,
-        _transaction: Vec<u8>,
-        _cosignatories: Vec<Account>,
-        _generation_hash: GenerationHash,
-    ) -> Result<Vec<u8>> {
-        unimplemented!()
+        transaction: Vec<u8>,
+        cosignatories: Vec<Account>,
+        generation_hash: GenerationHash,
+    ) -> Result<SignedTransaction> {
+        ensure!(
+            is_aggregate_payload(&transaction),
+            "Only aggregate transactions can be cosigned."
+        );
+
+        let signed_transaction = self.sign_transaction(transaction, generation_hash)?;
+
+        let cosignatures = cosignatories
+            .iter()
+            .map(|cosignatory| cosignatory.cosign_hash(signed_transaction.hash))
+            .collect();
+
+        append_cosignatures(signed_transaction, cosignatures)
     }
 
+    /// Sign a serialized aggregate transaction and attach the given cosignatures.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The serialized aggregate transaction to be signed.
+    /// * `cosignature_signed_transactions`: The vec of `CosignatureSignedTransaction` signed offline.
+    /// * `generation_hash`: The network generation hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SignedTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
     pub fn sign_transaction_given_signatures(
         &self,
-        _transaction: Vec<u8>,
-        _cosignature_signed_transactions: Vec<u8>,
-        _generation_hash: GenerationHash,
-    ) -> Result<Vec<u8>> {
-        unimplemented!()
+        transaction: Vec<u8>,
+        cosignature_signed_transactions: Vec<CosignatureSignedTransaction>,
+        generation_hash: GenerationHash,
+    ) -> Result<SignedTransaction> {
+        ensure!(
+            is_aggregate_payload(&transaction),
+            "Only aggregate transactions can be cosigned."
+        );
+
+        let signed_transaction = self.sign_transaction(transaction, generation_hash)?;
+
+        for cosignature in cosignature_signed_transactions.iter() {
+            ensure!(
+                cosignature.parent_hash == signed_transaction.hash,
+                "Cosignature parent hash {:X} does not match the transaction hash {:X}.",
+                cosignature.parent_hash,
+                signed_transaction.hash
+            );
+        }
+
+        append_cosignatures(signed_transaction, cosignature_signed_transactions)
     }
 
+    /// Cosign a serialized aggregate transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `cosignature_transaction`: The serialized aggregate transaction to be cosigned.
+    /// * `generation_hash`: The network generation hash.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `CosignatureSignedTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
     pub fn sign_cosignature_transaction(
         &self,
-        _cosignature_transaction: Vec<u8>,
-        _generation_hash: GenerationHash,
-    ) -> Result<Vec<u8>> {
-        unimplemented!()
+        cosignature_transaction: Vec<u8>,
+        generation_hash: GenerationHash,
+    ) -> Result<CosignatureSignedTransaction> {
+        ensure!(
+            is_aggregate_payload(&cosignature_transaction),
+            "Only aggregate transactions can be cosigned."
+        );
+
+        let hash = create_transaction_hash_from_bytes(&cosignature_transaction, generation_hash)?;
+        Ok(self.cosign_hash(hash))
+    }
+
+    fn cosign_hash(&self, hash: TransactionHash) -> CosignatureSignedTransaction {
+        let signature = self.key_pair.sign(hash.as_bytes());
+
+        CosignatureSignedTransaction {
+            parent_hash: hash,
+            signature: hex::encode_upper(signature.as_bytes()),
+            signer_public_key: self.public_key_to_hex().to_uppercase(),
+            version: 0,
+        }
     }
 }
 
@@ -329,6 +447,39 @@ impl fmt::Display for Account {
     }
 }
 
+// internal function.
+fn append_cosignatures(
+    signed_transaction: SignedTransaction,
+    cosignatures: Vec<CosignatureSignedTransaction>,
+) -> Result<SignedTransaction> {
+    let mut payload = hex_decode(&signed_transaction.payload);
+
+    for cosignature in cosignatures.iter() {
+        ensure!(
+            is_hex(&cosignature.signature) && cosignature.signature.len() == 128,
+            "Invalid cosignature signature {}.",
+            cosignature.signature
+        );
+        ensure!(
+            is_hex(&cosignature.signer_public_key) && cosignature.signer_public_key.len() == 64,
+            "Invalid cosignature signer public key {}.",
+            cosignature.signer_public_key
+        );
+
+        payload.extend_from_slice(&cosignature.version.to_le_bytes());
+        payload.extend(hex_decode(&cosignature.signer_public_key));
+        payload.extend(hex_decode(&cosignature.signature));
+    }
+
+    let size = payload.len() as u32;
+    payload[0..4].copy_from_slice(&size.to_le_bytes());
+
+    Ok(SignedTransaction {
+        payload: hex::encode_upper(&payload),
+        ..signed_transaction
+    })
+}
+
 // internal function.
 pub(crate) fn sign_data(kp: Keypair, data: &str) -> Result<Signature> {
     // ensure!(!data.is_empty(), "data cannot be empty");
@@ -478,4 +629,141 @@ pub mod tests {
             assert!(public_account.verify_signature("0x", signed_with0x).is_ok());
         }
     }
+
+    #[cfg(test)]
+    mod tests_sign_transaction {
+        use std::str::FromStr;
+
+        use crypto::prelude::Signature;
+
+        use super::*;
+        use crate::hex_decode;
+        use crate::transaction::TransactionType;
+        use crate::GenerationHash;
+
+        const GENERATION_HASH: &str =
+            "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+        fn generation_hash() -> GenerationHash {
+            GenerationHash::from_str(GENERATION_HASH).unwrap()
+        }
+
+        fn transfer_payload() -> Vec<u8> {
+            hex_decode(&format!(
+                "AD00000000000000{}0000000001A8544100000000000000000100000000000000\
+                 A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C5360D0000000000000000\
+                 746573742D6D657373616765",
+                "0".repeat(192)
+            ))
+        }
+
+        fn aggregate_payload() -> Vec<u8> {
+            let mut payload = vec![0u8; 168];
+            payload[0] = 168;
+            payload[108] = 1;
+            payload[109] = NetworkType::PrivateTest.value();
+            payload[110..112].copy_from_slice(&TransactionType::AggregateComplete.to_bytes());
+            payload[124] = 1;
+            payload
+        }
+
+        #[test]
+        fn test_should_sign_transaction() {
+            let signed_transaction = TESTING_ACCOUNT
+                .sign_transaction(transfer_payload(), generation_hash())
+                .unwrap();
+
+            assert_eq!(
+                signed_transaction.payload,
+                "AD00000000000000A873E7C5818FA8FA8FC9D338DEEFF15A5F3DEE2EE879DEB35E3D517410669BA2\
+                 CFD01ABA1FC91A3BD9945F73FC68FA3AE0418F52313F50177BECDFF1A9CBB50B9801508C58666C74\
+                 6F471538E43002B85B1CD542F9874B2861183919BA8787B60000000001A854410000000000000000\
+                 0100000000000000A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C5360D00000000000000\
+                 00746573742D6D657373616765"
+            );
+            assert_eq!(
+                format!("{:X}", signed_transaction.hash),
+                "654CDABBE1D00F911AC59120B3A04FBDF1CD15188D252EF48EBC618AEA30E53A"
+            );
+            assert_eq!(signed_transaction.signer, TESTING_ACCOUNT.public_account);
+            assert_eq!(
+                signed_transaction.transaction_type,
+                TransactionType::Transfer
+            );
+            assert_eq!(signed_transaction.network_type, NetworkType::PrivateTest);
+        }
+
+        #[test]
+        fn test_should_sign_transaction_with_cosignatories() {
+            let signed_transaction = MULTISIG_ACCOUNT
+                .sign_transaction_with_cosignatories(
+                    aggregate_payload(),
+                    vec![COSIGNATORY_ACCOUNT.clone(), COSIGNATORY2_ACCOUNT.clone()],
+                    generation_hash(),
+                )
+                .unwrap();
+
+            let payload = hex_decode(&signed_transaction.payload);
+            assert_eq!(payload.len(), 168 + 2 * 104);
+            assert_eq!(&payload[0..4], &(376u32).to_le_bytes());
+
+            let cosignature = &payload[168..272];
+            assert_eq!(&cosignature[0..8], &[0u8; 8]);
+            assert_eq!(
+                &cosignature[8..40],
+                COSIGNATORY_ACCOUNT.public_account.public_key.as_bytes()
+            );
+
+            let signature = Signature::from_slice(&cosignature[40..104]);
+            assert!(COSIGNATORY_ACCOUNT
+                .verify_signature(&format!("{:x}", signed_transaction.hash), signature)
+                .is_ok());
+        }
+
+        #[test]
+        fn test_should_sign_transaction_given_signatures() {
+            let signed_transaction = MULTISIG_ACCOUNT
+                .sign_transaction(aggregate_payload(), generation_hash())
+                .unwrap();
+
+            let cosignature = COSIGNATORY_ACCOUNT
+                .sign_cosignature_transaction(
+                    hex_decode(&signed_transaction.payload),
+                    generation_hash(),
+                )
+                .unwrap();
+            assert_eq!(cosignature.parent_hash, signed_transaction.hash);
+
+            let given_signatures = MULTISIG_ACCOUNT
+                .sign_transaction_given_signatures(
+                    aggregate_payload(),
+                    vec![cosignature],
+                    generation_hash(),
+                )
+                .unwrap();
+
+            let with_cosignatories = MULTISIG_ACCOUNT
+                .sign_transaction_with_cosignatories(
+                    aggregate_payload(),
+                    vec![COSIGNATORY_ACCOUNT.clone()],
+                    generation_hash(),
+                )
+                .unwrap();
+
+            assert_eq!(given_signatures, with_cosignatories);
+            assert_eq!(given_signatures.hash, signed_transaction.hash);
+        }
+
+        #[test]
+        #[should_panic(expected = "Only aggregate transactions can be cosigned.")]
+        fn test_should_panic_when_cosigning_non_aggregate_transaction() {
+            TESTING_ACCOUNT
+                .sign_transaction_with_cosignatories(
+                    transfer_payload(),
+                    vec![COSIGNATORY_ACCOUNT.clone()],
+                    generation_hash(),
+                )
+                .unwrap();
+        }
+    }
 }
@@ -0,0 +1,153 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::{AccountType, ActivityBucket, Address, PublicAccount, SupplementalPublicKeys};
+use crate::mosaic::{Mosaic, UnresolvedMosaicId};
+
+/// The account state, as stored on the chain.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    /// Version.
+    ///
+    pub version: u16,
+
+    /// The database record id.
+    ///
+    pub record_id: String,
+
+    /// The account address.
+    ///
+    pub address: Address,
+
+    /// The block height at which the address was first seen by the network.
+    ///
+    pub address_height: u64,
+
+    /// The account, once its public key is known by the network.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_account: Option<PublicAccount>,
+
+    /// The block height at which the public key was first seen by the network.
+    ///
+    pub public_key_height: u64,
+
+    /// The account type.
+    ///
+    pub account_type: AccountType,
+
+    /// The public keys linked to the account.
+    ///
+    pub supplemental_public_keys: SupplementalPublicKeys,
+
+    /// The account activity used by the importance calculation.
+    ///
+    pub activity_buckets: Vec<ActivityBucket>,
+
+    /// The mosaics owned by the account.
+    ///
+    pub mosaics: Vec<Mosaic>,
+
+    /// The account importance.
+    ///
+    pub importance: u64,
+
+    /// The block height at which the importance was calculated.
+    ///
+    pub importance_height: u64,
+}
+
+impl AccountInfo {
+    /// Returns the amount of the given mosaic owned by the account.
+    ///
+    /// # Inputs
+    ///
+    /// * `mosaic_id`: The mosaic id.
+    ///
+    /// # Returns
+    ///
+    /// The absolute amount, 0 if the account does not own the mosaic.
+    ///
+    pub fn get_mosaic_amount(&self, mosaic_id: &dyn UnresolvedMosaicId) -> u64 {
+        self.mosaics
+            .iter()
+            .find(|mosaic| mosaic.id.to_uint64() == mosaic_id.to_uint64())
+            .map(|mosaic| *mosaic.amount)
+            .unwrap_or_default()
+    }
+
+    /// Returns true when the account is linked to a remote account.
+    ///
+    pub fn is_linked(&self) -> bool {
+        self.account_type == AccountType::Main || self.account_type == AccountType::Remote
+    }
+}
+
+impl fmt::Display for AccountInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::{AccountInfo, AccountType, Address, SupplementalPublicKeys};
+    use crate::mosaic::{Mosaic, MosaicId};
+
+    fn account_info(account_type: AccountType) -> AccountInfo {
+        AccountInfo {
+            version: 1,
+            record_id: "5FDA2A4A39E6A6A4A1E5E3C1".to_owned(),
+            address: Address::from_raw("TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q").unwrap(),
+            address_height: 1,
+            public_account: None,
+            public_key_height: 0,
+            account_type,
+            supplemental_public_keys: SupplementalPublicKeys::default(),
+            activity_buckets: vec![],
+            mosaics: vec![
+                Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), 100).unwrap(),
+                Mosaic::create(MosaicId::from_hex("6BED913FA20223F8").unwrap(), 200).unwrap(),
+            ],
+            importance: 0,
+            importance_height: 0,
+        }
+    }
+
+    #[test]
+    fn test_should_get_mosaic_amount() {
+        let info = account_info(AccountType::Unlinked);
+
+        assert_eq!(
+            info.get_mosaic_amount(&MosaicId::from_hex("6BED913FA20223F8").unwrap()),
+            200
+        );
+        assert_eq!(
+            info.get_mosaic_amount(&MosaicId::from_hex("0DC67FBE1CAD29E3").unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_should_be_linked_for_main_and_remote_accounts() {
+        assert!(account_info(AccountType::Main).is_linked());
+        assert!(account_info(AccountType::Remote).is_linked());
+        assert!(!account_info(AccountType::Unlinked).is_linked());
+        assert!(!account_info(AccountType::RemoteUnlinked).is_linked());
+    }
+}
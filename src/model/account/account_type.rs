@@ -0,0 +1,83 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// The account type. Supported types are:
+///
+/// * 0 - Unlinked account, its importance is not delegated.
+/// * 1 - Main account, its importance is delegated to a remote account.
+/// * 2 - Remote account, harvests with the importance of a main account.
+/// * 3 - Remote account that is not linked to a main account.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum AccountType {
+    /// Account is not linked to another account.
+    Unlinked = 0x00,
+    /// Account is a balance-holding account that is linked to a remote harvester account.
+    Main = 0x01,
+    /// Account is a remote harvester account that is linked to a balance-holding account.
+    Remote = 0x02,
+    /// Account is a remote harvester eligible account that is unlinked.
+    RemoteUnlinked = 0x03,
+}
+
+impl AccountType {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'AccountType' for the given u8 value.
+///
+/// Throws an Err when the type is unknown.
+impl TryFrom<u8> for AccountType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::Unlinked as u8 => Ok(Self::Unlinked),
+            x if x == Self::Main as u8 => Ok(Self::Main),
+            x if x == Self::Remote as u8 => Ok(Self::Remote),
+            x if x == Self::RemoteUnlinked as u8 => Ok(Self::RemoteUnlinked),
+            _ => Err(anyhow!("Unknown account type {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::account::AccountType;
+
+    #[test]
+    fn test_account_type_values() {
+        assert_eq!(AccountType::Unlinked.value(), 0x00);
+        assert_eq!(AccountType::Main.value(), 0x01);
+        assert_eq!(AccountType::Remote.value(), 0x02);
+        assert_eq!(AccountType::RemoteUnlinked.value(), 0x03);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(AccountType::try_from(4).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// The activity of an account during an importance calculation period.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityBucket {
+    /// The height at which the bucket starts.
+    ///
+    pub start_height: u64,
+
+    /// The fees paid by the account during the bucket.
+    ///
+    pub total_fees_paid: u64,
+
+    /// The number of times the account was the beneficiary of a harvested block.
+    ///
+    pub beneficiary_count: u32,
+
+    /// The raw importance score of the account.
+    ///
+    pub raw_score: u64,
+}
+
+impl fmt::Display for ActivityBucket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -11,11 +11,25 @@
 //! The `account` module provides functions for creating `Address`, `PublicAccount` and `Account`.
 
 pub use self::account::*;
+pub use self::account_info::*;
+pub use self::account_names::*;
+pub use self::account_type::*;
+pub use self::activity_bucket::*;
 pub use self::address::*;
+pub use self::multisig_account_graph_info::*;
+pub use self::multisig_account_info::*;
 pub use self::public_account::*;
+pub use self::supplemental_public_keys::*;
 pub use self::unresolved_address::*;
 
 mod account;
+mod account_info;
+mod account_names;
+mod account_type;
+mod activity_bucket;
 mod address;
+mod multisig_account_graph_info;
+mod multisig_account_info;
 mod public_account;
+mod supplemental_public_keys;
 mod unresolved_address;
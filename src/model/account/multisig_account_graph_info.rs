@@ -0,0 +1,193 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use crate::account::{Address, MultisigAccountInfo};
+
+/// The multisig account graph info structure describes the information of all the
+/// multisig levels an account is involved in.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigAccountGraphInfo {
+    /// The multisig accounts info of each level.
+    ///
+    pub multisig_entries: BTreeMap<i32, Vec<MultisigAccountInfo>>,
+}
+
+impl MultisigAccountGraphInfo {
+    /// Returns the levels of the graph, in ascending order.
+    ///
+    pub fn levels(&self) -> Vec<i32> {
+        self.multisig_entries.keys().copied().collect()
+    }
+
+    /// Returns the multisig accounts info of a level.
+    ///
+    pub fn get_level(&self, level: i32) -> &[MultisigAccountInfo] {
+        self.multisig_entries
+            .get(&level)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the multisig account info of an account of the graph.
+    ///
+    pub fn get_multisig_account(&self, address: &Address) -> Option<&MultisigAccountInfo> {
+        self.multisig_entries
+            .values()
+            .flatten()
+            .find(|info| &info.account_address == address)
+    }
+
+    /// Returns every account that must cosign a transaction of the given multisig account.
+    ///
+    /// The cosignatories are walked level by level, a cosignatory that is itself a multisig
+    /// account is replaced by its own cosignatories, as it cannot sign by itself.
+    /// Each account is listed once, in the order it is reached.
+    ///
+    pub fn get_required_cosigners(&self, address: &Address) -> Vec<Address> {
+        let mut cosigners = vec![];
+        let mut visited = HashSet::new();
+        self.collect_cosigners(address, &mut visited, &mut cosigners);
+        cosigners
+    }
+
+    fn collect_cosigners(
+        &self,
+        address: &Address,
+        visited: &mut HashSet<Address>,
+        cosigners: &mut Vec<Address>,
+    ) {
+        if !visited.insert(*address) {
+            return;
+        }
+
+        let info = match self.get_multisig_account(address) {
+            Some(info) if info.is_multisig() => info,
+            _ => return,
+        };
+
+        for cosigner in info.cosignatory_addresses.iter() {
+            match self.get_multisig_account(cosigner) {
+                Some(cosigner_info) if cosigner_info.is_multisig() => {
+                    self.collect_cosigners(cosigner, visited, cosigners)
+                }
+                _ => {
+                    if visited.insert(*cosigner) {
+                        cosigners.push(*cosigner)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for MultisigAccountGraphInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::account::{Account, Address, MultisigAccountGraphInfo, MultisigAccountInfo};
+    use crate::network::NetworkType;
+
+    fn address() -> Address {
+        Account::random(NetworkType::TestNet).public_account.address
+    }
+
+    fn info(
+        account_address: Address,
+        min_approval: u32,
+        cosignatory_addresses: Vec<Address>,
+        multisig_addresses: Vec<Address>,
+    ) -> MultisigAccountInfo {
+        MultisigAccountInfo {
+            version: 1,
+            account_address,
+            min_approval,
+            min_removal: min_approval,
+            cosignatory_addresses,
+            multisig_addresses,
+        }
+    }
+
+    #[test]
+    fn test_should_get_required_cosigners_of_multilevel_multisig() {
+        let multisig = address();
+        let sub_multisig = address();
+        let cosigner_1 = address();
+        let cosigner_2 = address();
+        let cosigner_3 = address();
+
+        let mut multisig_entries = BTreeMap::new();
+        multisig_entries.insert(
+            0,
+            vec![info(multisig, 2, vec![cosigner_1, sub_multisig], vec![])],
+        );
+        multisig_entries.insert(
+            1,
+            vec![
+                info(cosigner_1, 0, vec![], vec![multisig, sub_multisig]),
+                info(
+                    sub_multisig,
+                    1,
+                    vec![cosigner_1, cosigner_2, cosigner_3],
+                    vec![multisig],
+                ),
+            ],
+        );
+        multisig_entries.insert(
+            2,
+            vec![
+                info(cosigner_2, 0, vec![], vec![sub_multisig]),
+                info(cosigner_3, 0, vec![], vec![sub_multisig]),
+            ],
+        );
+        let graph = MultisigAccountGraphInfo { multisig_entries };
+
+        assert_eq!(graph.levels(), vec![0, 1, 2]);
+        assert_eq!(graph.get_level(1).len(), 2);
+        assert!(graph.get_level(3).is_empty());
+        assert_eq!(
+            graph.get_required_cosigners(&multisig),
+            vec![cosigner_1, cosigner_2, cosigner_3]
+        );
+        assert_eq!(
+            graph.get_required_cosigners(&sub_multisig),
+            vec![cosigner_1, cosigner_2, cosigner_3]
+        );
+    }
+
+    #[test]
+    fn test_should_not_get_cosigners_of_non_multisig_account() {
+        let multisig = address();
+        let cosigner = address();
+
+        let mut multisig_entries = BTreeMap::new();
+        multisig_entries.insert(-1, vec![info(multisig, 1, vec![cosigner], vec![])]);
+        multisig_entries.insert(0, vec![info(cosigner, 0, vec![], vec![multisig])]);
+        let graph = MultisigAccountGraphInfo { multisig_entries };
+
+        assert!(graph.get_required_cosigners(&cosigner).is_empty());
+        assert!(graph.get_required_cosigners(&address()).is_empty());
+        assert_eq!(graph.get_required_cosigners(&multisig), vec![cosigner]);
+    }
+}
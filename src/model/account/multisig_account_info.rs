@@ -0,0 +1,119 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::Address;
+
+/// The multisig account info structure describes the information of a multisig account,
+/// its cosignatories and the multisig accounts it cosigns for.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigAccountInfo {
+    /// The version of the multisig account entry.
+    ///
+    pub version: u16,
+
+    /// The account multisig address.
+    ///
+    pub account_address: Address,
+
+    /// The number of signatures needed to approve a transaction.
+    ///
+    pub min_approval: u32,
+
+    /// The number of signatures needed to remove a cosignatory.
+    ///
+    pub min_removal: u32,
+
+    /// The multisig account cosignatories.
+    ///
+    pub cosignatory_addresses: Vec<Address>,
+
+    /// The multisig accounts this account is cosigner of.
+    ///
+    pub multisig_addresses: Vec<Address>,
+}
+
+impl MultisigAccountInfo {
+    /// Checks if the account is a multisig account.
+    ///
+    pub fn is_multisig(&self) -> bool {
+        self.min_removal != 0 && self.min_approval != 0
+    }
+
+    /// Checks if an account is cosignatory of the multisig account.
+    ///
+    pub fn has_cosigner(&self, address: &Address) -> bool {
+        self.cosignatory_addresses.contains(address)
+    }
+
+    /// Checks if the multisig account is cosignatory of an account.
+    ///
+    pub fn is_cosigner_of_multisig_account(&self, address: &Address) -> bool {
+        self.multisig_addresses.contains(address)
+    }
+}
+
+impl fmt::Display for MultisigAccountInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::{Account, MultisigAccountInfo};
+    use crate::network::NetworkType;
+
+    #[test]
+    fn test_should_check_multisig_account() {
+        let multisig = Account::random(NetworkType::TestNet).public_account.address;
+        let cosigner = Account::random(NetworkType::TestNet).public_account.address;
+        let other = Account::random(NetworkType::TestNet).public_account.address;
+
+        let info = MultisigAccountInfo {
+            version: 1,
+            account_address: multisig,
+            min_approval: 1,
+            min_removal: 1,
+            cosignatory_addresses: vec![cosigner],
+            multisig_addresses: vec![],
+        };
+
+        assert!(info.is_multisig());
+        assert!(info.has_cosigner(&cosigner));
+        assert!(!info.has_cosigner(&other));
+        assert!(!info.is_cosigner_of_multisig_account(&other));
+    }
+
+    #[test]
+    fn test_should_not_be_multisig_without_min_approval() {
+        let cosigner = Account::random(NetworkType::TestNet).public_account.address;
+        let multisig = Account::random(NetworkType::TestNet).public_account.address;
+
+        let info = MultisigAccountInfo {
+            version: 1,
+            account_address: cosigner,
+            min_approval: 0,
+            min_removal: 0,
+            cosignatory_addresses: vec![],
+            multisig_addresses: vec![multisig],
+        };
+
+        assert!(!info.is_multisig());
+        assert!(info.is_cosigner_of_multisig_account(&multisig));
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::H256;
+
+/// The public keys linked to an account.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplementalPublicKeys {
+    /// The public key of the remote account the importance is delegated to.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked: Option<H256>,
+
+    /// The public key of the node the account harvests on.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node: Option<H256>,
+
+    /// The VRF public key used to harvest.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vrf: Option<H256>,
+
+    /// The voting public keys used for finalization.
+    ///
+    pub voting: Vec<AccountLinkVotingKey>,
+}
+
+impl SupplementalPublicKeys {
+    /// Returns true when the account has no linked public key.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.linked.is_none() && self.node.is_none() && self.vrf.is_none() && self.voting.is_empty()
+    }
+}
+
+impl fmt::Display for SupplementalPublicKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// A voting public key together with the finalization epochs it is valid for.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountLinkVotingKey {
+    /// The voting public key.
+    ///
+    pub public_key: H256,
+
+    /// The first finalization epoch the key is valid for.
+    ///
+    pub start_epoch: u32,
+
+    /// The last finalization epoch the key is valid for.
+    ///
+    pub end_epoch: u32,
+}
@@ -127,6 +127,19 @@ mod tests {
         assert_eq!(unresolved.try_downcast::<Address>().is_ok(), false);
     }
 
+    #[test]
+    fn test_can_map_encoded_alias_to_namespace_id() {
+        let unresolved = unresolved_mapping::to_unresolved_address(&format!(
+            "994BFA5F372D55B384{}",
+            "0".repeat(30)
+        ))
+        .unwrap();
+        assert_eq!(
+            unresolved.try_downcast::<NamespaceId>().unwrap().to_hex(),
+            "84B3552D375FFA4B"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Input string is not in valid hexadecimal notation.")]
     fn test_should_panic_if_id_not_in_hex() {
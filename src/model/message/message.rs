@@ -13,7 +13,7 @@ use std::fmt;
 use anyhow::Result;
 
 use crate::message::{
-    MessageType, PersistentHarvestingDelegationMessage, PlainMessage, RawMessage,
+    EncryptedMessage, MessageType, PersistentHarvestingDelegationMessage, PlainMessage, RawMessage,
     PERSISTENT_DELEGATION_UNLOCK,
 };
 use crate::{hex_decode, is_hex};
@@ -115,8 +115,10 @@ pub(crate) fn create_message_from_hex<S: AsRef<str>>(payload: S) -> Result<Box<d
         MessageType::PlainMessageType => Ok(Box::new(PlainMessage::from_payload(
             &payload_without_prefix,
         )?)),
-        MessageType::SecureMessageType => unimplemented!(),
-        _ => Ok(Box::new(RawMessage::new(&payload_vec[1..]))),
+        MessageType::SecureMessageType => Ok(Box::new(EncryptedMessage::from_payload(
+            &payload_without_prefix,
+        )?)),
+        _ => Ok(Box::new(RawMessage::new(&payload_vec))),
     }
 }
 
@@ -139,6 +141,8 @@ mod tests {
     use crate::message::{EncryptedMessage, Message, MessageType, PlainMessage};
     use crate::utf8_to_hex;
 
+    use super::create_message_from_hex;
+
     #[test]
     fn test_should_create_an_plain_message_dto_struct() {
         let message = PlainMessage::create("test");
@@ -159,4 +163,13 @@ mod tests {
             format!("01{}", utf8_to_hex("test").to_uppercase())
         );
     }
+
+    #[test]
+    fn test_should_keep_unknown_message_type_as_raw_message() {
+        let message = create_message_from_hex("FF0102").unwrap();
+
+        assert_eq!(message.message_type(), MessageType::RawMessageType);
+        assert_eq!(message.payload(), "ff0102");
+        assert_eq!(message.to_dto(), "FF0102");
+    }
 }
@@ -9,6 +9,7 @@
  */
 
 pub use self::encrypted_message::EncryptedMessage;
+pub(crate) use self::message::create_message_from_buffer;
 pub use self::message::Message;
 pub use self::message_type::MessageType;
 pub use self::persistent_harvesting_delegation_message::*;
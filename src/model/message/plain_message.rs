@@ -11,7 +11,7 @@
 use anyhow::{ensure, Result};
 
 use crate::message::{Message, MessageType};
-use crate::{hex_to_utf8, is_hex};
+use crate::{is_hex, try_hex_to_utf8};
 
 /// The `PlainMessage` struct defines a plain string.
 /// When sending it to the network we transform the payload to hex-string.
@@ -46,7 +46,7 @@ impl PlainMessage {
     ///
     pub fn from_payload(payload_hex: &str) -> Result<Self> {
         ensure!(is_hex(payload_hex), "payload_hex it's not hex.");
-        let payload = hex_to_utf8(payload_hex);
+        let payload = try_hex_to_utf8(payload_hex)?;
         Ok(Self {
             r#type: MessageType::PlainMessageType,
             payload,
@@ -67,6 +67,9 @@ impl Message for PlainMessage {
     fn message_type(&self) -> MessageType {
         self.r#type
     }
+    fn payload_to_vec(&self) -> Vec<u8> {
+        self.payload.as_bytes().to_vec()
+    }
     fn payload(&self) -> String {
         self.payload.to_owned()
     }
@@ -105,4 +108,9 @@ pub mod tests {
         assert_eq!(plain_message.payload, "test-message");
         assert_eq!(plain_message.to_dto(), "00746573742D6D657373616765");
     }
+
+    #[test]
+    fn test_create_plain_message_from_invalid_utf8_payload() {
+        assert!(PlainMessage::from_payload("C328").is_err());
+    }
 }
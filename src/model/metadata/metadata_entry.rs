@@ -0,0 +1,70 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::Address;
+use crate::metadata::{MetadataType, MetadataValue};
+use crate::mosaic::UnresolvedMosaicId;
+use crate::{Uint64, H256};
+
+/// A metadata entry, the key-value pair attached to an account, a mosaic or a namespace.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The internal resource identifier.
+    ///
+    pub record_id: String,
+
+    /// The version of the metadata entry.
+    ///
+    pub version: u16,
+
+    /// The composite hash, the unique identifier of the metadata entry.
+    ///
+    pub composite_hash: H256,
+
+    /// The address of the account that created the metadata.
+    ///
+    pub source_address: Address,
+
+    /// The address of the account the metadata is attached to, or the owner of the
+    /// mosaic or namespace.
+    ///
+    pub target_address: Address,
+
+    /// The metadata key scoped to source, target and type.
+    ///
+    pub scoped_metadata_key: Uint64,
+
+    /// The metadata type.
+    ///
+    pub metadata_type: MetadataType,
+
+    /// The mosaic or namespace id the metadata is attached to, `None` for account metadata.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Box<dyn UnresolvedMosaicId + 'static>>,
+
+    /// The metadata value.
+    ///
+    pub value: MetadataValue,
+}
+
+impl fmt::Display for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// The metadata type. Supported types are:
+///
+/// * 0 - Account metadata.
+/// * 1 - Mosaic metadata.
+/// * 2 - Namespace metadata.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum MetadataType {
+    /// The metadata is attached to an account.
+    Account = 0x00,
+    /// The metadata is attached to a mosaic.
+    Mosaic = 0x01,
+    /// The metadata is attached to a namespace.
+    Namespace = 0x02,
+}
+
+impl MetadataType {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for MetadataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'MetadataType' for the given u8 value.
+///
+/// Throws an Err when the type is unknown.
+impl TryFrom<u8> for MetadataType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::Account as u8 => Ok(Self::Account),
+            x if x == Self::Mosaic as u8 => Ok(Self::Mosaic),
+            x if x == Self::Namespace as u8 => Ok(Self::Namespace),
+            _ => Err(anyhow!("Unknown metadata type {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::metadata::MetadataType;
+
+    #[test]
+    fn test_metadata_type_values() {
+        assert_eq!(MetadataType::Account.value(), 0x00);
+        assert_eq!(MetadataType::Mosaic.value(), 0x01);
+        assert_eq!(MetadataType::Namespace.value(), 0x02);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(MetadataType::try_from(3).is_err());
+    }
+}
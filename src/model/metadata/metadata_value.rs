@@ -0,0 +1,94 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// A metadata value, decoded as UTF-8 text when possible and kept as raw bytes otherwise.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetadataValue {
+    /// The value is valid UTF-8 text.
+    Text(String),
+    /// The value is not valid UTF-8, for example a binary document hash.
+    Raw(Vec<u8>),
+}
+
+impl MetadataValue {
+    /// Returns the value bytes as stored on chain.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            MetadataValue::Text(text) => text.as_bytes(),
+            MetadataValue::Raw(bytes) => bytes,
+        }
+    }
+
+    /// Returns the value text, `None` when the value is not valid UTF-8.
+    ///
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            MetadataValue::Text(text) => Some(text),
+            MetadataValue::Raw(_) => None,
+        }
+    }
+
+    /// Returns the value in hexadecimal notation.
+    ///
+    pub fn to_hex(&self) -> String {
+        hex::encode_upper(self.as_bytes())
+    }
+}
+
+impl From<Vec<u8>> for MetadataValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(text) => MetadataValue::Text(text),
+            Err(e) => MetadataValue::Raw(e.into_bytes()),
+        }
+    }
+}
+
+/// The text is written as is and raw values in hexadecimal notation.
+///
+impl fmt::Display for MetadataValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetadataValue::Text(text) => write!(f, "{}", text),
+            MetadataValue::Raw(_) => write!(f, "{}", self.to_hex()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::MetadataValue;
+
+    #[test]
+    fn test_should_decode_utf8_value() {
+        let value = MetadataValue::from(b"document".to_vec());
+
+        assert_eq!(value, MetadataValue::Text("document".to_owned()));
+        assert_eq!(value.as_text(), Some("document"));
+        assert_eq!(value.as_bytes(), b"document");
+        assert_eq!(value.to_string(), "document");
+    }
+
+    #[test]
+    fn test_should_keep_raw_value_when_not_utf8() {
+        let bytes = vec![0xC3, 0x28, 0xFF, 0x00];
+        let value = MetadataValue::from(bytes.clone());
+
+        assert_eq!(value, MetadataValue::Raw(bytes));
+        assert_eq!(value.as_text(), None);
+        assert_eq!(value.to_hex(), "C328FF00");
+        assert_eq!(value.to_string(), "C328FF00");
+    }
+}
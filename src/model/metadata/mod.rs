@@ -0,0 +1,133 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, ensure, Result};
+
+use crate::network::MetadataNetworkProperties;
+use crate::{generate_uint64_key, Uint64};
+
+pub use self::metadata_entry::*;
+pub use self::metadata_type::*;
+pub use self::metadata_value::*;
+
+mod metadata_entry;
+mod metadata_type;
+mod metadata_value;
+
+/// Derives the 64-bit scoped metadata key from a string.
+///
+/// # Inputs
+///
+/// * `key`: The metadata key, for example "CERTIFICATE".
+///
+/// # Returns
+///
+/// A `Result` whose okay value is an `Uint64` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn scoped_metadata_key(key: &str) -> Result<Uint64> {
+    generate_uint64_key(key)
+}
+
+/// Computes the value to announce when a metadata value is replaced.
+///
+/// The network stores the XOR of the previous and the new value, the shorter
+/// value is padded with zeros.
+///
+/// # Inputs
+///
+/// * `old_value`: The current metadata value, empty when the metadata is created.
+/// * `new_value`: The new metadata value.
+///
+/// # Returns
+///
+/// The XOR value delta, as long as the longest of both values.
+///
+pub fn metadata_value_delta(old_value: &[u8], new_value: &[u8]) -> Vec<u8> {
+    let size = old_value.len().max(new_value.len());
+    (0..size)
+        .map(|i| old_value.get(i).unwrap_or(&0) ^ new_value.get(i).unwrap_or(&0))
+        .collect()
+}
+
+/// Computes the size difference in bytes between the new and the old metadata value.
+///
+/// # Inputs
+///
+/// * `old_value`: The current metadata value, empty when the metadata is created.
+/// * `new_value`: The new metadata value.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is the `value_size_delta` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn metadata_value_size_delta(old_value: &[u8], new_value: &[u8]) -> Result<i16> {
+    let delta = new_value.len() as i64 - old_value.len() as i64;
+    i16::try_from(delta)
+        .map_err(|_| anyhow!("Metadata value size delta {} is out of range.", delta))
+}
+
+/// Validates a metadata value size against the network metadata properties.
+///
+pub(crate) fn validate_metadata_value_size(
+    properties: &MetadataNetworkProperties,
+    value_size: usize,
+) -> Result<()> {
+    let max_value_size = properties.get_max_value_size()?;
+    ensure!(
+        value_size as u64 <= max_value_size,
+        "Invalid metadata value size {}, the network allows at most {} bytes.",
+        value_size,
+        max_value_size
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::{metadata_value_delta, metadata_value_size_delta, scoped_metadata_key};
+
+    #[test]
+    fn test_should_compute_metadata_value_delta() {
+        assert_eq!(metadata_value_delta(b"", b"abc"), b"abc".to_vec());
+        assert_eq!(metadata_value_delta(b"abc", b"abc"), vec![0, 0, 0]);
+        assert_eq!(metadata_value_delta(b"abc", b"ab"), vec![0, 0, b'c']);
+        assert_eq!(metadata_value_delta(b"a", b"ba"), vec![b'a' ^ b'b', b'a']);
+    }
+
+    #[test]
+    fn test_should_recover_new_value_from_delta() {
+        let old_value = b"old value";
+        let new_value = b"new longer value";
+        let delta = metadata_value_delta(old_value, new_value);
+
+        assert_eq!(metadata_value_delta(old_value, &delta), new_value.to_vec());
+    }
+
+    #[test]
+    fn test_should_compute_metadata_value_size_delta() {
+        assert_eq!(metadata_value_size_delta(b"", b"abc").unwrap(), 3);
+        assert_eq!(metadata_value_size_delta(b"abc", b"a").unwrap(), -2);
+        assert!(metadata_value_size_delta(&[], &[0u8; 40_000]).is_err());
+    }
+
+    #[test]
+    fn test_should_derive_scoped_metadata_key() {
+        assert_eq!(
+            scoped_metadata_key("KYC").unwrap().to_hex(),
+            "8112637C907BDD77"
+        );
+        assert!(scoped_metadata_key("").is_err());
+    }
+}
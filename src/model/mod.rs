@@ -17,11 +17,14 @@ pub mod blockchain;
 
 #[allow(dead_code)]
 pub mod message;
+pub mod metadata;
 pub mod mosaic;
 pub mod namespace;
 pub mod network;
 pub mod node;
 mod node_identity_equality_strategy;
+pub mod receipt;
+pub mod restriction;
 pub mod state;
 pub mod transaction;
 mod uint64;
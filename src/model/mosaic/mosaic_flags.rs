@@ -10,7 +10,7 @@
 
 use std::fmt;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MosaicFlags {
     /// The creator can choose between a definition that allows a mosaic supply change at a later point or an immutable supply.
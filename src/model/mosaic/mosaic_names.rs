@@ -8,12 +8,26 @@
  * // except according to those terms.
  */
 
+use std::fmt;
+
 use crate::mosaic::MosaicId;
+use crate::namespace::NamespaceName;
 
 /// Mosaic with linked names.
 ///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MosaicNames {
     pub mosaic_id: MosaicId,
-    // todo: crate NamespaceName.
-    // pub names: Vec<NamespaceName>,
+    pub names: Vec<NamespaceName>,
+}
+
+impl fmt::Display for MosaicNames {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
 }
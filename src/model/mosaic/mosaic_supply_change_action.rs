@@ -8,11 +8,49 @@
  * // except according to those terms.
  */
 
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
 /// The supply type. Supported supply types are:
 /// 0: Increase in supply.
 /// 1: Decrease in supply.
 ///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum MosaicSupplyChangeAction {
     Decrease = 0x00,
     Increase = 0x01,
 }
+
+impl MosaicSupplyChangeAction {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_bytes(&self) -> [u8; 1] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl fmt::Display for MosaicSupplyChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'MosaicSupplyChangeAction' for the given u8 value.
+///
+/// Throws an Err when the action is unknown.
+impl TryFrom<u8> for MosaicSupplyChangeAction {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::Decrease as u8 => Ok(Self::Decrease),
+            x if x == Self::Increase as u8 => Ok(Self::Increase),
+            _ => Err(anyhow!("Unknown mosaic supply change action {}", v)),
+        }
+    }
+}
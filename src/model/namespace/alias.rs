@@ -0,0 +1,84 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::Address;
+use crate::mosaic::MosaicId;
+use crate::namespace::AliasType;
+
+/// The alias of a namespace, the mosaic id or the address the namespace is linked to.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alias {
+    /// The alias type.
+    ///
+    #[serde(rename = "type")]
+    pub alias_type: AliasType,
+
+    /// The aliased mosaic id, set for mosaic aliases.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<MosaicId>,
+
+    /// The aliased address, set for address aliases.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+impl Alias {
+    /// Create an empty alias.
+    ///
+    pub fn empty() -> Self {
+        Self {
+            alias_type: AliasType::None,
+            mosaic_id: None,
+            address: None,
+        }
+    }
+
+    /// Create a mosaic id alias.
+    ///
+    pub fn from_mosaic_id(mosaic_id: MosaicId) -> Self {
+        Self {
+            alias_type: AliasType::Mosaic,
+            mosaic_id: Some(mosaic_id),
+            address: None,
+        }
+    }
+
+    /// Create an address alias.
+    ///
+    pub fn from_address(address: Address) -> Self {
+        Self {
+            alias_type: AliasType::Address,
+            mosaic_id: None,
+            address: Some(address),
+        }
+    }
+
+    /// Returns true when the namespace is not linked.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.alias_type == AliasType::None
+    }
+}
+
+impl fmt::Display for Alias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
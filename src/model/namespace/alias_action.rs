@@ -0,0 +1,77 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// The alias action. Supported actions are:
+///
+/// * 0 - Unlink an alias.
+/// * 1 - Link an alias.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum AliasAction {
+    /// Unlink the alias from the namespace.
+    Unlink = 0x00,
+    /// Link the alias to the namespace.
+    Link = 0x01,
+}
+
+impl AliasAction {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_bytes(&self) -> [u8; 1] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl fmt::Display for AliasAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'AliasAction' for the given u8 value.
+///
+/// Throws an Err when the action is unknown.
+impl TryFrom<u8> for AliasAction {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::Unlink as u8 => Ok(Self::Unlink),
+            x if x == Self::Link as u8 => Ok(Self::Link),
+            _ => Err(anyhow!("Unknown alias action {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::namespace::AliasAction;
+
+    #[test]
+    fn test_alias_action_values() {
+        assert_eq!(AliasAction::Unlink.value(), 0x00);
+        assert_eq!(AliasAction::Link.value(), 0x01);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(AliasAction::try_from(2).is_err());
+    }
+}
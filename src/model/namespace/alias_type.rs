@@ -0,0 +1,78 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// The alias type. Supported types are:
+///
+/// * 0 - No alias.
+/// * 1 - Mosaic id alias.
+/// * 2 - Address alias.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum AliasType {
+    /// The namespace is not linked.
+    None = 0x00,
+    /// The namespace is linked to a mosaic id.
+    Mosaic = 0x01,
+    /// The namespace is linked to an address.
+    Address = 0x02,
+}
+
+impl AliasType {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for AliasType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'AliasType' for the given u8 value.
+///
+/// Throws an Err when the type is unknown.
+impl TryFrom<u8> for AliasType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::None as u8 => Ok(Self::None),
+            x if x == Self::Mosaic as u8 => Ok(Self::Mosaic),
+            x if x == Self::Address as u8 => Ok(Self::Address),
+            _ => Err(anyhow!("Unknown alias type {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::namespace::AliasType;
+
+    #[test]
+    fn test_alias_type_values() {
+        assert_eq!(AliasType::None.value(), 0x00);
+        assert_eq!(AliasType::Mosaic.value(), 0x01);
+        assert_eq!(AliasType::Address.value(), 0x02);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(AliasType::try_from(3).is_err());
+    }
+}
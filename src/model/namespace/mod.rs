@@ -17,9 +17,25 @@ use sha3::{Digest, Sha3_256};
 
 use crate::Uint64;
 
+pub use self::alias::*;
+pub use self::alias_action::*;
+pub use self::alias_type::*;
 pub use self::namespace_id::*;
+pub use self::namespace_info::*;
+pub use self::namespace_name::*;
+pub use self::namespace_registration_type::*;
 
+mod alias;
+mod alias_action;
+mod alias_type;
 mod namespace_id;
+mod namespace_info;
+mod namespace_name;
+mod namespace_registration_type;
+
+/// The maximum size of a namespace part name in bytes.
+///
+pub(crate) const NAMESPACE_MAX_NAME_SIZE: usize = 64;
 
 fn namespace_id(namespace_name: &str) -> Result<Uint64> {
     let path = generate_namespace_path(namespace_name)?;
@@ -55,6 +71,19 @@ pub(crate) fn generate_namespace_id(parent_id: Uint64, name: &str) -> Result<Uin
     Ok(Uint64::from(value | 1 << 63))
 }
 
+/// Validates a single namespace part name, as it is registered by a `NamespaceRegistrationTransaction`.
+///
+pub(crate) fn validate_namespace_name(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty() && name.len() <= NAMESPACE_MAX_NAME_SIZE,
+        "Invalid namespace name size {}, expected between 1 and {} characters.",
+        name.len(),
+        NAMESPACE_MAX_NAME_SIZE
+    );
+    valid_part_name(name)?;
+    Ok(())
+}
+
 fn valid_part_name(name: &str) -> Result<String> {
     let reg_valid_namespace: Regex = Regex::new(r"^[a-z0-9][a-z0-9-_]*$").unwrap();
     ensure!(
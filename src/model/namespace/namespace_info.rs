@@ -0,0 +1,158 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::Address;
+use crate::namespace::{Alias, NamespaceId, NamespaceRegistrationType};
+
+/// The namespace state, as stored on the chain.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceInfo {
+    /// Version.
+    ///
+    pub version: u16,
+
+    /// The database record id.
+    ///
+    pub record_id: String,
+
+    /// True when the namespace is active.
+    ///
+    pub active: bool,
+
+    /// The namespace index.
+    ///
+    pub index: u32,
+
+    /// The namespace registration type.
+    ///
+    pub registration_type: NamespaceRegistrationType,
+
+    /// The level of the namespace, 1 for a root namespace.
+    ///
+    pub depth: u8,
+
+    /// The namespace ids of the levels, from the root namespace to this namespace.
+    ///
+    pub levels: Vec<NamespaceId>,
+
+    /// The parent namespace id, 0 for a root namespace.
+    ///
+    pub parent_id: NamespaceId,
+
+    /// The namespace owner address.
+    ///
+    pub owner_address: Address,
+
+    /// The block height at which the namespace was registered.
+    ///
+    pub start_height: u64,
+
+    /// The block height at which the namespace expires.
+    ///
+    pub end_height: u64,
+
+    /// The namespace alias.
+    ///
+    pub alias: Alias,
+}
+
+impl NamespaceInfo {
+    /// Returns the namespace id.
+    ///
+    pub fn id(&self) -> NamespaceId {
+        self.levels[self.levels.len() - 1].clone()
+    }
+
+    /// Returns true when the namespace is a root namespace.
+    ///
+    pub fn is_root(&self) -> bool {
+        self.registration_type == NamespaceRegistrationType::RootNamespace
+    }
+
+    /// Returns true when the namespace is a sub namespace.
+    ///
+    pub fn is_subnamespace(&self) -> bool {
+        self.registration_type == NamespaceRegistrationType::SubNamespace
+    }
+
+    /// Returns true when the namespace is linked to a mosaic id or an address.
+    ///
+    pub fn has_alias(&self) -> bool {
+        !self.alias.is_empty()
+    }
+}
+
+impl fmt::Display for NamespaceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::mosaic::MosaicId;
+    use crate::namespace::{Alias, NamespaceId, NamespaceInfo, NamespaceRegistrationType};
+
+    fn namespace_info(levels: Vec<NamespaceId>, alias: Alias) -> NamespaceInfo {
+        let registration_type = if levels.len() == 1 {
+            NamespaceRegistrationType::RootNamespace
+        } else {
+            NamespaceRegistrationType::SubNamespace
+        };
+
+        NamespaceInfo {
+            version: 1,
+            record_id: "5FDA2A4A39E6A6A4A1E5E3C1".to_owned(),
+            active: true,
+            index: 0,
+            registration_type,
+            depth: levels.len() as u8,
+            parent_id: NamespaceId::from(0),
+            levels,
+            owner_address: Address::from_raw("TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q").unwrap(),
+            start_height: 1,
+            end_height: u64::MAX,
+            alias,
+        }
+    }
+
+    #[test]
+    fn test_should_return_the_last_level_as_id() {
+        let info = namespace_info(
+            vec![NamespaceId::from(1), NamespaceId::from(2)],
+            Alias::empty(),
+        );
+
+        assert_eq!(info.id(), NamespaceId::from(2));
+        assert!(info.is_subnamespace());
+        assert!(!info.is_root());
+        assert!(!info.has_alias());
+    }
+
+    #[test]
+    fn test_should_have_alias() {
+        let info = namespace_info(
+            vec![NamespaceId::from(1)],
+            Alias::from_mosaic_id(MosaicId::from_hex("6BED913FA20223F8").unwrap()),
+        );
+
+        assert!(info.is_root());
+        assert!(info.has_alias());
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::namespace::NamespaceId;
+
+/// The name of a namespace.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceName {
+    /// The namespace id.
+    ///
+    pub namespace_id: NamespaceId,
+
+    /// The namespace name, the full name when it is resolved from an alias.
+    ///
+    pub name: String,
+
+    /// The parent namespace id, set for sub namespaces.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<NamespaceId>,
+}
+
+impl NamespaceName {
+    /// Create a namespace name from a full namespace name such as `symbol.xym`.
+    ///
+    /// # Inputs
+    ///
+    /// * `full_name`: The namespace full name.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `NamespaceName` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_full_name(full_name: &str) -> Result<Self> {
+        let parent_id = match full_name.rfind('.') {
+            Some(index) => Some(NamespaceId::create_from_name(&full_name[..index])?),
+            None => None,
+        };
+
+        Ok(Self {
+            namespace_id: NamespaceId::create_from_name(full_name)?,
+            name: full_name.to_owned(),
+            parent_id,
+        })
+    }
+}
+
+impl fmt::Display for NamespaceName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::namespace::NamespaceName;
+
+    #[test]
+    fn test_should_create_from_full_name() {
+        let name = NamespaceName::from_full_name("symbol.xym").unwrap();
+
+        assert_eq!(name.name, "symbol.xym");
+        assert_eq!(name.namespace_id.id.to_hex(), "E74B99BA41F4AFEE");
+        assert_eq!(name.parent_id.unwrap().id.to_hex(), "A95F1F8A96159516");
+    }
+
+    #[test]
+    fn test_should_create_root_namespace_name() {
+        let name = NamespaceName::from_full_name("symbol").unwrap();
+
+        assert_eq!(name.namespace_id.id.to_hex(), "A95F1F8A96159516");
+        assert!(name.parent_id.is_none());
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// Enum containing namespace registration type constants.
+///
+/// * 0 - Root namespace.
+/// * 1 - Child namespace.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum NamespaceRegistrationType {
+    /// Root namespace, registered for a duration.
+    RootNamespace = 0x00,
+    /// Child namespace, registered under a parent namespace.
+    SubNamespace = 0x01,
+}
+
+impl NamespaceRegistrationType {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_bytes(&self) -> [u8; 1] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl fmt::Display for NamespaceRegistrationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'NamespaceRegistrationType' for the given u8 value.
+///
+/// Throws an Err when the type is unknown.
+impl TryFrom<u8> for NamespaceRegistrationType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::RootNamespace as u8 => Ok(Self::RootNamespace),
+            x if x == Self::SubNamespace as u8 => Ok(Self::SubNamespace),
+            _ => Err(anyhow!("Unknown namespace registration type {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::namespace::NamespaceRegistrationType;
+
+    #[test]
+    fn test_root_namespace_is_0x00() {
+        assert_eq!(NamespaceRegistrationType::RootNamespace.value(), 0x00);
+    }
+
+    #[test]
+    fn test_sub_namespace_is_0x01() {
+        assert_eq!(NamespaceRegistrationType::SubNamespace.value(), 0x01);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(NamespaceRegistrationType::try_from(2).is_err());
+    }
+}
@@ -10,6 +10,10 @@
 
 use std::fmt;
 
+use anyhow::Result;
+
+use super::parse_network_property_u64;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AccountRestrictionNetworkProperties {
     /// Maximum number of account restriction values.
@@ -20,6 +24,17 @@ pub struct AccountRestrictionNetworkProperties {
     pub max_account_restriction_values: Option<String>,
 }
 
+impl AccountRestrictionNetworkProperties {
+    /// Returns the maximum number of values of an account restriction.
+    ///
+    pub fn get_max_account_restriction_values(&self) -> Result<u64> {
+        parse_network_property_u64(
+            "maxAccountRestrictionValues",
+            &self.max_account_restriction_values,
+        )
+    }
+}
+
 impl fmt::Display for AccountRestrictionNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
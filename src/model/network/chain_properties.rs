@@ -9,6 +9,11 @@
  */
 
 use std::fmt;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::{parse_network_property_duration, parse_network_property_u64};
 
 /// ChainPropertiesDto : Chain related configuration properties.
 ///
@@ -164,6 +169,29 @@ pub struct ChainProperties {
     pub max_transactions_per_block: Option<String>,
 }
 
+impl ChainProperties {
+    /// Returns the targeted time between blocks.
+    ///
+    pub fn get_block_generation_target_time(&self) -> Result<Duration> {
+        parse_network_property_duration(
+            "blockGenerationTargetTime",
+            &self.block_generation_target_time,
+        )
+    }
+
+    /// Returns the minimum number of finalization epochs a voting key can be registered for.
+    ///
+    pub fn get_min_voting_key_lifetime(&self) -> Result<u64> {
+        parse_network_property_u64("minVotingKeyLifetime", &self.min_voting_key_lifetime)
+    }
+
+    /// Returns the maximum number of finalization epochs a voting key can be registered for.
+    ///
+    pub fn get_max_voting_key_lifetime(&self) -> Result<u64> {
+        parse_network_property_u64("maxVotingKeyLifetime", &self.max_voting_key_lifetime)
+    }
+}
+
 impl fmt::Display for ChainProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
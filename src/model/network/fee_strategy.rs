@@ -0,0 +1,36 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// The fee strategy, selects the fee multiplier from the recent `TransactionFees`
+/// of the network. A higher multiplier makes it more likely that harvesters include
+/// the transaction sooner.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FeeStrategy {
+    /// The minimum fee multiplier accepted by the node.
+    Slowest,
+    /// The lowest fee multiplier of the recent blocks.
+    Slow,
+    /// The average fee multiplier of the recent blocks.
+    Average,
+    /// The larger of the median and average fee multipliers of the recent blocks.
+    Fast,
+    /// The highest fee multiplier of the recent blocks.
+    Fastest,
+}
+
+impl fmt::Display for FeeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -9,6 +9,11 @@
  */
 
 use std::fmt;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::{parse_network_property_duration, parse_network_property_u64};
 
 #[serde(rename_all = "camelCase")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -21,6 +26,20 @@ pub struct HashLockNetworkProperties {
     pub max_hash_lock_duration: Option<String>,
 }
 
+impl HashLockNetworkProperties {
+    /// Returns the amount that has to be locked per aggregate in absolute units.
+    ///
+    pub fn get_locked_funds_per_aggregate(&self) -> Result<u64> {
+        parse_network_property_u64("lockedFundsPerAggregate", &self.locked_funds_per_aggregate)
+    }
+
+    /// Returns the maximum time a hash lock can exist.
+    ///
+    pub fn get_max_hash_lock_duration(&self) -> Result<Duration> {
+        parse_network_property_duration("maxHashLockDuration", &self.max_hash_lock_duration)
+    }
+}
+
 impl fmt::Display for HashLockNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
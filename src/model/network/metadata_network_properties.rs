@@ -10,6 +10,10 @@
 
 use std::fmt;
 
+use anyhow::Result;
+
+use super::parse_network_property_u64;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MetadataNetworkProperties {
     /// Maximum metadata value size.
@@ -17,6 +21,14 @@ pub struct MetadataNetworkProperties {
     pub max_value_size: Option<String>,
 }
 
+impl MetadataNetworkProperties {
+    /// Returns the maximum size of a metadata value in bytes.
+    ///
+    pub fn get_max_value_size(&self) -> Result<u64> {
+        parse_network_property_u64("maxValueSize", &self.max_value_size)
+    }
+}
+
 impl fmt::Display for MetadataNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
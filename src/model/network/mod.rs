@@ -12,6 +12,7 @@ pub use self::account_key_link_network_properties::*;
 pub use self::account_restriction_network_properties::*;
 pub use self::aggregate_network_properties::*;
 pub use self::chain_properties::*;
+pub use self::fee_strategy::*;
 pub use self::hash_lock_network_properties::*;
 pub use self::metadata_network_properties::*;
 pub use self::mosaic_network_properties::*;
@@ -23,6 +24,7 @@ pub use self::network_name::*;
 pub use self::network_properties::*;
 pub use self::network_type::*;
 pub use self::plugins_properties::*;
+pub(crate) use self::property_parser::*;
 pub use self::rental_fees::*;
 pub use self::secret_lock_network_properties::*;
 pub use self::transaction_fees::*;
@@ -32,6 +34,7 @@ mod account_key_link_network_properties;
 mod account_restriction_network_properties;
 mod aggregate_network_properties;
 mod chain_properties;
+mod fee_strategy;
 mod hash_lock_network_properties;
 mod metadata_network_properties;
 mod mosaic_network_properties;
@@ -43,6 +46,7 @@ mod network_name;
 mod network_properties;
 mod network_type;
 mod plugins_properties;
+mod property_parser;
 mod rental_fees;
 mod secret_lock_network_properties;
 mod transaction_fees;
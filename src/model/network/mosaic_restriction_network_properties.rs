@@ -10,6 +10,10 @@
 
 use std::fmt;
 
+use anyhow::Result;
+
+use super::parse_network_property_u64;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MosaicRestrictionNetworkProperties {
     /// Maximum number of mosaic restriction values.
@@ -20,6 +24,17 @@ pub struct MosaicRestrictionNetworkProperties {
     pub max_mosaic_restriction_values: Option<String>,
 }
 
+impl MosaicRestrictionNetworkProperties {
+    /// Returns the maximum number of restriction values of a mosaic.
+    ///
+    pub fn get_max_mosaic_restriction_values(&self) -> Result<u64> {
+        parse_network_property_u64(
+            "maxMosaicRestrictionValues",
+            &self.max_mosaic_restriction_values,
+        )
+    }
+}
+
 impl fmt::Display for MosaicRestrictionNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
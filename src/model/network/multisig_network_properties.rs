@@ -10,6 +10,10 @@
 
 use std::fmt;
 
+use anyhow::Result;
+
+use super::parse_network_property_u64;
+
 #[serde(rename_all = "camelCase")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MultisigNetworkProperties {
@@ -24,6 +28,32 @@ pub struct MultisigNetworkProperties {
     pub max_cosigned_accounts_per_account: Option<String>,
 }
 
+impl MultisigNetworkProperties {
+    /// Returns the maximum number of multisig levels.
+    ///
+    pub fn get_max_multisig_depth(&self) -> Result<u64> {
+        parse_network_property_u64("maxMultisigDepth", &self.max_multisig_depth)
+    }
+
+    /// Returns the maximum number of cosignatories per account.
+    ///
+    pub fn get_max_cosignatories_per_account(&self) -> Result<u64> {
+        parse_network_property_u64(
+            "maxCosignatoriesPerAccount",
+            &self.max_cosignatories_per_account,
+        )
+    }
+
+    /// Returns the maximum number of accounts a single account can cosign.
+    ///
+    pub fn get_max_cosigned_accounts_per_account(&self) -> Result<u64> {
+        parse_network_property_u64(
+            "maxCosignedAccountsPerAccount",
+            &self.max_cosigned_accounts_per_account,
+        )
+    }
+}
+
 impl fmt::Display for MultisigNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -10,8 +10,12 @@
 
 use std::fmt;
 
+use anyhow::Result;
+
 use crate::NodeIdentityEqualityStrategy;
 
+use super::parse_network_property_duration;
+
 /// NetworkPropertiesDto : Network related configuration properties.
 ///
 #[serde(rename_all = "camelCase")]
@@ -32,6 +36,15 @@ pub struct NetworkProperties {
     pub epoch_adjustment: Option<String>,
 }
 
+impl NetworkProperties {
+    /// Returns the nemesis epoch time adjustment in seconds, used to create a `Deadline`.
+    ///
+    pub fn get_epoch_adjustment(&self) -> Result<u64> {
+        parse_network_property_duration("epochAdjustment", &self.epoch_adjustment)
+            .map(|epoch_adjustment| epoch_adjustment.as_secs())
+    }
+}
+
 impl fmt::Display for NetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
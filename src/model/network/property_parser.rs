@@ -0,0 +1,119 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+
+/// Parses a numeric network property as returned by the node, for example "10'000'000".
+///
+/// # Inputs
+///
+/// * `name`: The property name, used in the error message.
+/// * `value`: The optional property value.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is the property as `u64` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub(crate) fn parse_network_property_u64(name: &str, value: &Option<String>) -> Result<u64> {
+    let value = value
+        .as_ref()
+        .ok_or_else(|| anyhow!("Network property {} is not set.", name))?;
+
+    value
+        .replace('\'', "")
+        .parse::<u64>()
+        .map_err(|e| anyhow!("Invalid network property {} '{}': {}", name, value, e))
+}
+
+/// Parses a time span network property as returned by the node, for example "30s" or "2d".
+///
+/// Supported units are `ms`, `s`, `m`, `h` and `d`.
+///
+/// # Inputs
+///
+/// * `name`: The property name, used in the error message.
+/// * `value`: The optional property value.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is the property as `Duration` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub(crate) fn parse_network_property_duration(
+    name: &str,
+    value: &Option<String>,
+) -> Result<Duration> {
+    let raw_value = value
+        .as_ref()
+        .ok_or_else(|| anyhow!("Network property {} is not set.", name))?
+        .replace('\'', "");
+
+    let units: [(&str, u64); 5] = [
+        ("ms", 1),
+        ("s", 1_000),
+        ("m", 60_000),
+        ("h", 3_600_000),
+        ("d", 86_400_000),
+    ];
+    let (number, millis) = units
+        .iter()
+        .find_map(|(unit, millis)| {
+            raw_value
+                .strip_suffix(unit)
+                .filter(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+                .map(|number| (number, *millis))
+        })
+        .ok_or_else(|| anyhow!("Invalid network property {} '{}'.", name, raw_value))?;
+
+    Ok(Duration::from_millis(number.parse::<u64>()? * millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_network_property_duration, parse_network_property_u64};
+
+    #[test]
+    fn test_should_parse_network_property_with_separators() {
+        assert_eq!(
+            parse_network_property_u64("lockedFundsPerAggregate", &Some("10'000'000".to_owned()))
+                .unwrap(),
+            10_000_000
+        );
+        assert_eq!(
+            parse_network_property_u64("maxMultisigDepth", &Some("3".to_owned())).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_should_fail_if_network_property_is_missing_or_invalid() {
+        assert!(parse_network_property_u64("maxMultisigDepth", &None).is_err());
+        assert!(parse_network_property_u64("maxHashLockDuration", &Some("2d".to_owned())).is_err());
+    }
+
+    #[test]
+    fn test_should_parse_network_property_durations() {
+        let parse = |value: &str| {
+            parse_network_property_duration("maxHashLockDuration", &Some(value.to_owned()))
+        };
+
+        assert_eq!(parse("2d").unwrap(), Duration::from_secs(2 * 86_400));
+        assert_eq!(parse("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse("1'440m").unwrap(), Duration::from_secs(86_400));
+        assert!(parse("2 days").is_err());
+        assert!(parse("s").is_err());
+    }
+}
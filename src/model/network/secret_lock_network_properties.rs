@@ -9,6 +9,11 @@
  */
 
 use std::fmt;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::{parse_network_property_duration, parse_network_property_u64};
 
 #[serde(rename_all = "camelCase")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,6 +29,26 @@ pub struct SecretLockNetworkProperties {
     pub max_proof_size: Option<String>,
 }
 
+impl SecretLockNetworkProperties {
+    /// Returns the maximum time a secret lock can exist.
+    ///
+    pub fn get_max_secret_lock_duration(&self) -> Result<Duration> {
+        parse_network_property_duration("maxSecretLockDuration", &self.max_secret_lock_duration)
+    }
+
+    /// Returns the minimum size of a proof in bytes.
+    ///
+    pub fn get_min_proof_size(&self) -> Result<u64> {
+        parse_network_property_u64("minProofSize", &self.min_proof_size)
+    }
+
+    /// Returns the maximum size of a proof in bytes.
+    ///
+    pub fn get_max_proof_size(&self) -> Result<u64> {
+        parse_network_property_u64("maxProofSize", &self.max_proof_size)
+    }
+}
+
 impl fmt::Display for SecretLockNetworkProperties {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -10,6 +10,8 @@
 
 use std::fmt;
 
+use super::FeeStrategy;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TransactionFees {
     /// Fee multiplier applied to transactions contained in block.
@@ -29,6 +31,23 @@ pub struct TransactionFees {
     pub min_fee_multiplier: u64,
 }
 
+impl TransactionFees {
+    /// Returns the fee multiplier for the given strategy.
+    ///
+    /// The multiplier is never lower than the minimum fee multiplier accepted by the node.
+    ///
+    pub fn get_fee_multiplier(&self, strategy: FeeStrategy) -> u64 {
+        let fee_multiplier = match strategy {
+            FeeStrategy::Slowest => self.min_fee_multiplier,
+            FeeStrategy::Slow => self.lowest_fee_multiplier,
+            FeeStrategy::Average => self.average_fee_multiplier,
+            FeeStrategy::Fast => self.median_fee_multiplier.max(self.average_fee_multiplier),
+            FeeStrategy::Fastest => self.highest_fee_multiplier,
+        };
+        fee_multiplier.max(self.min_fee_multiplier)
+    }
+}
+
 impl fmt::Display for TransactionFees {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
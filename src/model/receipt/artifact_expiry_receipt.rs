@@ -0,0 +1,91 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+
+use crate::mosaic::UnresolvedMosaicId;
+use crate::receipt::ReceiptType;
+use crate::ser_to_id;
+
+/// Artifact Expiry: An artifact, a mosaic or a namespace, expired or was deleted.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactExpiryReceipt {
+    /// The receipt version.
+    ///
+    pub version: u16,
+
+    /// The receipt type.
+    ///
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+
+    /// The id of the artifact.
+    /// This can either be of type `MosaicId` or `NamespaceId`.
+    ///
+    #[serde(serialize_with = "ser_to_id")]
+    pub artifact_id: Box<dyn UnresolvedMosaicId + 'static>,
+}
+
+impl ArtifactExpiryReceipt {
+    /// The receipt types recorded as an artifact expiry.
+    ///
+    pub const RECEIPT_TYPES: [ReceiptType; 3] = [
+        ReceiptType::MosaicExpired,
+        ReceiptType::NamespaceExpired,
+        ReceiptType::NamespaceDeleted,
+    ];
+
+    /// Create an `ArtifactExpiryReceipt`.
+    ///
+    /// # Inputs
+    ///
+    /// * `version`: The receipt version.
+    ///
+    /// * `receipt_type`: The receipt type, a mosaic expired, namespace expired or namespace deleted.
+    ///
+    /// * `artifact_id`: The id of the mosaic or namespace.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `ArtifactExpiryReceipt` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        version: u16,
+        receipt_type: ReceiptType,
+        artifact_id: Box<dyn UnresolvedMosaicId + 'static>,
+    ) -> Result<Self> {
+        ensure!(
+            Self::RECEIPT_TYPES.contains(&receipt_type),
+            "Invalid artifact expiry receipt type {}.",
+            receipt_type
+        );
+
+        Ok(Self {
+            version,
+            receipt_type,
+            artifact_id,
+        })
+    }
+}
+
+impl fmt::Display for ArtifactExpiryReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
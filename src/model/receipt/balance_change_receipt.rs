@@ -0,0 +1,101 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+
+use crate::account::Address;
+use crate::mosaic::Mosaic;
+use crate::receipt::ReceiptType;
+
+/// Balance Change: A mosaic credit or debit was triggered.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChangeReceipt {
+    /// The receipt version.
+    ///
+    pub version: u16,
+
+    /// The receipt type.
+    ///
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+
+    /// The mosaic credited or debited.
+    ///
+    pub mosaic: Mosaic,
+
+    /// The address of the account whose balance changed.
+    ///
+    pub target_address: Address,
+}
+
+impl BalanceChangeReceipt {
+    /// The receipt types recorded as a balance change.
+    ///
+    pub const RECEIPT_TYPES: [ReceiptType; 7] = [
+        ReceiptType::HarvestFee,
+        ReceiptType::LockHashCreated,
+        ReceiptType::LockHashCompleted,
+        ReceiptType::LockHashExpired,
+        ReceiptType::LockSecretCreated,
+        ReceiptType::LockSecretCompleted,
+        ReceiptType::LockSecretExpired,
+    ];
+
+    /// Create a `BalanceChangeReceipt`.
+    ///
+    /// # Inputs
+    ///
+    /// * `version`: The receipt version.
+    ///
+    /// * `receipt_type`: The receipt type, a harvest fee or a lock receipt.
+    ///
+    /// * `mosaic`: The mosaic credited or debited.
+    ///
+    /// * `target_address`: The address of the account whose balance changed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `BalanceChangeReceipt` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        version: u16,
+        receipt_type: ReceiptType,
+        mosaic: Mosaic,
+        target_address: Address,
+    ) -> Result<Self> {
+        ensure!(
+            Self::RECEIPT_TYPES.contains(&receipt_type),
+            "Invalid balance change receipt type {}.",
+            receipt_type
+        );
+
+        Ok(Self {
+            version,
+            receipt_type,
+            mosaic,
+            target_address,
+        })
+    }
+}
+
+impl fmt::Display for BalanceChangeReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
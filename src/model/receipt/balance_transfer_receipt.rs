@@ -0,0 +1,104 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+
+use crate::account::Address;
+use crate::mosaic::Mosaic;
+use crate::receipt::ReceiptType;
+
+/// Balance Transfer: A mosaic transfer was triggered.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceTransferReceipt {
+    /// The receipt version.
+    ///
+    pub version: u16,
+
+    /// The receipt type.
+    ///
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+
+    /// The mosaic transferred.
+    ///
+    pub mosaic: Mosaic,
+
+    /// The address of the sender.
+    ///
+    pub sender_address: Address,
+
+    /// The address of the recipient.
+    ///
+    pub recipient_address: Address,
+}
+
+impl BalanceTransferReceipt {
+    /// The receipt types recorded as a balance transfer.
+    ///
+    pub const RECEIPT_TYPES: [ReceiptType; 2] = [
+        ReceiptType::MosaicRentalFee,
+        ReceiptType::NamespaceRentalFee,
+    ];
+
+    /// Create a `BalanceTransferReceipt`.
+    ///
+    /// # Inputs
+    ///
+    /// * `version`: The receipt version.
+    ///
+    /// * `receipt_type`: The receipt type, a mosaic or namespace rental fee.
+    ///
+    /// * `mosaic`: The mosaic transferred.
+    ///
+    /// * `sender_address`: The address of the sender.
+    ///
+    /// * `recipient_address`: The address of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `BalanceTransferReceipt` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        version: u16,
+        receipt_type: ReceiptType,
+        mosaic: Mosaic,
+        sender_address: Address,
+        recipient_address: Address,
+    ) -> Result<Self> {
+        ensure!(
+            Self::RECEIPT_TYPES.contains(&receipt_type),
+            "Invalid balance transfer receipt type {}.",
+            receipt_type
+        );
+
+        Ok(Self {
+            version,
+            receipt_type,
+            mosaic,
+            sender_address,
+            recipient_address,
+        })
+    }
+}
+
+impl fmt::Display for BalanceTransferReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::mosaic::Mosaic;
+use crate::receipt::ReceiptType;
+
+/// Inflation: Native currency mosaics were created due to inflation.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InflationReceipt {
+    /// The receipt version.
+    ///
+    pub version: u16,
+
+    /// The receipt type.
+    ///
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+
+    /// The mosaic created.
+    ///
+    pub mosaic: Mosaic,
+}
+
+impl InflationReceipt {
+    pub fn new(version: u16, mosaic: Mosaic) -> Self {
+        Self {
+            version,
+            receipt_type: ReceiptType::Inflation,
+            mosaic,
+        }
+    }
+}
+
+impl fmt::Display for InflationReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+pub use self::artifact_expiry_receipt::*;
+pub use self::balance_change_receipt::*;
+pub use self::balance_transfer_receipt::*;
+pub use self::inflation_receipt::*;
+pub use self::receipt_source::*;
+pub use self::receipt_type::*;
+pub use self::receipts::*;
+pub use self::resolution_entry::*;
+pub use self::resolution_statement::*;
+pub use self::transaction_statement::*;
+
+mod artifact_expiry_receipt;
+mod balance_change_receipt;
+mod balance_transfer_receipt;
+mod inflation_receipt;
+mod receipt_source;
+mod receipt_type;
+mod receipts;
+mod resolution_entry;
+mod resolution_statement;
+mod transaction_statement;
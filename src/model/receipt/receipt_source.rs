@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// The id of the source of a receipt or resolution.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptSource {
+    /// The transaction primary source (e.g. index within block).
+    ///
+    pub primary_id: u32,
+
+    /// The transaction secondary source (e.g. index within aggregate).
+    ///
+    pub secondary_id: u32,
+}
+
+impl ReceiptSource {
+    pub fn new(primary_id: u32, secondary_id: u32) -> Self {
+        Self {
+            primary_id,
+            secondary_id,
+        }
+    }
+}
+
+impl fmt::Display for ReceiptSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
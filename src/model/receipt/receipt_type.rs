@@ -0,0 +1,186 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Enum containing receipt type constants.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+#[repr(u16)]
+pub enum ReceiptType {
+    /// The recipient, account and amount of fees received for harvesting a block.
+    /// It is recorded when a block is harvested.
+    ///
+    HarvestFee = 0x2143,
+
+    /// The unresolved and resolved alias. It is recorded when a transaction
+    /// indicates a valid address alias instead of an address.
+    ///
+    AddressAliasResolution = 0xF143,
+
+    /// The unresolved and resolved alias. It is recorded when a transaction
+    /// indicates a valid mosaic alias instead of a mosaic id.
+    ///
+    MosaicAliasResolution = 0xF243,
+
+    /// A collection of state changes for a given source. It is recorded when
+    /// a state change receipt is issued.
+    ///
+    TransactionGroup = 0xE143,
+
+    /// The mosaic id expiring in this block. It is recorded when a mosaic expires.
+    ///
+    MosaicExpired = 0x414D,
+
+    /// The sender and recipient of the mosaic id and amount representing the cost
+    /// of registering the mosaic. It is recorded when a mosaic definition transaction is announced.
+    ///
+    MosaicRentalFee = 0x124D,
+
+    /// The namespace id expiring in this block. It is recorded when a namespace expires.
+    ///
+    NamespaceExpired = 0x414E,
+
+    /// The namespace id deleted in this block. It is recorded when a namespace
+    /// is removed after its grace period.
+    ///
+    NamespaceDeleted = 0x424E,
+
+    /// The sender and recipient of the mosaic id and amount representing the cost
+    /// of extending the namespace. It is recorded when a namespace registration transaction is announced.
+    ///
+    NamespaceRentalFee = 0x134E,
+
+    /// The lockhash sender, mosaic id and amount locked. It is recorded when a valid
+    /// hash lock transaction is announced.
+    ///
+    LockHashCreated = 0x3148,
+
+    /// The hash lock owner, mosaic id and amount locked. It is recorded when an
+    /// aggregate bonded transaction linked to the hash completes.
+    ///
+    LockHashCompleted = 0x2248,
+
+    /// The account receiving the locked mosaic, the mosaic id and the amount.
+    /// It is recorded when a lock hash expires.
+    ///
+    LockHashExpired = 0x2348,
+
+    /// The secret lock sender, mosaic id and amount locked. It is recorded when a valid
+    /// secret lock transaction is announced.
+    ///
+    LockSecretCreated = 0x3152,
+
+    /// The secret lock recipient, mosaic id and amount locked. It is recorded when a
+    /// secret lock is proved.
+    ///
+    LockSecretCompleted = 0x2252,
+
+    /// The account receiving the locked mosaic, the mosaic id and the amount.
+    /// It is recorded when a secret lock expires.
+    ///
+    LockSecretExpired = 0x2352,
+
+    /// The amount of native currency mosaics created. The receipt is recorded when
+    /// the network has inflation configured and a new block triggers the creation of currency mosaics.
+    ///
+    Inflation = 0x5143,
+}
+
+impl ReceiptType {
+    const UNKNOWN_RECEIPT_TYPE: &'static str = "Unknown receipt type";
+
+    pub fn value(self) -> u16 {
+        self as u16
+    }
+
+    pub fn to_bytes(&self) -> [u8; 2] {
+        self.value().to_le_bytes()
+    }
+}
+
+/// Returns a 'ReceiptType' for the given u16 value.
+///
+/// Throws an Err UNKNOWN_RECEIPT_TYPE when the type is unknown.
+impl TryFrom<u16> for ReceiptType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u16) -> Result<Self, Self::Error> {
+        use ReceiptType::*;
+        match v {
+            x if x == HarvestFee as u16 => Ok(HarvestFee),
+            x if x == AddressAliasResolution as u16 => Ok(AddressAliasResolution),
+            x if x == MosaicAliasResolution as u16 => Ok(MosaicAliasResolution),
+            x if x == TransactionGroup as u16 => Ok(TransactionGroup),
+            x if x == MosaicExpired as u16 => Ok(MosaicExpired),
+            x if x == MosaicRentalFee as u16 => Ok(MosaicRentalFee),
+            x if x == NamespaceExpired as u16 => Ok(NamespaceExpired),
+            x if x == NamespaceDeleted as u16 => Ok(NamespaceDeleted),
+            x if x == NamespaceRentalFee as u16 => Ok(NamespaceRentalFee),
+            x if x == LockHashCreated as u16 => Ok(LockHashCreated),
+            x if x == LockHashCompleted as u16 => Ok(LockHashCompleted),
+            x if x == LockHashExpired as u16 => Ok(LockHashExpired),
+            x if x == LockSecretCreated as u16 => Ok(LockSecretCreated),
+            x if x == LockSecretCompleted as u16 => Ok(LockSecretCompleted),
+            x if x == LockSecretExpired as u16 => Ok(LockSecretExpired),
+            x if x == Inflation as u16 => Ok(Inflation),
+            _ => Err(anyhow::anyhow!(Self::UNKNOWN_RECEIPT_TYPE)),
+        }
+    }
+}
+
+impl From<ReceiptType> for u16 {
+    fn from(value: ReceiptType) -> Self {
+        value as u16
+    }
+}
+
+impl fmt::Display for ReceiptType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", &self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::receipt::ReceiptType;
+
+    #[test]
+    fn test_should_match_the_specification() {
+        assert_eq!(ReceiptType::HarvestFee.value(), 0x2143);
+        assert_eq!(ReceiptType::AddressAliasResolution.value(), 0xF143);
+        assert_eq!(ReceiptType::MosaicAliasResolution.value(), 0xF243);
+        assert_eq!(ReceiptType::TransactionGroup.value(), 0xE143);
+        assert_eq!(ReceiptType::MosaicExpired.value(), 0x414D);
+        assert_eq!(ReceiptType::MosaicRentalFee.value(), 0x124D);
+        assert_eq!(ReceiptType::NamespaceExpired.value(), 0x414E);
+        assert_eq!(ReceiptType::NamespaceDeleted.value(), 0x424E);
+        assert_eq!(ReceiptType::NamespaceRentalFee.value(), 0x134E);
+        assert_eq!(ReceiptType::LockHashCreated.value(), 0x3148);
+        assert_eq!(ReceiptType::LockHashCompleted.value(), 0x2248);
+        assert_eq!(ReceiptType::LockHashExpired.value(), 0x2348);
+        assert_eq!(ReceiptType::LockSecretCreated.value(), 0x3152);
+        assert_eq!(ReceiptType::LockSecretCompleted.value(), 0x2252);
+        assert_eq!(ReceiptType::LockSecretExpired.value(), 0x2352);
+        assert_eq!(ReceiptType::Inflation.value(), 0x5143);
+    }
+
+    #[test]
+    fn test_should_map_u16_to_receipt_type() {
+        assert_eq!(
+            ReceiptType::try_from(0x2143).unwrap(),
+            ReceiptType::HarvestFee
+        );
+        assert!(ReceiptType::try_from(0x0001).is_err());
+    }
+}
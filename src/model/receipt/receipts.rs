@@ -0,0 +1,180 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::receipt::{
+    ArtifactExpiryReceipt, BalanceChangeReceipt, BalanceTransferReceipt, InflationReceipt,
+    ReceiptType,
+};
+
+/// A receipt recorded by the network when a state change was triggered.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Receipt {
+    BalanceTransfer(BalanceTransferReceipt),
+    BalanceChange(BalanceChangeReceipt),
+    ArtifactExpiry(ArtifactExpiryReceipt),
+    Inflation(InflationReceipt),
+}
+
+impl Receipt {
+    /// Returns the receipt type.
+    ///
+    pub fn receipt_type(&self) -> ReceiptType {
+        match self {
+            Receipt::BalanceTransfer(receipt) => receipt.receipt_type,
+            Receipt::BalanceChange(receipt) => receipt.receipt_type,
+            Receipt::ArtifactExpiry(receipt) => receipt.receipt_type,
+            Receipt::Inflation(receipt) => receipt.receipt_type,
+        }
+    }
+
+    /// Returns the receipt version.
+    ///
+    pub fn version(&self) -> u16 {
+        match self {
+            Receipt::BalanceTransfer(receipt) => receipt.version,
+            Receipt::BalanceChange(receipt) => receipt.version,
+            Receipt::ArtifactExpiry(receipt) => receipt.version,
+            Receipt::Inflation(receipt) => receipt.version,
+        }
+    }
+}
+
+impl From<BalanceTransferReceipt> for Receipt {
+    fn from(receipt: BalanceTransferReceipt) -> Self {
+        Receipt::BalanceTransfer(receipt)
+    }
+}
+
+impl From<BalanceChangeReceipt> for Receipt {
+    fn from(receipt: BalanceChangeReceipt) -> Self {
+        Receipt::BalanceChange(receipt)
+    }
+}
+
+impl From<ArtifactExpiryReceipt> for Receipt {
+    fn from(receipt: ArtifactExpiryReceipt) -> Self {
+        Receipt::ArtifactExpiry(receipt)
+    }
+}
+
+impl From<InflationReceipt> for Receipt {
+    fn from(receipt: InflationReceipt) -> Self {
+        Receipt::Inflation(receipt)
+    }
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::mosaic::{Mosaic, MosaicId};
+    use crate::namespace::NamespaceId;
+    use crate::receipt::{
+        ArtifactExpiryReceipt, BalanceChangeReceipt, BalanceTransferReceipt, InflationReceipt,
+        Receipt, ReceiptType,
+    };
+
+    fn mosaic() -> Mosaic {
+        Mosaic::create(MosaicId::from_hex("6BED913FA20223F8").unwrap(), 1000).unwrap()
+    }
+
+    fn address() -> Address {
+        Address::from_raw("TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q").unwrap()
+    }
+
+    #[test]
+    fn test_should_create_balance_transfer_receipt() {
+        let receipt = BalanceTransferReceipt::create(
+            1,
+            ReceiptType::MosaicRentalFee,
+            mosaic(),
+            address(),
+            address(),
+        )
+        .unwrap();
+
+        let receipt = Receipt::from(receipt);
+        assert_eq!(receipt.receipt_type(), ReceiptType::MosaicRentalFee);
+        assert_eq!(receipt.version(), 1);
+    }
+
+    #[test]
+    fn test_should_not_create_balance_transfer_receipt_with_invalid_type() {
+        assert!(BalanceTransferReceipt::create(
+            1,
+            ReceiptType::HarvestFee,
+            mosaic(),
+            address(),
+            address(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_should_create_balance_change_receipt() {
+        let receipt =
+            BalanceChangeReceipt::create(1, ReceiptType::HarvestFee, mosaic(), address()).unwrap();
+
+        let receipt = Receipt::from(receipt);
+        assert_eq!(receipt.receipt_type(), ReceiptType::HarvestFee);
+    }
+
+    #[test]
+    fn test_should_not_create_balance_change_receipt_with_invalid_type() {
+        assert!(
+            BalanceChangeReceipt::create(1, ReceiptType::Inflation, mosaic(), address()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_should_create_artifact_expiry_receipt() {
+        let namespace_id = NamespaceId::from_hex("E74B99BA41F4AFEE").unwrap();
+        let receipt =
+            ArtifactExpiryReceipt::create(1, ReceiptType::NamespaceExpired, Box::new(namespace_id))
+                .unwrap();
+
+        assert_eq!(receipt.artifact_id.to_uint64().to_hex(), "E74B99BA41F4AFEE");
+        assert_eq!(
+            Receipt::from(receipt).receipt_type(),
+            ReceiptType::NamespaceExpired
+        );
+    }
+
+    #[test]
+    fn test_should_not_create_artifact_expiry_receipt_with_invalid_type() {
+        let mosaic_id = MosaicId::from_hex("6BED913FA20223F8").unwrap();
+        assert!(ArtifactExpiryReceipt::create(
+            1,
+            ReceiptType::MosaicRentalFee,
+            Box::new(mosaic_id)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_should_create_inflation_receipt() {
+        let receipt = Receipt::from(InflationReceipt::new(1, mosaic()));
+
+        assert_eq!(receipt.receipt_type(), ReceiptType::Inflation);
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::Address;
+use crate::mosaic::MosaicId;
+use crate::receipt::ReceiptSource;
+
+/// The resolved address and the source of the resolution.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressResolutionEntry {
+    /// The receipt source object.
+    ///
+    pub source: ReceiptSource,
+
+    /// The resolved address.
+    ///
+    pub resolved: Address,
+}
+
+/// The resolved mosaic id and the source of the resolution.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicResolutionEntry {
+    /// The receipt source object.
+    ///
+    pub source: ReceiptSource,
+
+    /// The resolved mosaic id.
+    ///
+    pub resolved: MosaicId,
+}
+
+impl fmt::Display for AddressResolutionEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+impl fmt::Display for MosaicResolutionEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::{Address, UnresolvedAddress};
+use crate::mosaic::{MosaicId, UnresolvedMosaicId};
+use crate::receipt::{AddressResolutionEntry, MosaicResolutionEntry, ReceiptSource};
+use crate::ser_to_id;
+
+/// An address resolution statement, links a namespace alias used in a transaction
+/// to the real address.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressResolutionStatement {
+    /// The block height.
+    ///
+    pub height: u64,
+
+    /// The unresolved address.
+    /// This can either be of type `Address` or `NamespaceId`.
+    ///
+    pub unresolved: Box<dyn UnresolvedAddress + 'static>,
+
+    /// The vec of resolution entries.
+    ///
+    pub resolution_entries: Vec<AddressResolutionEntry>,
+}
+
+impl AddressResolutionStatement {
+    /// Returns the address the unresolved address was resolved to for the given source,
+    /// the closest preceding resolution entry is used.
+    ///
+    pub fn get_resolved(&self, source: ReceiptSource) -> Option<Address> {
+        resolve_entry(
+            self.resolution_entries
+                .iter()
+                .map(|entry| (entry.source, entry.resolved)),
+            source,
+        )
+    }
+}
+
+/// A mosaic resolution statement, links a namespace alias used in a transaction
+/// to the real mosaic id.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicResolutionStatement {
+    /// The block height.
+    ///
+    pub height: u64,
+
+    /// The unresolved mosaic id.
+    /// This can either be of type `MosaicId` or `NamespaceId`.
+    ///
+    #[serde(serialize_with = "ser_to_id")]
+    pub unresolved: Box<dyn UnresolvedMosaicId + 'static>,
+
+    /// The vec of resolution entries.
+    ///
+    pub resolution_entries: Vec<MosaicResolutionEntry>,
+}
+
+impl MosaicResolutionStatement {
+    /// Returns the mosaic id the unresolved mosaic id was resolved to for the given source,
+    /// the closest preceding resolution entry is used.
+    ///
+    pub fn get_resolved(&self, source: ReceiptSource) -> Option<MosaicId> {
+        resolve_entry(
+            self.resolution_entries
+                .iter()
+                .map(|entry| (entry.source, entry.resolved)),
+            source,
+        )
+    }
+}
+
+/// Entries are ordered by source, a resolution applies from its source until the next entry.
+///
+fn resolve_entry<T>(
+    entries: impl Iterator<Item = (ReceiptSource, T)>,
+    source: ReceiptSource,
+) -> Option<T> {
+    let key = |s: ReceiptSource| (s.primary_id, s.secondary_id);
+
+    entries
+        .filter(|(entry_source, _)| key(*entry_source) <= key(source))
+        .last()
+        .map(|(_, resolved)| resolved)
+}
+
+impl fmt::Display for AddressResolutionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+impl fmt::Display for MosaicResolutionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mosaic::MosaicId;
+    use crate::namespace::NamespaceId;
+    use crate::receipt::{MosaicResolutionEntry, MosaicResolutionStatement, ReceiptSource};
+
+    #[test]
+    fn test_should_get_resolved_mosaic_id_for_source() {
+        let first = MosaicId::from_hex("6BED913FA20223F8").unwrap();
+        let second = MosaicId::from_hex("091F837E059AE13C").unwrap();
+
+        let statement = MosaicResolutionStatement {
+            height: 1,
+            unresolved: Box::new(NamespaceId::from_hex("E74B99BA41F4AFEE").unwrap()),
+            resolution_entries: vec![
+                MosaicResolutionEntry {
+                    source: ReceiptSource::new(1, 0),
+                    resolved: first,
+                },
+                MosaicResolutionEntry {
+                    source: ReceiptSource::new(3, 5),
+                    resolved: second,
+                },
+            ],
+        };
+
+        assert_eq!(statement.get_resolved(ReceiptSource::new(0, 0)), None);
+        assert_eq!(
+            statement.get_resolved(ReceiptSource::new(1, 0)),
+            Some(first)
+        );
+        assert_eq!(
+            statement.get_resolved(ReceiptSource::new(3, 4)),
+            Some(first)
+        );
+        assert_eq!(
+            statement.get_resolved(ReceiptSource::new(3, 5)),
+            Some(second)
+        );
+        assert_eq!(
+            statement.get_resolved(ReceiptSource::new(4, 0)),
+            Some(second)
+        );
+    }
+}
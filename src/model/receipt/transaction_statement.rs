@@ -0,0 +1,41 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::receipt::{Receipt, ReceiptSource};
+
+/// A transaction statement, the collection of receipts linked to a transaction in a block.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatement {
+    /// The block height.
+    ///
+    pub height: u64,
+
+    /// The receipt source object.
+    ///
+    pub source: ReceiptSource,
+
+    /// The vec of receipts.
+    ///
+    pub receipts: Vec<Receipt>,
+}
+
+impl fmt::Display for TransactionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
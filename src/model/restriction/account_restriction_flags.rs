@@ -0,0 +1,235 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, ensure, Result};
+
+/// The kind of value an account restriction applies to.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u16)]
+pub enum AccountRestrictionValueType {
+    /// Restriction type is an address.
+    Address = 0x0001,
+    /// Restriction type is a mosaic identifier.
+    MosaicId = 0x0002,
+    /// Restriction type is a transaction type.
+    TransactionType = 0x0004,
+}
+
+impl AccountRestrictionValueType {
+    pub fn value(self) -> u16 {
+        self as u16
+    }
+}
+
+impl fmt::Display for AccountRestrictionValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The account restriction flags, combines the value type of the restriction
+/// with its direction and whether the values are allowed or blocked.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRestrictionFlags {
+    /// The kind of value the restriction applies to.
+    pub value_type: AccountRestrictionValueType,
+    /// The restriction applies to outgoing transactions, otherwise to incoming transactions.
+    pub outgoing: bool,
+    /// The restriction values are blocked, otherwise only the restriction values are allowed.
+    pub block: bool,
+}
+
+impl AccountRestrictionFlags {
+    /// Restriction is interpreted as outgoing.
+    ///
+    const OUTGOING_FLAG: u16 = 0x4000;
+
+    /// Restriction is interpreted as blocking operation.
+    ///
+    const BLOCK_FLAG: u16 = 0x8000;
+
+    /// Allow only incoming transactions from the given addresses.
+    pub const ALLOW_INCOMING_ADDRESS: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::Address, false, false);
+    /// Allow only outgoing transactions to the given addresses.
+    pub const ALLOW_OUTGOING_ADDRESS: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::Address, true, false);
+    /// Block incoming transactions from the given addresses.
+    pub const BLOCK_INCOMING_ADDRESS: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::Address, false, true);
+    /// Block outgoing transactions to the given addresses.
+    pub const BLOCK_OUTGOING_ADDRESS: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::Address, true, true);
+    /// Allow only incoming transactions containing the given mosaics.
+    pub const ALLOW_MOSAIC: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::MosaicId, false, false);
+    /// Block incoming transactions containing the given mosaics.
+    pub const BLOCK_MOSAIC: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::MosaicId, false, true);
+    /// Allow only outgoing transactions of the given transaction types.
+    pub const ALLOW_OUTGOING_TRANSACTION_TYPE: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::TransactionType, true, false);
+    /// Block outgoing transactions of the given transaction types.
+    pub const BLOCK_OUTGOING_TRANSACTION_TYPE: AccountRestrictionFlags =
+        Self::new(AccountRestrictionValueType::TransactionType, true, true);
+
+    const fn new(value_type: AccountRestrictionValueType, outgoing: bool, block: bool) -> Self {
+        Self {
+            value_type,
+            outgoing,
+            block,
+        }
+    }
+
+    /// Create account restriction flags.
+    ///
+    /// # Inputs
+    ///
+    /// * `value_type`: The kind of value the restriction applies to.
+    /// * `outgoing`: The restriction applies to outgoing transactions.
+    /// * `block`: The restriction values are blocked.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AccountRestrictionFlags` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        value_type: AccountRestrictionValueType,
+        outgoing: bool,
+        block: bool,
+    ) -> Result<Self> {
+        ensure!(
+            value_type != AccountRestrictionValueType::MosaicId || !outgoing,
+            "Mosaic restrictions can only be applied to incoming transactions."
+        );
+        ensure!(
+            value_type != AccountRestrictionValueType::TransactionType || outgoing,
+            "Transaction type restrictions can only be applied to outgoing transactions."
+        );
+
+        Ok(Self::new(value_type, outgoing, block))
+    }
+
+    /// Get account restriction flags value in number
+    ///
+    pub fn get_value(&self) -> u16 {
+        let mut value = self.value_type.value();
+        if self.outgoing {
+            value |= Self::OUTGOING_FLAG;
+        }
+        if self.block {
+            value |= Self::BLOCK_FLAG;
+        }
+        value
+    }
+
+    pub fn to_bytes(&self) -> [u8; 2] {
+        self.get_value().to_le_bytes()
+    }
+}
+
+/// Returns an 'AccountRestrictionFlags' for the given u16 value.
+///
+/// Throws an Err when the flags are unknown.
+impl TryFrom<u16> for AccountRestrictionFlags {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u16) -> Result<Self, Self::Error> {
+        let value_type = match v & !(Self::OUTGOING_FLAG | Self::BLOCK_FLAG) {
+            x if x == AccountRestrictionValueType::Address as u16 => {
+                AccountRestrictionValueType::Address
+            }
+            x if x == AccountRestrictionValueType::MosaicId as u16 => {
+                AccountRestrictionValueType::MosaicId
+            }
+            x if x == AccountRestrictionValueType::TransactionType as u16 => {
+                AccountRestrictionValueType::TransactionType
+            }
+            _ => return Err(anyhow!("Unknown account restriction flags {}", v)),
+        };
+
+        Self::create(
+            value_type,
+            v & Self::OUTGOING_FLAG != 0,
+            v & Self::BLOCK_FLAG != 0,
+        )
+    }
+}
+
+impl fmt::Display for AccountRestrictionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::restriction::{AccountRestrictionFlags, AccountRestrictionValueType};
+
+    #[test]
+    fn test_account_restriction_flags_values() {
+        assert_eq!(
+            AccountRestrictionFlags::ALLOW_INCOMING_ADDRESS.get_value(),
+            0x0001
+        );
+        assert_eq!(
+            AccountRestrictionFlags::ALLOW_OUTGOING_ADDRESS.get_value(),
+            0x4001
+        );
+        assert_eq!(
+            AccountRestrictionFlags::BLOCK_INCOMING_ADDRESS.get_value(),
+            0x8001
+        );
+        assert_eq!(
+            AccountRestrictionFlags::BLOCK_OUTGOING_ADDRESS.get_value(),
+            0xC001
+        );
+        assert_eq!(AccountRestrictionFlags::ALLOW_MOSAIC.get_value(), 0x0002);
+        assert_eq!(AccountRestrictionFlags::BLOCK_MOSAIC.get_value(), 0x8002);
+        assert_eq!(
+            AccountRestrictionFlags::ALLOW_OUTGOING_TRANSACTION_TYPE.get_value(),
+            0x4004
+        );
+        assert_eq!(
+            AccountRestrictionFlags::BLOCK_OUTGOING_TRANSACTION_TYPE.get_value(),
+            0xC004
+        );
+    }
+
+    #[test]
+    fn test_should_create_from_num_value() {
+        let flags = AccountRestrictionFlags::try_from(0xC001).unwrap();
+
+        assert_eq!(flags.value_type, AccountRestrictionValueType::Address);
+        assert_eq!(flags.outgoing, true);
+        assert_eq!(flags.block, true);
+        assert_eq!(flags, AccountRestrictionFlags::BLOCK_OUTGOING_ADDRESS);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(AccountRestrictionFlags::try_from(0x0003).is_err());
+        assert!(AccountRestrictionFlags::try_from(0x4002).is_err());
+        assert!(AccountRestrictionFlags::try_from(0x0004).is_err());
+    }
+}
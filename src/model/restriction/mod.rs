@@ -0,0 +1,53 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{ensure, Result};
+
+use crate::network::AccountRestrictionNetworkProperties;
+
+pub use self::account_restriction_flags::*;
+pub use self::mosaic_restriction_type::*;
+
+mod account_restriction_flags;
+mod mosaic_restriction_type;
+
+/// Checks the number of restriction values against `max_account_restriction_values`,
+/// shared by all account restriction transactions.
+///
+pub(crate) fn validate_account_restriction_values(
+    properties: &AccountRestrictionNetworkProperties,
+    current_values: usize,
+    additions: usize,
+    deletions: usize,
+) -> Result<()> {
+    let max_values = properties.get_max_account_restriction_values()?;
+    ensure!(
+        (additions + deletions) as u64 <= max_values,
+        "Too many restriction modifications {}, the network allows at most {}.",
+        additions + deletions,
+        max_values
+    );
+    ensure!(
+        deletions <= current_values,
+        "Cannot remove {} restriction values, the restriction has {}.",
+        deletions,
+        current_values
+    );
+
+    let values = current_values + additions - deletions;
+    ensure!(
+        values as u64 <= max_values,
+        "The restriction would have {} values, the network allows at most {}.",
+        values,
+        max_values
+    );
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// The mosaic restriction type, the operator used to compare the restriction value
+/// of an account with the global restriction value of a mosaic. Supported types are:
+///
+/// * 0 - None: Uninitialized value indicating no restriction.
+/// * 1 - Eq: Allow if equal.
+/// * 2 - Ne: Allow if not equal.
+/// * 3 - Lt: Allow if less than.
+/// * 4 - Le: Allow if less than or equal.
+/// * 5 - Gt: Allow if greater than.
+/// * 6 - Ge: Allow if greater than or equal.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum MosaicRestrictionType {
+    /// Uninitialized value indicating no restriction.
+    None = 0x00,
+    /// Allow if equal.
+    Eq = 0x01,
+    /// Allow if not equal.
+    Ne = 0x02,
+    /// Allow if less than.
+    Lt = 0x03,
+    /// Allow if less than or equal.
+    Le = 0x04,
+    /// Allow if greater than.
+    Gt = 0x05,
+    /// Allow if greater than or equal.
+    Ge = 0x06,
+}
+
+impl MosaicRestrictionType {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_bytes(&self) -> [u8; 1] {
+        self.value().to_le_bytes()
+    }
+
+    /// Checks if an account restriction value satisfies the global restriction value.
+    ///
+    /// # Inputs
+    ///
+    /// * `value`: The restriction value of the account.
+    /// * `reference`: The global restriction value of the mosaic.
+    ///
+    /// # Returns
+    ///
+    /// `true` when the operator holds for the values, always `true` for `None`.
+    ///
+    pub fn evaluate(self, value: u64, reference: u64) -> bool {
+        match self {
+            MosaicRestrictionType::None => true,
+            MosaicRestrictionType::Eq => value == reference,
+            MosaicRestrictionType::Ne => value != reference,
+            MosaicRestrictionType::Lt => value < reference,
+            MosaicRestrictionType::Le => value <= reference,
+            MosaicRestrictionType::Gt => value > reference,
+            MosaicRestrictionType::Ge => value >= reference,
+        }
+    }
+}
+
+impl fmt::Display for MosaicRestrictionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'MosaicRestrictionType' for the given u8 value.
+///
+/// Throws an Err when the type is unknown.
+impl TryFrom<u8> for MosaicRestrictionType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::None as u8 => Ok(Self::None),
+            x if x == Self::Eq as u8 => Ok(Self::Eq),
+            x if x == Self::Ne as u8 => Ok(Self::Ne),
+            x if x == Self::Lt as u8 => Ok(Self::Lt),
+            x if x == Self::Le as u8 => Ok(Self::Le),
+            x if x == Self::Gt as u8 => Ok(Self::Gt),
+            x if x == Self::Ge as u8 => Ok(Self::Ge),
+            _ => Err(anyhow!("Unknown mosaic restriction type {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::restriction::MosaicRestrictionType;
+
+    #[test]
+    fn test_mosaic_restriction_type_values() {
+        assert_eq!(MosaicRestrictionType::None.value(), 0x00);
+        assert_eq!(MosaicRestrictionType::Eq.value(), 0x01);
+        assert_eq!(MosaicRestrictionType::Ne.value(), 0x02);
+        assert_eq!(MosaicRestrictionType::Lt.value(), 0x03);
+        assert_eq!(MosaicRestrictionType::Le.value(), 0x04);
+        assert_eq!(MosaicRestrictionType::Gt.value(), 0x05);
+        assert_eq!(MosaicRestrictionType::Ge.value(), 0x06);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(MosaicRestrictionType::try_from(7).is_err());
+    }
+
+    #[test]
+    fn test_should_evaluate_comparison_operators() {
+        assert!(MosaicRestrictionType::None.evaluate(0, 1));
+        assert!(MosaicRestrictionType::Eq.evaluate(1, 1));
+        assert!(!MosaicRestrictionType::Ne.evaluate(1, 1));
+        assert!(MosaicRestrictionType::Lt.evaluate(0, 1));
+        assert!(MosaicRestrictionType::Le.evaluate(1, 1));
+        assert!(!MosaicRestrictionType::Gt.evaluate(1, 1));
+        assert!(MosaicRestrictionType::Ge.evaluate(2, 1));
+    }
+}
@@ -0,0 +1,628 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::mosaic::UnresolvedMosaicId;
+use crate::network::{AccountRestrictionNetworkProperties, NetworkType};
+use crate::restriction::{
+    validate_account_restriction_values, AccountRestrictionFlags, AccountRestrictionValueType,
+};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// The size of the fixed part of an account restriction transaction body in bytes, the
+/// restriction flags, the modification counts and the reserved bytes.
+///
+const BODY_FIXED_SIZE: usize = 2 + 1 + 1 + 4;
+
+/// A value that is added to or removed from an account restriction.
+///
+trait AccountRestrictionValue: Sized {
+    /// The size of the serialized value in bytes.
+    ///
+    const SIZE: usize;
+
+    fn value_to_bytes(&self, network_type: NetworkType) -> Vec<u8>;
+
+    fn value_from_bytes(bytes: &[u8]) -> Result<Self>;
+
+    fn value_to_string(&self) -> String;
+}
+
+impl AccountRestrictionValue for Box<dyn UnresolvedAddress> {
+    const SIZE: usize = 24;
+
+    fn value_to_bytes(&self, network_type: NetworkType) -> Vec<u8> {
+        self.unresolved_address_to_bytes(network_type)
+    }
+
+    fn value_from_bytes(bytes: &[u8]) -> Result<Self> {
+        to_unresolved_address(&hex::encode(bytes))
+    }
+
+    fn value_to_string(&self) -> String {
+        self.recipient_to_string()
+    }
+}
+
+impl AccountRestrictionValue for Box<dyn UnresolvedMosaicId> {
+    const SIZE: usize = 8;
+
+    fn value_to_bytes(&self, _network_type: NetworkType) -> Vec<u8> {
+        self.to_uint64().to_le_bytes().to_vec()
+    }
+
+    fn value_from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let mosaic_id = Uint64::from(bytes.read_u64::<LittleEndian>()?);
+        to_unresolved_mosaic(&mosaic_id.to_hex())
+    }
+
+    fn value_to_string(&self) -> String {
+        self.to_uint64().to_hex()
+    }
+}
+
+impl AccountRestrictionValue for TransactionType {
+    const SIZE: usize = 2;
+
+    fn value_to_bytes(&self, _network_type: NetworkType) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    fn value_from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        TransactionType::try_from(bytes.read_u16::<LittleEndian>()?)
+    }
+
+    fn value_to_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Defines an account restriction transaction whose body is the restriction flags followed by
+/// the added and removed values of the given `AccountRestrictionValue` type.
+///
+macro_rules! account_restriction_transaction {
+    (
+        $(#[$doc:meta])*
+        $name:ident {
+            transaction_type: $transaction_type:ident,
+            version: $version:ident,
+            value: $value:ty,
+            value_type: $value_type:ident,
+            description: $description:literal,
+            restriction: $restriction:literal,
+            value_name: $value_name:literal,
+            values: $values:literal,
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $name {
+            pub common: CommonTransaction,
+            /// The account restriction flags.
+            pub restriction_flags: AccountRestrictionFlags,
+            /// The account restriction additions.
+            pub restriction_additions: Vec<$value>,
+            /// The account restriction deletions.
+            pub restriction_deletions: Vec<$value>,
+        }
+
+        impl $name {
+            #[doc = concat!("Create an ", $description, " transaction.")]
+            ///
+            /// # Inputs
+            ///
+            /// * `deadline`: The deadline to include the transaction.
+            #[doc = concat!("* `restriction_flags`: The account restriction flags, must be ", $restriction, " restriction.")]
+            #[doc = concat!("* `restriction_additions`: The ", $values, " added to the restriction.")]
+            #[doc = concat!("* `restriction_deletions`: The ", $values, " removed from the restriction.")]
+            /// * `network_type`: The network type.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A `Result` whose okay value is an `", stringify!($name), "` or whose error value")]
+            /// is an `Error` describing the error that occurred.
+            ///
+            pub fn create(
+                deadline: Deadline,
+                restriction_flags: AccountRestrictionFlags,
+                restriction_additions: Vec<$value>,
+                restriction_deletions: Vec<$value>,
+                network_type: NetworkType,
+                max_fee: Option<u64>,
+            ) -> Result<Self> {
+                ensure!(
+                    restriction_flags.value_type == AccountRestrictionValueType::$value_type,
+                    concat!("Invalid account restriction flags {}, expected ", $restriction, " restriction."),
+                    restriction_flags.get_value()
+                );
+                ensure!(
+                    restriction_additions.len() <= u8::MAX as usize
+                        && restriction_deletions.len() <= u8::MAX as usize,
+                    "Too many restriction modifications, at most {} additions and deletions are allowed.",
+                    u8::MAX
+                );
+
+                let mut values = HashSet::new();
+                for value in restriction_additions
+                    .iter()
+                    .chain(restriction_deletions.iter())
+                {
+                    ensure!(
+                        values.insert(value.value_to_bytes(network_type)),
+                        concat!("Restriction ", $value_name, " {} is modified more than once."),
+                        value.value_to_string()
+                    );
+                }
+
+                let common = CommonTransaction::create_from_type(
+                    TransactionType::$transaction_type,
+                    network_type,
+                    TransactionVersion::$version,
+                    deadline,
+                    max_fee.unwrap_or_default(),
+                );
+
+                Ok(Self {
+                    common,
+                    restriction_flags,
+                    restriction_additions,
+                    restriction_deletions,
+                })
+            }
+
+            /// Validates the modification against the network account restriction limits.
+            ///
+            /// # Inputs
+            ///
+            /// * `properties`: The network `AccountRestrictionNetworkProperties`.
+            /// * `current_values`: The number of values of the restriction before the modification.
+            ///
+            /// # Returns
+            ///
+            /// A `Result` whose okay value is `()` or whose error value
+            /// is an `Error` describing the limit that is exceeded.
+            ///
+            pub fn validate(
+                &self,
+                properties: &AccountRestrictionNetworkProperties,
+                current_values: usize,
+            ) -> Result<()> {
+                validate_account_restriction_values(
+                    properties,
+                    current_values,
+                    self.restriction_additions.len(),
+                    self.restriction_deletions.len(),
+                )
+            }
+
+            #[doc = concat!("Creates an `", stringify!($name), "` from its catbuffer binary layout.")]
+            ///
+            /// # Inputs
+            ///
+            /// * `payload`: The serialized transaction bytes.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A `Result` whose okay value is an `", stringify!($name), "` or whose error value")]
+            /// is an `Error` describing the error that occurred.
+            ///
+            pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+                let (common, body) = CommonTransaction::from_bytes(payload)?;
+                ensure!(
+                    common.transaction_type == TransactionType::$transaction_type,
+                    "Invalid transaction type {}, expected {}.",
+                    common.transaction_type,
+                    TransactionType::$transaction_type
+                );
+
+                Self::from_body(common, body)
+            }
+
+            #[doc = concat!("Creates an `", stringify!($name), "` from its hex payload.")]
+            ///
+            pub fn from_payload(payload: &str) -> Result<Self> {
+                ensure!(
+                    is_hex(payload),
+                    "Input string is not in valid hexadecimal notation."
+                );
+
+                Self::from_bytes(&hex_decode(payload))
+            }
+
+            pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+                ensure!(
+                    body.len() >= BODY_FIXED_SIZE,
+                    concat!("Invalid ", $description, " transaction body size {}, expected at least {} bytes."),
+                    body.len(),
+                    BODY_FIXED_SIZE
+                );
+
+                let mut cursor = Cursor::new(body);
+                let restriction_flags =
+                    AccountRestrictionFlags::try_from(cursor.read_u16::<LittleEndian>()?)?;
+                let additions_count = cursor.read_u8()? as usize;
+                let deletions_count = cursor.read_u8()? as usize;
+                cursor.set_position(BODY_FIXED_SIZE as u64);
+
+                let expected_size =
+                    BODY_FIXED_SIZE + (additions_count + deletions_count) * <$value>::SIZE;
+                ensure!(
+                    body.len() == expected_size,
+                    concat!("Invalid ", $description, " transaction body size {}, expected {} bytes."),
+                    body.len(),
+                    expected_size
+                );
+
+                let mut read_values = |count: usize| -> Result<Vec<$value>> {
+                    let mut values = Vec::with_capacity(count);
+                    for _ in 0..count {
+                        let mut value = [0u8; <$value>::SIZE];
+                        cursor.read_exact(&mut value)?;
+                        values.push(<$value>::value_from_bytes(&value)?);
+                    }
+                    Ok(values)
+                };
+                let restriction_additions = read_values(additions_count)?;
+                let restriction_deletions = read_values(deletions_count)?;
+
+                Ok(Self {
+                    common,
+                    restriction_flags,
+                    restriction_additions,
+                    restriction_deletions,
+                })
+            }
+
+            fn values_to_bytes(&self, values: &[$value]) -> Vec<u8> {
+                values
+                    .iter()
+                    .flat_map(|value| value.value_to_bytes(self.common.network_type))
+                    .collect()
+            }
+        }
+
+        #[typetag::serde]
+        impl Transaction for $name {
+            fn common(&self) -> &CommonTransaction {
+                &self.common
+            }
+
+            fn common_mut(&mut self) -> &mut CommonTransaction {
+                &mut self.common
+            }
+
+            fn body_to_bytes(&self) -> Vec<u8> {
+                let mut buf = Vec::with_capacity(
+                    BODY_FIXED_SIZE
+                        + (self.restriction_additions.len() + self.restriction_deletions.len())
+                            * <$value>::SIZE,
+                );
+                buf.extend_from_slice(&self.restriction_flags.to_bytes());
+                buf.push(self.restriction_additions.len() as u8);
+                buf.push(self.restriction_deletions.len() as u8);
+                // account_restriction_transaction_body_reserved_1
+                buf.extend_from_slice(&[0u8; 4]);
+                buf.extend(self.values_to_bytes(&self.restriction_additions));
+                buf.extend(self.values_to_bytes(&self.restriction_deletions));
+                buf
+            }
+
+            fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+                Box::new((*self).clone())
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "{}",
+                    serde_json::to_string_pretty(&self).unwrap_or_default()
+                )
+            }
+        }
+    };
+}
+
+account_restriction_transaction! {
+    /// Account address restriction transaction struct, allows or blocks incoming and outgoing
+    /// transactions for the given addresses.
+    ///
+    AccountAddressRestrictionTransaction {
+        transaction_type: AccountRestrictionAddress,
+        version: ACCOUNT_ADDRESS_RESTRICTION,
+        value: Box<dyn UnresolvedAddress>,
+        value_type: Address,
+        description: "account address restriction",
+        restriction: "an address",
+        value_name: "address",
+        values: "addresses",
+    }
+}
+
+account_restriction_transaction! {
+    /// Account mosaic restriction transaction struct, allows or blocks incoming transactions
+    /// containing the given mosaics.
+    ///
+    AccountMosaicRestrictionTransaction {
+        transaction_type: AccountRestrictionMosaic,
+        version: ACCOUNT_MOSAIC_RESTRICTION,
+        value: Box<dyn UnresolvedMosaicId>,
+        value_type: MosaicId,
+        description: "account mosaic restriction",
+        restriction: "a mosaic",
+        value_name: "mosaic",
+        values: "mosaics",
+    }
+}
+
+account_restriction_transaction! {
+    /// Account operation restriction transaction struct, allows or blocks outgoing transactions
+    /// of the given transaction types.
+    ///
+    AccountOperationRestrictionTransaction {
+        transaction_type: AccountRestrictionOperation,
+        version: MODIFY_ACCOUNT_RESTRICTION_ENTITY_TYPE,
+        value: TransactionType,
+        value_type: TransactionType,
+        description: "account operation restriction",
+        restriction: "a transaction type",
+        value_name: "transaction type",
+        values: "transaction types",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::mosaic::{MosaicId, UnresolvedMosaicId};
+    use crate::namespace::NamespaceId;
+    use crate::network::{AccountRestrictionNetworkProperties, NetworkType};
+    use crate::restriction::AccountRestrictionFlags;
+    use crate::transaction::{
+        create_transaction_from_payload, AccountAddressRestrictionTransaction,
+        AccountMosaicRestrictionTransaction, AccountOperationRestrictionTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::Deadline;
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    fn address_restriction_transaction() -> AccountAddressRestrictionTransaction {
+        AccountAddressRestrictionTransaction::create(
+            Deadline::from(1),
+            AccountRestrictionFlags::BLOCK_OUTGOING_ADDRESS,
+            vec![Box::new(Address::from_raw(RAW_ADDRESS).unwrap())],
+            vec![Box::new(NamespaceId::create_from_name("nem").unwrap())],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn mosaic_restriction_transaction() -> AccountMosaicRestrictionTransaction {
+        AccountMosaicRestrictionTransaction::create(
+            Deadline::from(1),
+            AccountRestrictionFlags::ALLOW_MOSAIC,
+            vec![Box::new(MosaicId::from_hex("091F837E059AE13C").unwrap())],
+            vec![Box::new(NamespaceId::create_from_name("nem").unwrap())],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn operation_restriction_transaction() -> AccountOperationRestrictionTransaction {
+        AccountOperationRestrictionTransaction::create(
+            Deadline::from(1),
+            AccountRestrictionFlags::BLOCK_OUTGOING_TRANSACTION_TYPE,
+            vec![TransactionType::Transfer, TransactionType::AggregateBonded],
+            vec![TransactionType::SecretLock],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_account_address_restriction_transaction() {
+        let transaction = address_restriction_transaction();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::AccountRestrictionAddress
+        );
+        assert_eq!(transaction.size(), 128 + 8 + 2 * 24);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!(
+                "01C0010100000000\
+                9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776\
+                994BFA5F372D55B384{}",
+                "0".repeat(30)
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_create_account_mosaic_restriction_transaction() {
+        let transaction = mosaic_restriction_transaction();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::AccountRestrictionMosaic
+        );
+        assert_eq!(transaction.size(), 128 + 8 + 2 * 8);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "02000101000000003CE19A057E831F094BFA5F372D55B384"
+        );
+    }
+
+    #[test]
+    fn test_should_create_account_operation_restriction_transaction() {
+        let transaction = operation_restriction_transaction();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::AccountRestrictionOperation
+        );
+        assert_eq!(transaction.size(), 128 + 8 + 3 * 2);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "04C0020100000000544141425241"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_account_address_restriction_transaction() {
+        let transaction = address_restriction_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<AccountAddressRestrictionTransaction>();
+
+        assert_eq!(
+            mapped.restriction_flags,
+            AccountRestrictionFlags::BLOCK_OUTGOING_ADDRESS
+        );
+        assert_eq!(
+            mapped.restriction_additions[0].recipient_to_string(),
+            RAW_ADDRESS
+        );
+        assert_eq!(
+            mapped.restriction_deletions[0].recipient_to_string(),
+            "84B3552D375FFA4B"
+        );
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_deserialize_account_mosaic_restriction_transaction() {
+        let transaction = mosaic_restriction_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<AccountMosaicRestrictionTransaction>();
+
+        assert_eq!(
+            mapped.restriction_flags,
+            AccountRestrictionFlags::ALLOW_MOSAIC
+        );
+        assert_eq!(
+            mapped.restriction_additions[0].to_uint64().to_hex(),
+            "091F837E059AE13C"
+        );
+        assert_eq!(
+            mapped.restriction_deletions[0].to_uint64().to_hex(),
+            "84B3552D375FFA4B"
+        );
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_deserialize_account_operation_restriction_transaction() {
+        let transaction = operation_restriction_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<AccountOperationRestrictionTransaction>();
+
+        assert_eq!(
+            mapped.restriction_flags,
+            AccountRestrictionFlags::BLOCK_OUTGOING_TRANSACTION_TYPE
+        );
+        assert_eq!(
+            mapped.restriction_additions,
+            vec![TransactionType::Transfer, TransactionType::AggregateBonded]
+        );
+        assert_eq!(
+            mapped.restriction_deletions,
+            vec![TransactionType::SecretLock]
+        );
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_network_properties() {
+        let properties = AccountRestrictionNetworkProperties {
+            max_account_restriction_values: Some("2".to_owned()),
+        };
+
+        let transaction = address_restriction_transaction();
+        assert!(transaction.validate(&properties, 2).is_ok());
+        assert_eq!(
+            transaction
+                .validate(&properties, 0)
+                .unwrap_err()
+                .to_string(),
+            "Cannot remove 1 restriction values, the restriction has 0."
+        );
+
+        let transaction = mosaic_restriction_transaction();
+        assert_eq!(
+            transaction
+                .validate(&properties, 3)
+                .unwrap_err()
+                .to_string(),
+            "The restriction would have 3 values, the network allows at most 2."
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid account restriction flags 16385, expected a transaction type restriction."
+    )]
+    fn test_should_panic_for_mismatched_restriction_flags() {
+        AccountOperationRestrictionTransaction::create(
+            Deadline::from(1),
+            AccountRestrictionFlags::ALLOW_OUTGOING_ADDRESS,
+            vec![TransactionType::Transfer],
+            vec![],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Restriction mosaic 091F837E059AE13C is modified more than once.")]
+    fn test_should_panic_if_value_is_added_and_removed() {
+        let mosaic_id: Box<dyn UnresolvedMosaicId> =
+            Box::new(MosaicId::from_hex("091F837E059AE13C").unwrap());
+        AccountMosaicRestrictionTransaction::create(
+            Deadline::from(1),
+            AccountRestrictionFlags::BLOCK_MOSAIC,
+            vec![mosaic_id.clone()],
+            vec![mosaic_id],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+    }
+}
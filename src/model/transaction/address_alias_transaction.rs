@@ -0,0 +1,254 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::Address;
+use crate::namespace::{AliasAction, NamespaceId};
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline};
+
+/// Address alias transaction struct, links or unlinks a namespace to an account address.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressAliasTransaction {
+    pub common: CommonTransaction,
+    /// The alias action type.
+    pub alias_action: AliasAction,
+    /// The namespace id that will be an alias.
+    pub namespace_id: NamespaceId,
+    /// The aliased address.
+    pub address: Address,
+}
+
+impl AddressAliasTransaction {
+    /// The size of the address alias transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + Address::LENGTH_IN_DECODED + 1;
+
+    /// Create an address alias transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `alias_action`: The alias action type.
+    /// * `namespace_id`: The namespace id.
+    /// * `address`: The address to link or unlink.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AddressAliasTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        deadline: Deadline,
+        alias_action: AliasAction,
+        namespace_id: NamespaceId,
+        address: Address,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            address.network_type == network_type,
+            "Address network type {} does not match the transaction network type {}.",
+            address.network_type,
+            network_type
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::AddressAlias,
+            network_type,
+            TransactionVersion::ADDRESS_ALIAS,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            alias_action,
+            namespace_id,
+            address,
+        })
+    }
+
+    /// Creates an `AddressAliasTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AddressAliasTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::AddressAlias,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::AddressAlias
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates an `AddressAliasTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid address alias transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let namespace_id = NamespaceId::from(cursor.read_u64::<LittleEndian>()?);
+        let mut address = [0u8; Address::LENGTH_IN_DECODED];
+        cursor.read_exact(&mut address)?;
+        let alias_action = AliasAction::try_from(cursor.read_u8()?)?;
+
+        Ok(Self {
+            common,
+            alias_action,
+            namespace_id,
+            address: Address::from_encoded(hex::encode(address))?,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for AddressAliasTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.namespace_id.to_le_bytes());
+        buf.extend_from_slice(self.address.as_bytes());
+        buf.extend_from_slice(&self.alias_action.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for AddressAliasTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::namespace::{AliasAction, NamespaceId};
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, AddressAliasTransaction, Transaction, TransactionType,
+    };
+    use crate::Deadline;
+
+    fn address_alias_transaction(alias_action: AliasAction) -> AddressAliasTransaction {
+        AddressAliasTransaction::create(
+            Deadline::from(1),
+            alias_action,
+            NamespaceId::from([33347626, 3779697293]),
+            Address::from_raw("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ").unwrap(),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_address_alias_transaction() {
+        let transaction = address_alias_transaction(AliasAction::Link);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::AddressAlias
+        );
+        assert_eq!(transaction.size(), 161);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "2AD8FC018D9A49E1A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C53601"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_address_alias_transaction() {
+        let transaction = address_alias_transaction(AliasAction::Unlink);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<AddressAliasTransaction>();
+
+        assert_eq!(mapped.alias_action, AliasAction::Unlink);
+        assert_eq!(mapped.namespace_id, transaction.namespace_id);
+        assert_eq!(mapped.address, transaction.address);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Address network type PrivateTest does not match the transaction network type MainNet."
+    )]
+    fn test_should_panic_if_network_type_does_not_match() {
+        AddressAliasTransaction::create(
+            Deadline::from(1),
+            AliasAction::Link,
+            NamespaceId::from([33347626, 3779697293]),
+            Address::from_raw("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ").unwrap(),
+            NetworkType::MainNet,
+            None,
+        )
+        .unwrap();
+    }
+}
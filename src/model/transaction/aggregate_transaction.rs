@@ -0,0 +1,555 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+use sha3::{Digest, Sha3_256};
+
+use crate::core::crypto::MerkleHashBuilder;
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{
+    create_embedded_transaction_from_bytes, AggregateTransactionCosignature, Transaction,
+    TransactionType, TransactionVersion,
+};
+use crate::{hex_decode, is_hex, Deadline, H256};
+
+/// Aggregate transactions contain multiple inner transactions that are executed atomically.
+///
+/// An `AggregateComplete` transaction is announced when all the required cosignatures are
+/// attached, an `AggregateBonded` transaction collects the missing cosignatures on the network.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateTransaction {
+    pub common: CommonTransaction,
+    /// The vec of inner transactions, each of them carrying its signer.
+    pub inner_transactions: Vec<Box<dyn Transaction>>,
+    /// The vec of transaction cosigners signatures.
+    pub cosignatures: Vec<AggregateTransactionCosignature>,
+}
+
+impl AggregateTransaction {
+    /// The size of the fixed part of the aggregate transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = 32 + 4 + 4;
+
+    /// The alignment of the inner transactions in bytes.
+    ///
+    const INNER_TRANSACTION_ALIGNMENT: usize = 8;
+
+    /// Create an aggregate complete transaction object.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `inner_transactions`: The vec of inner transactions.
+    /// * `cosignatures`: The vec of `AggregateTransactionCosignature`.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AggregateTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create_complete(
+        deadline: Deadline,
+        inner_transactions: Vec<Box<dyn Transaction>>,
+        cosignatures: Vec<AggregateTransactionCosignature>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        Self::create(
+            TransactionType::AggregateComplete,
+            TransactionVersion::AGGREGATE_COMPLETE,
+            deadline,
+            inner_transactions,
+            cosignatures,
+            network_type,
+            max_fee,
+        )
+    }
+
+    /// Create an aggregate bonded transaction object.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `inner_transactions`: The vec of inner transactions.
+    /// * `cosignatures`: The vec of `AggregateTransactionCosignature`.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AggregateTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create_bonded(
+        deadline: Deadline,
+        inner_transactions: Vec<Box<dyn Transaction>>,
+        cosignatures: Vec<AggregateTransactionCosignature>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        Self::create(
+            TransactionType::AggregateBonded,
+            TransactionVersion::AGGREGATE_BONDED,
+            deadline,
+            inner_transactions,
+            cosignatures,
+            network_type,
+            max_fee,
+        )
+    }
+
+    fn create(
+        transaction_type: TransactionType,
+        version: TransactionVersion,
+        deadline: Deadline,
+        inner_transactions: Vec<Box<dyn Transaction>>,
+        cosignatures: Vec<AggregateTransactionCosignature>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        validate_inner_transactions(&inner_transactions)?;
+
+        let common = CommonTransaction::create_from_type(
+            transaction_type,
+            network_type,
+            version,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            inner_transactions,
+            cosignatures,
+        })
+    }
+
+    /// Adds inner transactions to the aggregate transaction.
+    ///
+    pub fn add_transactions(&mut self, transactions: Vec<Box<dyn Transaction>>) -> Result<()> {
+        validate_inner_transactions(&transactions)?;
+        self.inner_transactions.extend(transactions);
+        Ok(())
+    }
+
+    /// Adds cosignatures to the aggregate transaction.
+    ///
+    pub fn add_cosignatures(&mut self, cosignatures: Vec<AggregateTransactionCosignature>) {
+        self.cosignatures.extend(cosignatures);
+    }
+
+    /// Calculates the merkle root hash of the inner transactions.
+    ///
+    pub fn calculate_transactions_hash(&self) -> H256 {
+        let mut builder = MerkleHashBuilder::default();
+        self.inner_transactions.iter().for_each(|transaction| {
+            let hash = Sha3_256::digest(&transaction.to_embedded_bytes());
+            builder.update(H256::from_slice(&hash));
+        });
+        builder.finalize()
+    }
+
+    /// Creates an `AggregateTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `AggregateTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::AggregateComplete
+                || common.transaction_type == TransactionType::AggregateBonded,
+            "Invalid transaction type {}, expected an aggregate transaction.",
+            common.transaction_type
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates an `AggregateTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid aggregate transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut transactions_hash = [0u8; 32];
+        cursor.read_exact(&mut transactions_hash)?;
+        let payload_size = cursor.read_u32::<LittleEndian>()? as usize;
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE + payload_size,
+            "Invalid aggregate transaction payload size {}, the body has {} bytes.",
+            payload_size,
+            body.len()
+        );
+
+        let transactions = &body[Self::BODY_FIXED_SIZE..Self::BODY_FIXED_SIZE + payload_size];
+        let mut inner_transactions = vec![];
+        let mut offset = 0;
+        while offset < transactions.len() {
+            ensure!(
+                transactions.len() - offset >= 4,
+                "Invalid aggregate transaction, truncated inner transaction at offset {}.",
+                offset
+            );
+            let mut size = [0u8; 4];
+            size.copy_from_slice(&transactions[offset..offset + 4]);
+            let size = u32::from_le_bytes(size) as usize;
+            ensure!(
+                size > 0 && offset + size <= transactions.len(),
+                "Invalid aggregate transaction, truncated inner transaction at offset {}.",
+                offset
+            );
+
+            inner_transactions.push(create_embedded_transaction_from_bytes(
+                &transactions[offset..offset + size],
+            )?);
+            offset += size + padding_size(size, Self::INNER_TRANSACTION_ALIGNMENT);
+        }
+
+        let cosignatures_bytes = &body[Self::BODY_FIXED_SIZE + payload_size..];
+        ensure!(
+            cosignatures_bytes.len() % AggregateTransactionCosignature::SIZE == 0,
+            "Invalid aggregate transaction cosignatures size {}.",
+            cosignatures_bytes.len()
+        );
+
+        let cosignatures = cosignatures_bytes
+            .chunks(AggregateTransactionCosignature::SIZE)
+            .map(|bytes| AggregateTransactionCosignature::from_bytes(bytes, common.network_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let aggregate_transaction = Self {
+            common,
+            inner_transactions,
+            cosignatures,
+        };
+        ensure!(
+            aggregate_transaction.calculate_transactions_hash().as_bytes() == transactions_hash,
+            "Invalid aggregate transaction, the transactions hash does not match the inner transactions."
+        );
+
+        Ok(aggregate_transaction)
+    }
+
+    fn inner_transactions_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for transaction in self.inner_transactions.iter() {
+            let bytes = transaction.to_embedded_bytes();
+            let padding_size = padding_size(bytes.len(), Self::INNER_TRANSACTION_ALIGNMENT);
+            buf.extend(bytes);
+            buf.extend(vec![0u8; padding_size]);
+        }
+        buf
+    }
+}
+
+#[typetag::serde]
+impl Transaction for AggregateTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let transactions = self.inner_transactions_to_bytes();
+
+        let mut buf = Vec::with_capacity(
+            Self::BODY_FIXED_SIZE
+                + transactions.len()
+                + self.cosignatures.len() * AggregateTransactionCosignature::SIZE,
+        );
+        buf.extend_from_slice(self.calculate_transactions_hash().as_bytes());
+        buf.extend_from_slice(&(transactions.len() as u32).to_le_bytes());
+        // aggregate_transaction_header_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend(transactions);
+        for cosignature in self.cosignatures.iter() {
+            buf.extend(cosignature.to_bytes());
+        }
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for AggregateTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Ensures the inner transactions can be embedded in an aggregate transaction.
+///
+fn validate_inner_transactions(transactions: &[Box<dyn Transaction>]) -> Result<()> {
+    for transaction in transactions.iter() {
+        let common = transaction.common();
+        ensure!(
+            common.signer.is_some(),
+            "Inner transaction {} has no signer, use to_aggregate to set it.",
+            common.transaction_type
+        );
+        ensure!(
+            common.transaction_type != TransactionType::AggregateComplete
+                && common.transaction_type != TransactionType::AggregateBonded,
+            "Aggregate transactions cannot be embedded in an aggregate transaction."
+        );
+    }
+    Ok(())
+}
+
+/// Returns the number of padding bytes needed to align `size` to `alignment`.
+///
+fn padding_size(size: usize, alignment: usize) -> usize {
+    (alignment - size % alignment) % alignment
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::account::tests::{COSIGNATORY2_ACCOUNT, COSIGNATORY_ACCOUNT, TESTING_ACCOUNT};
+    use crate::account::Address;
+    use crate::message::PlainMessage;
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        AggregateTransaction, AggregateTransactionCosignature, Transaction, TransactionType,
+        TransferTransaction,
+    };
+    use crate::{Deadline, GenerationHash};
+
+    const GENERATION_HASH: &str =
+        "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+    fn inner_transaction() -> Box<dyn Transaction> {
+        TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ").unwrap(),
+            vec![],
+            PlainMessage::create("test-message"),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+        .to_aggregate(TESTING_ACCOUNT.public_account)
+    }
+
+    #[test]
+    fn test_should_create_aggregate_complete_transaction() {
+        let aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![inner_transaction()],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            aggregate_transaction.common.transaction_type,
+            TransactionType::AggregateComplete
+        );
+        assert_eq!(aggregate_transaction.inner_transactions.len(), 1);
+        assert_eq!(aggregate_transaction.size(), 264);
+        assert_eq!(
+            &aggregate_transaction.serialize()[216..256],
+            "01A8414100000000000000000100000000000000"
+        );
+        assert_eq!(
+            &aggregate_transaction.serialize()[256..],
+            "6E3FCA3875F88774E3177233103EFEC06B3C94B4BB6386CBEE4B3F1912353018\
+             6000000000000000\
+             5D000000000000009801508C58666C746F471538E43002B85B1CD542F9874B28\
+             61183919BA8787B60000000001A85441A826D27E1D0A26CA4E316F901E23E55C\
+             8711DB20DF45C5360D0000000000000000746573742D6D657373616765000000"
+        );
+    }
+
+    #[test]
+    fn test_should_create_aggregate_bonded_transaction() {
+        let aggregate_transaction = AggregateTransaction::create_bonded(
+            Deadline::from(1),
+            vec![inner_transaction()],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            aggregate_transaction.common.transaction_type,
+            TransactionType::AggregateBonded
+        );
+        assert_eq!(&aggregate_transaction.serialize()[220..224], "4142");
+    }
+
+    #[test]
+    fn test_should_calculate_transactions_hash() {
+        let mut aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+        assert!(aggregate_transaction
+            .calculate_transactions_hash()
+            .is_zero());
+
+        aggregate_transaction
+            .add_transactions(vec![inner_transaction()])
+            .unwrap();
+        assert_eq!(
+            format!("{:X}", aggregate_transaction.calculate_transactions_hash()),
+            "6E3FCA3875F88774E3177233103EFEC06B3C94B4BB6386CBEE4B3F1912353018"
+        );
+    }
+
+    #[test]
+    fn test_should_serialize_cosignatures() {
+        let generation_hash = GenerationHash::from_str(GENERATION_HASH).unwrap();
+
+        let mut aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![inner_transaction()],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        let signed_transaction = TESTING_ACCOUNT
+            .sign_transaction_with_cosignatories(
+                aggregate_transaction.to_bytes(),
+                vec![COSIGNATORY_ACCOUNT.clone(), COSIGNATORY2_ACCOUNT.clone()],
+                generation_hash,
+            )
+            .unwrap();
+
+        let cosignatures = [&*COSIGNATORY_ACCOUNT, &*COSIGNATORY2_ACCOUNT]
+            .iter()
+            .map(|cosignatory| {
+                let cosignature = cosignatory
+                    .sign_cosignature_transaction(
+                        hex::decode(&signed_transaction.payload).unwrap(),
+                        generation_hash,
+                    )
+                    .unwrap();
+                AggregateTransactionCosignature::from_cosignature_signed_transaction(
+                    &cosignature,
+                    NetworkType::PrivateTest,
+                )
+                .unwrap()
+            })
+            .collect();
+        aggregate_transaction.add_cosignatures(cosignatures);
+
+        assert_eq!(aggregate_transaction.size(), 264 + 2 * 104);
+
+        let signed_with_cosignatures = TESTING_ACCOUNT
+            .sign_transaction(aggregate_transaction.to_bytes(), generation_hash)
+            .unwrap();
+        assert_eq!(signed_with_cosignatures, signed_transaction);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Inner transaction Transfer has no signer, use to_aggregate to set it."
+    )]
+    fn test_should_panic_if_inner_transaction_has_no_signer() {
+        let transfer_transaction = TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ").unwrap(),
+            vec![],
+            PlainMessage::empty(),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![Box::new(transfer_transaction)],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Aggregate transactions cannot be embedded in an aggregate transaction."
+    )]
+    fn test_should_panic_if_inner_transaction_is_aggregate() {
+        let aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![inner_transaction()],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        AggregateTransaction::create_bonded(
+            Deadline::from(1),
+            vec![aggregate_transaction.to_aggregate(TESTING_ACCOUNT.public_account)],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+
+use crate::account::PublicAccount;
+use crate::network::NetworkType;
+use crate::transaction::CosignatureSignedTransaction;
+use crate::{hex_decode, is_hex};
+
+/// Model representing cosignature of an aggregate transaction.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateTransactionCosignature {
+    /// The signature of aggregate transaction done by the cosigner.
+    pub signature: String,
+    /// The cosigner public account.
+    pub signer: PublicAccount,
+    /// The cosignature version.
+    pub version: u64,
+}
+
+impl AggregateTransactionCosignature {
+    /// The size of a serialized cosignature in bytes.
+    ///
+    pub const SIZE: usize = 104;
+
+    /// Create an `AggregateTransactionCosignature`.
+    ///
+    pub fn create(signature: &str, signer: PublicAccount) -> Result<Self> {
+        ensure!(
+            is_hex(signature) && signature.len() == 128,
+            "Invalid cosignature signature {}.",
+            signature
+        );
+
+        Ok(Self {
+            signature: signature.to_uppercase(),
+            signer,
+            version: 0,
+        })
+    }
+
+    /// Creates an `AggregateTransactionCosignature` from a `CosignatureSignedTransaction`.
+    ///
+    pub fn from_cosignature_signed_transaction(
+        cosignature: &CosignatureSignedTransaction,
+        network_type: NetworkType,
+    ) -> Result<Self> {
+        let signer = PublicAccount::from_public_key(&cosignature.signer_public_key, network_type)?;
+
+        Ok(Self {
+            version: cosignature.version,
+            ..Self::create(&cosignature.signature, signer)?
+        })
+    }
+
+    /// Creates an `AggregateTransactionCosignature` from its catbuffer binary layout.
+    ///
+    pub fn from_bytes(bytes: &[u8], network_type: NetworkType) -> Result<Self> {
+        ensure!(
+            bytes.len() == Self::SIZE,
+            "Invalid cosignature size {}, expected {} bytes.",
+            bytes.len(),
+            Self::SIZE
+        );
+
+        let mut version = [0u8; 8];
+        version.copy_from_slice(&bytes[0..8]);
+
+        Ok(Self {
+            signature: hex::encode_upper(&bytes[40..104]),
+            signer: PublicAccount::from_public_key(hex::encode(&bytes[8..40]), network_type)?,
+            version: u64::from_le_bytes(version),
+        })
+    }
+
+    /// Serializes the cosignature into its catbuffer binary layout.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::SIZE);
+        buf.extend_from_slice(&self.version.to_le_bytes());
+        buf.extend_from_slice(self.signer.public_key.as_bytes());
+        buf.extend(hex_decode(&self.signature));
+        buf
+    }
+}
+
+impl fmt::Display for AggregateTransactionCosignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
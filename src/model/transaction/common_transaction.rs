@@ -8,10 +8,16 @@
  * // except according to those terms.
  */
 
+use std::convert::TryFrom;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
 use crate::account::PublicAccount;
 use crate::network::NetworkType;
 use crate::transaction::{TransactionInfo, TransactionType, TransactionVersion};
-use crate::{Deadline, H256};
+use crate::{hex_decode, is_hex, Deadline, H256};
 
 pub type Height = u64;
 
@@ -33,6 +39,7 @@ pub struct CommonTransaction {
 
     /// A sender of a transaction must specify during the transaction definition a max_fee,
     /// meaning the maximum fee the account allows to spend for this transaction.
+    /// The transaction `create` functions set it to 0 when no max fee is given.
     pub max_fee: u64,
 
     /// The transaction signature (missing if part of an aggregate transaction).
@@ -49,6 +56,22 @@ pub struct CommonTransaction {
 }
 
 impl CommonTransaction {
+    /// The size of the serialized transaction header in bytes.
+    ///
+    pub const HEADER_SIZE: usize = 128;
+
+    /// The size of the serialized embedded transaction header in bytes.
+    ///
+    pub const EMBEDDED_HEADER_SIZE: usize = 48;
+
+    /// The size of the transaction signature in bytes.
+    ///
+    pub const SIGNATURE_SIZE: usize = 64;
+
+    /// The size of the signer public key in bytes.
+    ///
+    pub const SIGNER_SIZE: usize = 32;
+
     pub fn create_from_type(
         transaction_type: TransactionType,
         network_type: NetworkType,
@@ -77,6 +100,178 @@ impl CommonTransaction {
             _ => H256::default(),
         }
     }
+
+    /// Serializes the transaction header for a transaction of the given total `size`.
+    ///
+    /// The missing signature and signer are serialized as zero bytes.
+    ///
+    pub(crate) fn header_to_bytes(&self, size: usize) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size);
+        buf.extend_from_slice(&(size as u32).to_le_bytes());
+        // verifiable_entity_header_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend_from_slice(&self.signature_to_bytes());
+        buf.extend_from_slice(&self.signer_to_bytes());
+        // entity_body_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend_from_slice(&self.version.to_bytes());
+        buf.extend_from_slice(&self.network_type.to_bytes());
+        buf.extend_from_slice(&self.transaction_type.to_bytes());
+        buf.extend_from_slice(&self.max_fee.to_le_bytes());
+        buf.extend_from_slice(&self.deadline.to_bytes());
+        buf
+    }
+
+    /// Serializes the embedded transaction header for an embedded transaction of the given `size`.
+    ///
+    /// The embedded header only carries the signer, the signature, max fee and deadline
+    /// are defined by the aggregate transaction.
+    ///
+    pub(crate) fn embedded_header_to_bytes(&self, size: usize) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size);
+        buf.extend_from_slice(&(size as u32).to_le_bytes());
+        // embedded_transaction_header_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend_from_slice(&self.signer_to_bytes());
+        // entity_body_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend_from_slice(&self.version.to_bytes());
+        buf.extend_from_slice(&self.network_type.to_bytes());
+        buf.extend_from_slice(&self.transaction_type.to_bytes());
+        buf
+    }
+
+    /// Deserializes a transaction header from the given payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the `CommonTransaction` together with the transaction body bytes
+    /// or whose error value is an `Error` describing the error that occurred.
+    ///
+    pub(crate) fn from_bytes(payload: &[u8]) -> Result<(Self, &[u8])> {
+        ensure!(
+            payload.len() >= Self::HEADER_SIZE,
+            "Invalid transaction payload size {}, the header requires {} bytes.",
+            payload.len(),
+            Self::HEADER_SIZE
+        );
+
+        let mut cursor = Cursor::new(payload);
+        let size = cursor.read_u32::<LittleEndian>()? as usize;
+        ensure!(
+            size >= Self::HEADER_SIZE && size <= payload.len(),
+            "Invalid transaction size {}, the payload has {} bytes.",
+            size,
+            payload.len()
+        );
+        cursor.set_position(8);
+
+        let mut signature = [0u8; Self::SIGNATURE_SIZE];
+        cursor.read_exact(&mut signature)?;
+        let mut signer = [0u8; Self::SIGNER_SIZE];
+        cursor.read_exact(&mut signer)?;
+        cursor.set_position(108);
+
+        let version = TransactionVersion::from(cursor.read_u8()?);
+        let network_type = NetworkType::try_from(cursor.read_u8()?)?;
+        let mut entity_type = [0u8; 2];
+        cursor.read_exact(&mut entity_type)?;
+        let transaction_type = TransactionType::try_from(entity_type)?;
+        let max_fee = cursor.read_u64::<LittleEndian>()?;
+        let deadline = Deadline::from(cursor.read_u64::<LittleEndian>()?);
+
+        let mut common =
+            Self::create_from_type(transaction_type, network_type, version, deadline, max_fee);
+
+        if signature.iter().any(|b| *b != 0) {
+            common.signature = Some(hex::encode_upper(signature));
+        }
+
+        common.signer = signer_from_bytes(signer, network_type)?;
+
+        Ok((common, &payload[Self::HEADER_SIZE..size]))
+    }
+
+    /// Deserializes an embedded transaction header from the given payload.
+    ///
+    /// The max fee and deadline are not part of an embedded transaction and are left empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the `CommonTransaction` together with the transaction body bytes
+    /// or whose error value is an `Error` describing the error that occurred.
+    ///
+    pub(crate) fn from_embedded_bytes(payload: &[u8]) -> Result<(Self, &[u8])> {
+        ensure!(
+            payload.len() >= Self::EMBEDDED_HEADER_SIZE,
+            "Invalid embedded transaction payload size {}, the header requires {} bytes.",
+            payload.len(),
+            Self::EMBEDDED_HEADER_SIZE
+        );
+
+        let mut cursor = Cursor::new(payload);
+        let size = cursor.read_u32::<LittleEndian>()? as usize;
+        ensure!(
+            size >= Self::EMBEDDED_HEADER_SIZE && size <= payload.len(),
+            "Invalid embedded transaction size {}, the payload has {} bytes.",
+            size,
+            payload.len()
+        );
+        cursor.set_position(8);
+
+        let mut signer = [0u8; Self::SIGNER_SIZE];
+        cursor.read_exact(&mut signer)?;
+        cursor.set_position(44);
+
+        let version = TransactionVersion::from(cursor.read_u8()?);
+        let network_type = NetworkType::try_from(cursor.read_u8()?)?;
+        let mut entity_type = [0u8; 2];
+        cursor.read_exact(&mut entity_type)?;
+        let transaction_type = TransactionType::try_from(entity_type)?;
+
+        let mut common = Self::create_from_type(
+            transaction_type,
+            network_type,
+            version,
+            Deadline::default(),
+            0,
+        );
+        common.signer = signer_from_bytes(signer, network_type)?;
+
+        Ok((common, &payload[Self::EMBEDDED_HEADER_SIZE..size]))
+    }
+
+    fn signature_to_bytes(&self) -> [u8; Self::SIGNATURE_SIZE] {
+        let mut buf = [0u8; Self::SIGNATURE_SIZE];
+        if let Some(signature) = &self.signature {
+            if is_hex(signature) && signature.len() == Self::SIGNATURE_SIZE * 2 {
+                buf.copy_from_slice(&hex_decode(signature));
+            }
+        }
+        buf
+    }
+
+    fn signer_to_bytes(&self) -> [u8; Self::SIGNER_SIZE] {
+        let mut buf = [0u8; Self::SIGNER_SIZE];
+        if let Some(signer) = &self.signer {
+            buf.copy_from_slice(signer.public_key.as_bytes());
+        }
+        buf
+    }
+}
+
+fn signer_from_bytes(
+    signer: [u8; CommonTransaction::SIGNER_SIZE],
+    network_type: NetworkType,
+) -> Result<Option<PublicAccount>> {
+    if signer.iter().all(|b| *b == 0) {
+        return Ok(None);
+    }
+
+    Ok(Some(PublicAccount::from_public_key(
+        hex::encode(signer),
+        network_type,
+    )?))
 }
 
 impl core::fmt::Display for CommonTransaction {
@@ -91,6 +91,12 @@ impl Deref for Deadline {
     }
 }
 
+impl From<u64> for Deadline {
+    fn from(value: u64) -> Self {
+        Deadline(value)
+    }
+}
+
 impl core::fmt::Display for Deadline {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", self.deref())
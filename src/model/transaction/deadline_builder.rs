@@ -0,0 +1,158 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{ensure, Result};
+use chrono::{DateTime, Duration, Local};
+
+use crate::node::NodeTime;
+use crate::Deadline;
+
+/// The deadline builder, creates deadlines from the network clock instead of the local clock.
+///
+/// The offset between the local and the network clock is computed once from the node time
+/// and cached, reuse the builder for the deadlines of a session and create a new one to
+/// synchronize the clocks again.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadlineBuilder {
+    /// The network's epoch adjustment in seconds.
+    pub epoch_adjustment: u64,
+    /// The milliseconds to add to the local clock to get the network clock.
+    pub clock_offset: i64,
+}
+
+impl DeadlineBuilder {
+    /// Create a deadline builder that trusts the local clock.
+    ///
+    /// # Inputs
+    ///
+    /// * `epoch_adjustment`: The network's epoch adjustment in seconds,
+    ///   see `NetworkProperties::get_epoch_adjustment`.
+    ///
+    pub fn create(epoch_adjustment: u64) -> Self {
+        Self {
+            epoch_adjustment,
+            clock_offset: 0,
+        }
+    }
+
+    /// Create a deadline builder synchronized with the node clock.
+    ///
+    /// The offset is estimated from the node communication timestamps and the local time the
+    /// request was sent and the response was received, assuming a symmetric network delay.
+    ///
+    /// # Inputs
+    ///
+    /// * `epoch_adjustment`: The network's epoch adjustment in seconds.
+    /// * `node_time`: The node time, see `NodeApi::get_node_time`.
+    /// * `request_sent`: The local time the node time request was sent.
+    /// * `response_received`: The local time the node time response was received.
+    ///
+    pub fn from_node_time(
+        epoch_adjustment: u64,
+        node_time: &NodeTime,
+        request_sent: DateTime<Local>,
+        response_received: DateTime<Local>,
+    ) -> Self {
+        let epoch_adjustment_millis = epoch_adjustment as i64 * 1000;
+        let request_sent = request_sent.timestamp_millis() - epoch_adjustment_millis;
+        let response_received = response_received.timestamp_millis() - epoch_adjustment_millis;
+
+        let clock_offset = ((node_time.receive_timestamp as i64 - request_sent)
+            + (node_time.send_timestamp as i64 - response_received))
+            / 2;
+
+        Self {
+            epoch_adjustment,
+            clock_offset,
+        }
+    }
+
+    /// Returns the current network time in milliseconds elapsed since the nemesis block.
+    ///
+    pub fn network_timestamp(&self) -> u64 {
+        let local_timestamp = Local::now().timestamp_millis();
+        (local_timestamp + self.clock_offset - self.epoch_adjustment as i64 * 1000).max(0) as u64
+    }
+
+    /// Create a deadline from the network clock.
+    ///
+    /// # Inputs
+    ///
+    /// * `duration`: The time until the deadline.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `Deadline` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn build(&self, duration: Duration) -> Result<Deadline> {
+        ensure!(
+            duration.num_milliseconds() > 0,
+            "deadline should be greater than 0"
+        );
+
+        Ok(Deadline::from(
+            self.network_timestamp() + duration.num_milliseconds() as u64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local, TimeZone};
+
+    use crate::node::NodeTime;
+    use crate::transaction::DeadlineBuilder;
+
+    const EPOCH_ADJUSTMENT: u64 = 1573430400;
+
+    #[test]
+    fn test_should_compute_clock_offset_from_node_time() {
+        let node_time = NodeTime {
+            send_timestamp: 10_600,
+            receive_timestamp: 10_500,
+        };
+        let request_sent = Local
+            .timestamp_millis_opt(EPOCH_ADJUSTMENT as i64 * 1000 + 10_000)
+            .unwrap();
+        let response_received = request_sent + Duration::milliseconds(200);
+
+        let builder = DeadlineBuilder::from_node_time(
+            EPOCH_ADJUSTMENT,
+            &node_time,
+            request_sent,
+            response_received,
+        );
+
+        assert_eq!(builder.clock_offset, 450);
+    }
+
+    #[test]
+    fn test_should_build_deadline_from_network_clock() {
+        let mut builder = DeadlineBuilder::create(EPOCH_ADJUSTMENT);
+        let local_deadline = *builder.build(Duration::hours(2)).unwrap();
+
+        builder.clock_offset = 60_000;
+        let network_deadline = *builder.build(Duration::hours(2)).unwrap();
+
+        let difference = network_deadline - local_deadline;
+        assert!((60_000..61_000).contains(&difference));
+    }
+
+    #[test]
+    #[should_panic(expected = "deadline should be greater than 0")]
+    fn test_should_panic_for_zero_duration() {
+        DeadlineBuilder::create(EPOCH_ADJUSTMENT)
+            .build(Duration::zero())
+            .unwrap();
+    }
+}
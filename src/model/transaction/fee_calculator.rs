@@ -0,0 +1,201 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{anyhow, Result};
+
+use crate::network::{FeeStrategy, TransactionFees};
+use crate::transaction::{AggregateTransaction, AggregateTransactionCosignature, Transaction};
+
+/// The fee calculator, estimates the `max_fee` of a transaction as its size
+/// multiplied by the fee multiplier.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeCalculator {
+    /// The fee multiplier, the fee paid per byte of the transaction.
+    pub fee_multiplier: u64,
+}
+
+impl FeeCalculator {
+    /// Create a fee calculator from the recent network transaction fees.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction_fees`: The network `TransactionFees`, see `NetworkApi::get_transaction_fees`.
+    /// * `strategy`: The `FeeStrategy` selecting the fee multiplier.
+    ///
+    pub fn create(transaction_fees: &TransactionFees, strategy: FeeStrategy) -> Self {
+        Self::from_fee_multiplier(transaction_fees.get_fee_multiplier(strategy))
+    }
+
+    /// Create a fee calculator with a fixed fee multiplier.
+    ///
+    pub fn from_fee_multiplier(fee_multiplier: u64) -> Self {
+        Self { fee_multiplier }
+    }
+
+    /// Calculates the size of the transaction once announced.
+    ///
+    /// Aggregate transactions grow with every cosignature, the size includes the cosignatures
+    /// still missing to reach `required_cosignatures`. It is ignored for other transactions.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The transaction.
+    /// * `required_cosignatures`: The number of cosignatures the aggregate transaction requires.
+    ///
+    pub fn calculate_size(
+        &self,
+        transaction: &dyn Transaction,
+        required_cosignatures: usize,
+    ) -> usize {
+        let missing_cosignatures = transaction
+            .as_any()
+            .downcast_ref::<AggregateTransaction>()
+            .map(|aggregate| required_cosignatures.saturating_sub(aggregate.cosignatures.len()))
+            .unwrap_or_default();
+
+        transaction.size() + missing_cosignatures * AggregateTransactionCosignature::SIZE
+    }
+
+    /// Calculates the max fee of a transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The transaction.
+    /// * `required_cosignatures`: The number of cosignatures the aggregate transaction requires.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the max fee or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn calculate_max_fee(
+        &self,
+        transaction: &dyn Transaction,
+        required_cosignatures: usize,
+    ) -> Result<u64> {
+        let size = self.calculate_size(transaction, required_cosignatures) as u64;
+        size.checked_mul(self.fee_multiplier).ok_or_else(|| {
+            anyhow!(
+                "Max fee overflow for size {} and fee multiplier {}.",
+                size,
+                self.fee_multiplier
+            )
+        })
+    }
+
+    /// Sets the calculated max fee on the transaction.
+    ///
+    /// The max fee must be set before the transaction is signed.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction`: The transaction.
+    /// * `required_cosignatures`: The number of cosignatures the aggregate transaction requires.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is the max fee that is set or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn set_max_fee(
+        &self,
+        transaction: &mut dyn Transaction,
+        required_cosignatures: usize,
+    ) -> Result<u64> {
+        let max_fee = self.calculate_max_fee(transaction, required_cosignatures)?;
+        transaction.common_mut().max_fee = max_fee;
+        Ok(max_fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::{Address, PublicAccount};
+    use crate::message::PlainMessage;
+    use crate::network::{FeeStrategy, NetworkType, TransactionFees};
+    use crate::transaction::{
+        AggregateTransaction, FeeCalculator, Transaction, TransferTransaction,
+    };
+    use crate::Deadline;
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+    const PUBLIC_KEY: &str = "9801508C58666C746F471538E43002B85B1CD542F9874B2861183919BA8787B6";
+
+    fn transfer_transaction() -> TransferTransaction {
+        TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            vec![],
+            PlainMessage::create("test-message"),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn transaction_fees() -> TransactionFees {
+        TransactionFees {
+            average_fee_multiplier: 150,
+            median_fee_multiplier: 100,
+            highest_fee_multiplier: 1000,
+            lowest_fee_multiplier: 10,
+            min_fee_multiplier: 25,
+        }
+    }
+
+    #[test]
+    fn test_should_select_fee_multiplier() {
+        let fees = transaction_fees();
+
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Slowest), 25);
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Slow), 25);
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Average), 150);
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Fast), 150);
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Fastest), 1000);
+
+        let fees = TransactionFees {
+            median_fee_multiplier: 200,
+            ..transaction_fees()
+        };
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Average), 150);
+        assert_eq!(fees.get_fee_multiplier(FeeStrategy::Fast), 200);
+    }
+
+    #[test]
+    fn test_should_calculate_max_fee() {
+        let mut transaction = transfer_transaction();
+        let calculator = FeeCalculator::create(&transaction_fees(), FeeStrategy::Average);
+
+        let max_fee = calculator.set_max_fee(&mut transaction, 3).unwrap();
+
+        assert_eq!(transaction.size(), 173);
+        assert_eq!(max_fee, 25950);
+        assert_eq!(transaction.common.max_fee, max_fee);
+    }
+
+    #[test]
+    fn test_should_include_missing_cosignatures_of_aggregate() {
+        let signer = PublicAccount::from_public_key(PUBLIC_KEY, NetworkType::TestNet).unwrap();
+        let aggregate = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![transfer_transaction().to_aggregate(signer)],
+            vec![],
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+        let calculator = FeeCalculator::from_fee_multiplier(100);
+
+        assert_eq!(calculator.calculate_size(&aggregate, 0), aggregate.size());
+        assert_eq!(calculator.calculate_max_fee(&aggregate, 2).unwrap(), 47200);
+    }
+}
@@ -0,0 +1,362 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::time::Duration;
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::core::utils::unresolved_mapping::to_unresolved_mosaic;
+use crate::mosaic::Mosaic;
+use crate::network::{HashLockNetworkProperties, NetworkType};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{SignedTransaction, Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, TransactionHash, Uint64, H256};
+
+/// Hash lock transaction struct, locks funds for an aggregate bonded transaction.
+///
+/// The aggregate bonded transaction can only be announced once the lock is confirmed,
+/// the locked funds are returned when the aggregate bonded transaction is completed.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashLockTransaction {
+    pub common: CommonTransaction,
+    /// The locked mosaic.
+    pub mosaic: Mosaic,
+    /// The number of blocks the funds are locked.
+    pub duration: Uint64,
+    /// The hash of the locked aggregate bonded transaction.
+    pub hash: TransactionHash,
+}
+
+impl HashLockTransaction {
+    /// The size of the hash lock transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 16 + 8 + 32;
+
+    /// Create a hash lock transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `mosaic`: The locked mosaic, see `HashLockNetworkProperties::locked_funds_per_aggregate`.
+    /// * `duration`: The number of blocks the funds are locked.
+    /// * `signed_transaction`: The signed aggregate bonded transaction to lock.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `HashLockTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        deadline: Deadline,
+        mosaic: Mosaic,
+        duration: Uint64,
+        signed_transaction: &SignedTransaction,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            signed_transaction.transaction_type == TransactionType::AggregateBonded,
+            "Signed transaction must be an aggregate bonded transaction, got {}.",
+            signed_transaction.transaction_type
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::Hash_Lock,
+            network_type,
+            TransactionVersion::HASH_LOCK,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            mosaic,
+            duration,
+            hash: signed_transaction.hash,
+        })
+    }
+
+    /// Validates the lock against the network hash lock properties.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `HashLockNetworkProperties`.
+    /// * `block_generation_target_time`: The targeted time between blocks,
+    ///   see `ChainProperties::get_block_generation_target_time`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(
+        &self,
+        properties: &HashLockNetworkProperties,
+        block_generation_target_time: Duration,
+    ) -> Result<()> {
+        let locked_funds = properties.get_locked_funds_per_aggregate()?;
+        ensure!(
+            *self.mosaic.amount == locked_funds,
+            "Invalid hash lock amount {}, the network requires {}.",
+            self.mosaic.amount,
+            locked_funds
+        );
+
+        let max_duration = properties.get_max_hash_lock_duration()?;
+        let max_blocks = max_duration.as_millis() / block_generation_target_time.as_millis().max(1);
+        ensure!(
+            *self.duration > 0 && u128::from(*self.duration) <= max_blocks,
+            "Invalid hash lock duration {}, expected between 1 and {} blocks.",
+            self.duration,
+            max_blocks
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `HashLockTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `HashLockTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::Hash_Lock,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::Hash_Lock
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `HashLockTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid hash lock transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let amount = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let duration = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let mut hash = [0u8; 32];
+        cursor.read_exact(&mut hash)?;
+
+        Ok(Self {
+            common,
+            mosaic: Mosaic {
+                id: to_unresolved_mosaic(&mosaic_id.to_hex())?,
+                amount,
+            },
+            duration,
+            hash: H256::from(hash),
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for HashLockTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend(self.mosaic.to_vec());
+        buf.extend_from_slice(&self.duration.to_le_bytes());
+        buf.extend_from_slice(self.hash.as_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for HashLockTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use crate::account::tests::TESTING_ACCOUNT;
+    use crate::mosaic::{Mosaic, MosaicId};
+    use crate::network::{HashLockNetworkProperties, NetworkType};
+    use crate::transaction::{
+        create_transaction_from_payload, AggregateTransaction, HashLockTransaction,
+        SignedTransaction, Transaction, TransactionType,
+    };
+    use crate::{Deadline, GenerationHash, Uint64};
+
+    const GENERATION_HASH: &str =
+        "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+    fn signed_aggregate_bonded_transaction() -> SignedTransaction {
+        let aggregate_transaction = AggregateTransaction::create_bonded(
+            Deadline::from(1),
+            vec![],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        TESTING_ACCOUNT
+            .sign_transaction(
+                aggregate_transaction.to_bytes(),
+                GenerationHash::from_str(GENERATION_HASH).unwrap(),
+            )
+            .unwrap()
+    }
+
+    fn hash_lock_transaction(amount: u64, duration: u64) -> HashLockTransaction {
+        HashLockTransaction::create(
+            Deadline::from(1),
+            Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), amount).unwrap(),
+            Uint64::from(duration),
+            &signed_aggregate_bonded_transaction(),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn hash_lock_network_properties() -> HashLockNetworkProperties {
+        HashLockNetworkProperties {
+            locked_funds_per_aggregate: Some("10'000'000".to_owned()),
+            max_hash_lock_duration: Some("2d".to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_should_create_hash_lock_transaction() {
+        let signed_transaction = signed_aggregate_bonded_transaction();
+        let transaction = hash_lock_transaction(10_000_000, 480);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::Hash_Lock
+        );
+        assert_eq!(transaction.hash, signed_transaction.hash);
+        assert_eq!(transaction.size(), 184);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!(
+                "3CE19A057E831F098096980000000000E001000000000000{:X}",
+                signed_transaction.hash
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_hash_lock_transaction() {
+        let transaction = hash_lock_transaction(10_000_000, 480);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<HashLockTransaction>();
+
+        assert_eq!(mapped.hash, transaction.hash);
+        assert_eq!(mapped.duration, Uint64::from(480));
+        assert_eq!(mapped.mosaic.amount, Uint64::from(10_000_000));
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_network_properties() {
+        let properties = hash_lock_network_properties();
+        let block_time = Duration::from_secs(30);
+
+        assert!(hash_lock_transaction(10_000_000, 5760)
+            .validate(&properties, block_time)
+            .is_ok());
+        assert_eq!(
+            hash_lock_transaction(10_000_000, 5761)
+                .validate(&properties, block_time)
+                .unwrap_err()
+                .to_string(),
+            "Invalid hash lock duration 5761, expected between 1 and 5760 blocks."
+        );
+        assert_eq!(
+            hash_lock_transaction(1, 480)
+                .validate(&properties, block_time)
+                .unwrap_err()
+                .to_string(),
+            "Invalid hash lock amount 1, the network requires 10000000."
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Signed transaction must be an aggregate bonded transaction, got Transfer."
+    )]
+    fn test_should_panic_if_signed_transaction_is_not_aggregate_bonded() {
+        let mut signed_transaction = signed_aggregate_bonded_transaction();
+        signed_transaction.transaction_type = TransactionType::Transfer;
+
+        HashLockTransaction::create(
+            Deadline::from(1),
+            Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), 10).unwrap(),
+            Uint64::from(480),
+            &signed_transaction,
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+}
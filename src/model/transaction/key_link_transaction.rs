@@ -0,0 +1,323 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use crypto::prelude::PublicKey;
+
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{LinkAction, Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline};
+
+/// The size of the linked public key in bytes.
+///
+const PUBLIC_KEY_SIZE: usize = 32;
+
+/// The size of a key link transaction body in bytes, the linked public key and the link action.
+///
+const BODY_SIZE: usize = PUBLIC_KEY_SIZE + 1;
+
+/// Defines a transaction whose body is a 32 bytes linked public key followed by a `LinkAction`.
+///
+macro_rules! key_link_transaction {
+    (
+        $(#[$doc:meta])*
+        $name:ident {
+            transaction_type: $transaction_type:ident,
+            version: $version:ident,
+            description: $description:literal,
+            linked_public_key: $linked_public_key_doc:literal,
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $name {
+            pub common: CommonTransaction,
+            #[doc = $linked_public_key_doc]
+            pub linked_public_key: PublicKey,
+            /// The link action.
+            pub link_action: LinkAction,
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new ", $description, " transaction.")]
+            ///
+            /// # Inputs
+            ///
+            /// * `deadline`: The deadline to include the transaction.
+            #[doc = concat!("* `linked_public_key`: ", $linked_public_key_doc)]
+            /// * `link_action`: The link action.
+            /// * `network_type`: The network type.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A `Result` whose okay value is the `", stringify!($name), "` or whose error value")]
+            /// is an `Error` describing the error that occurred.
+            ///
+            pub fn create(
+                deadline: Deadline,
+                linked_public_key: PublicKey,
+                link_action: LinkAction,
+                network_type: NetworkType,
+                max_fee: Option<u64>,
+            ) -> Result<Self> {
+                let common = CommonTransaction::create_from_type(
+                    TransactionType::$transaction_type,
+                    network_type,
+                    TransactionVersion::$version,
+                    deadline,
+                    max_fee.unwrap_or_default(),
+                );
+
+                Ok(Self {
+                    common,
+                    linked_public_key,
+                    link_action,
+                })
+            }
+
+            #[doc = concat!("Creates a `", stringify!($name), "` from its catbuffer binary layout.")]
+            ///
+            /// # Inputs
+            ///
+            /// * `payload`: The serialized transaction bytes.
+            ///
+            /// # Returns
+            ///
+            #[doc = concat!("A `Result` whose okay value is the `", stringify!($name), "` or whose error value")]
+            /// is an `Error` describing the error that occurred.
+            ///
+            pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+                let (common, body) = CommonTransaction::from_bytes(payload)?;
+                ensure!(
+                    common.transaction_type == TransactionType::$transaction_type,
+                    "Invalid transaction type {}, expected {}.",
+                    common.transaction_type,
+                    TransactionType::$transaction_type
+                );
+
+                Self::from_body(common, body)
+            }
+
+            #[doc = concat!("Creates a `", stringify!($name), "` from its hex payload.")]
+            ///
+            pub fn from_payload(payload: &str) -> Result<Self> {
+                ensure!(
+                    is_hex(payload),
+                    "Input string is not in valid hexadecimal notation."
+                );
+
+                Self::from_bytes(&hex_decode(payload))
+            }
+
+            pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+                ensure!(
+                    body.len() == BODY_SIZE,
+                    concat!("Invalid ", $description, " transaction body size {}, expected {} bytes."),
+                    body.len(),
+                    BODY_SIZE
+                );
+
+                Ok(Self {
+                    common,
+                    linked_public_key: PublicKey::from_slice(&body[..PUBLIC_KEY_SIZE]),
+                    link_action: LinkAction::try_from(body[PUBLIC_KEY_SIZE])?,
+                })
+            }
+        }
+
+        #[typetag::serde]
+        impl Transaction for $name {
+            fn common(&self) -> &CommonTransaction {
+                &self.common
+            }
+
+            fn common_mut(&mut self) -> &mut CommonTransaction {
+                &mut self.common
+            }
+
+            fn body_to_bytes(&self) -> Vec<u8> {
+                let mut buf = Vec::with_capacity(BODY_SIZE);
+                buf.extend_from_slice(self.linked_public_key.as_bytes());
+                buf.extend_from_slice(&self.link_action.to_bytes());
+                buf
+            }
+
+            fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+                Box::new((*self).clone())
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "{}",
+                    serde_json::to_string_pretty(&self).unwrap_or_default()
+                )
+            }
+        }
+    };
+}
+
+key_link_transaction! {
+    /// Account key link transaction struct, delegates the account importance
+    /// to a proxy account to enable delegated harvesting.
+    ///
+    AccountKeyLinkTransaction {
+        transaction_type: AccountKeyLink,
+        version: ACCOUNT_KEY_LINK,
+        description: "account key link",
+        linked_public_key: "The public key of the remote account.",
+    }
+}
+
+key_link_transaction! {
+    /// Node key link transaction struct, links an account to the public key of a node
+    /// to allow delegated harvesting on that node.
+    ///
+    NodeKeyLinkTransaction {
+        transaction_type: NodeKeyLink,
+        version: NODE_KEY_LINK,
+        description: "node key link",
+        linked_public_key: "The public key of the node.",
+    }
+}
+
+key_link_transaction! {
+    /// VRF key link transaction struct, links a verifiable random function public key
+    /// to an account, it is required to harvest.
+    ///
+    VrfKeyLinkTransaction {
+        transaction_type: VrfKeyLink,
+        version: VRF_KEY_LINK,
+        description: "VRF key link",
+        linked_public_key: "The VRF public key.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crypto::prelude::PublicKey;
+
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, AccountKeyLinkTransaction, LinkAction,
+        NodeKeyLinkTransaction, Transaction, TransactionType, VrfKeyLinkTransaction,
+    };
+    use crate::Deadline;
+
+    const PUBLIC_KEY: &str = "9801508C58666C746F471538E43002B85B1CD542F9874B2861183919BA8787B6";
+
+    fn key_link_transactions(link_action: LinkAction) -> Vec<Box<dyn Transaction>> {
+        let public_key = PublicKey::from_str(PUBLIC_KEY).unwrap();
+        let network_type = NetworkType::TestNet;
+
+        vec![
+            Box::new(
+                AccountKeyLinkTransaction::create(
+                    Deadline::from(1),
+                    public_key,
+                    link_action,
+                    network_type,
+                    None,
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                NodeKeyLinkTransaction::create(
+                    Deadline::from(1),
+                    public_key,
+                    link_action,
+                    network_type,
+                    None,
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                VrfKeyLinkTransaction::create(
+                    Deadline::from(1),
+                    public_key,
+                    link_action,
+                    network_type,
+                    None,
+                )
+                .unwrap(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_should_create_key_link_transactions() {
+        let transaction_types: Vec<TransactionType> = key_link_transactions(LinkAction::Link)
+            .iter()
+            .map(|transaction| transaction.common().transaction_type)
+            .collect();
+        assert_eq!(
+            transaction_types,
+            vec![
+                TransactionType::AccountKeyLink,
+                TransactionType::NodeKeyLink,
+                TransactionType::VrfKeyLink
+            ]
+        );
+
+        for transaction in key_link_transactions(LinkAction::Link) {
+            assert_eq!(transaction.size(), 128 + 33);
+            assert_eq!(&transaction.serialize()[256..], format!("{}01", PUBLIC_KEY));
+        }
+    }
+
+    #[test]
+    fn test_should_deserialize_key_link_transactions() {
+        for transaction in key_link_transactions(LinkAction::Unlink) {
+            let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+
+            assert_eq!(
+                mapped.common().transaction_type,
+                transaction.common().transaction_type
+            );
+            assert_eq!(mapped.serialize(), transaction.serialize());
+            assert_eq!(&mapped.serialize()[256..], format!("{}00", PUBLIC_KEY));
+        }
+
+        let vrf_key_link = VrfKeyLinkTransaction::from_payload(
+            &key_link_transactions(LinkAction::Unlink)[2].serialize(),
+        )
+        .unwrap();
+        assert_eq!(vrf_key_link.link_action, LinkAction::Unlink);
+        assert_eq!(
+            vrf_key_link.linked_public_key,
+            PublicKey::from_str(PUBLIC_KEY).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid transaction type VrfKeyLink, expected AccountKeyLink.")]
+    fn test_should_panic_if_key_link_transaction_type_does_not_match() {
+        let payload = key_link_transactions(LinkAction::Link)[2].serialize();
+        AccountKeyLinkTransaction::from_payload(&payload).unwrap();
+    }
+}
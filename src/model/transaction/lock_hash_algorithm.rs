@@ -0,0 +1,168 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{thread_rng, RngCore};
+use ripemd160::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Sha3_256};
+
+use crate::H256;
+
+/// The lock hash algorithm used to derive the secret of a secret lock from its proof.
+/// Supported algorithms are:
+///
+/// * 0 - Sha3_256: Input is hashed using Sha3-256.
+/// * 1 - Hash_160: Input is hashed twice, first with Sha-256 and then with Ripemd-160 (bitcoin's OP_HASH160).
+/// * 2 - Hash_256: Input is hashed twice with Sha-256 (bitcoin's OP_HASH256).
+///
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum LockHashAlgorithm {
+    /// Input is hashed using Sha3-256.
+    Sha3_256 = 0x00,
+    /// Input is hashed twice: first with Sha-256 and then with Ripemd-160.
+    Hash_160 = 0x01,
+    /// Input is hashed twice with Sha-256.
+    Hash_256 = 0x02,
+}
+
+impl LockHashAlgorithm {
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_bytes(&self) -> [u8; 1] {
+        self.value().to_le_bytes()
+    }
+
+    /// Creates the secret of the given proof.
+    ///
+    /// The `Hash_160` digest is 20 bytes long, the remaining bytes of the secret are zero.
+    ///
+    pub fn create_secret(&self, proof: &[u8]) -> H256 {
+        let mut secret = H256::zero();
+        match self {
+            LockHashAlgorithm::Sha3_256 => {
+                secret.assign_from_slice(Sha3_256::digest(proof).as_ref());
+            }
+            LockHashAlgorithm::Hash_160 => {
+                let hash = Ripemd160::digest(Sha256::digest(proof).as_ref());
+                secret[..hash.len()].copy_from_slice(hash.as_ref());
+            }
+            LockHashAlgorithm::Hash_256 => {
+                secret.assign_from_slice(Sha256::digest(Sha256::digest(proof).as_ref()).as_ref());
+            }
+        }
+        secret
+    }
+
+    /// Checks if the given proof unlocks the given secret.
+    ///
+    pub fn verify_proof(&self, proof: &[u8], secret: &H256) -> bool {
+        self.create_secret(proof) == *secret
+    }
+
+    /// Generates a random proof together with its secret.
+    ///
+    /// # Inputs
+    ///
+    /// * `proof_size`: The size of the proof in bytes,
+    ///   see `SecretLockNetworkProperties::get_max_proof_size`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a tuple with the proof and the secret or whose error
+    /// value is an `Error` describing the error that occurred.
+    ///
+    pub fn generate_proof(&self, proof_size: usize) -> Result<(Vec<u8>, H256)> {
+        ensure!(proof_size > 0, "Proof size must be greater than 0.");
+
+        let mut proof = vec![0u8; proof_size];
+        thread_rng().try_fill_bytes(&mut proof)?;
+
+        let secret = self.create_secret(&proof);
+        Ok((proof, secret))
+    }
+}
+
+impl fmt::Display for LockHashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Returns a 'LockHashAlgorithm' for the given u8 value.
+///
+/// Throws an Err when the algorithm is unknown.
+impl TryFrom<u8> for LockHashAlgorithm {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::Sha3_256 as u8 => Ok(Self::Sha3_256),
+            x if x == Self::Hash_160 as u8 => Ok(Self::Hash_160),
+            x if x == Self::Hash_256 as u8 => Ok(Self::Hash_256),
+            _ => Err(anyhow!("Unknown lock hash algorithm {}", v)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::transaction::LockHashAlgorithm;
+
+    #[test]
+    fn test_lock_hash_algorithm_values() {
+        assert_eq!(LockHashAlgorithm::Sha3_256.value(), 0x00);
+        assert_eq!(LockHashAlgorithm::Hash_160.value(), 0x01);
+        assert_eq!(LockHashAlgorithm::Hash_256.value(), 0x02);
+    }
+
+    #[test]
+    fn test_should_fail_for_unknown_value() {
+        assert!(LockHashAlgorithm::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_should_create_secret_for_each_algorithm() {
+        assert_eq!(
+            format!("{:X}", LockHashAlgorithm::Sha3_256.create_secret(&[])),
+            "A7FFC6F8BF1ED76651C14756A061D662F580FF4DE43B49FA82D80A4B80F8434A"
+        );
+        assert_eq!(
+            format!("{:X}", LockHashAlgorithm::Hash_160.create_secret(&[])),
+            "B472A266D0BD89C13706A4132CCFB16F7C3B9FCB000000000000000000000000"
+        );
+        assert_eq!(
+            format!("{:X}", LockHashAlgorithm::Hash_256.create_secret(&[])),
+            "5DF6E0E2761359D30A8275058E299FCC0381534545F55CF43E41983F5D4C9456"
+        );
+    }
+
+    #[test]
+    fn test_should_generate_proof_matching_secret() {
+        for algorithm in [
+            LockHashAlgorithm::Sha3_256,
+            LockHashAlgorithm::Hash_160,
+            LockHashAlgorithm::Hash_256,
+        ] {
+            let (proof, secret) = algorithm.generate_proof(32).unwrap();
+            assert_eq!(proof.len(), 32);
+            assert!(algorithm.verify_proof(&proof, &secret));
+        }
+    }
+}
@@ -8,17 +8,76 @@
  * // except according to those terms.
  */
 
+pub use self::account_metadata_transaction::*;
+pub use self::account_restriction_transaction::*;
+pub use self::address_alias_transaction::*;
+pub use self::aggregate_transaction::*;
+pub use self::aggregate_transaction_cosignature::*;
+pub use self::common_transaction::CommonTransaction;
 pub use self::deadline::*;
+pub use self::deadline_builder::*;
+pub use self::fee_calculator::*;
+pub use self::hash_lock_transaction::*;
+pub use self::key_link_transaction::*;
+pub use self::link_action::*;
+pub use self::lock_hash_algorithm::*;
+pub use self::mosaic_address_restriction_transaction::*;
+pub use self::mosaic_alias_transaction::*;
+pub use self::mosaic_definition_transaction::*;
+pub use self::mosaic_global_restriction_transaction::*;
+pub use self::mosaic_metadata_transaction::*;
+pub use self::mosaic_supply_change_transaction::*;
+pub use self::multisig_account_modification_transaction::*;
+pub use self::namespace_metadata_transaction::*;
+pub use self::namespace_registration_transaction::*;
+pub use self::secret_lock_transaction::*;
+pub use self::secret_proof_transaction::*;
+pub use self::signed_transaction::*;
 pub use self::transaction::*;
+pub use self::transaction_announce_response::*;
+pub use self::transaction_group::*;
+pub use self::transaction_hash::*;
 pub use self::transaction_info::*;
+pub use self::transaction_mapping::*;
+pub use self::transaction_status::*;
 pub use self::transaction_type::*;
 pub use self::transaction_version::*;
 pub use self::transfer_transaction::*;
+pub use self::voting_key_link_transaction::*;
 
+mod account_metadata_transaction;
+mod account_restriction_transaction;
+mod address_alias_transaction;
+mod aggregate_transaction;
+mod aggregate_transaction_cosignature;
 mod common_transaction;
 mod deadline;
+mod deadline_builder;
+mod fee_calculator;
+mod hash_lock_transaction;
+mod key_link_transaction;
+mod link_action;
+mod lock_hash_algorithm;
+mod mosaic_address_restriction_transaction;
+mod mosaic_alias_transaction;
+mod mosaic_definition_transaction;
+mod mosaic_global_restriction_transaction;
+mod mosaic_metadata_transaction;
+mod mosaic_supply_change_transaction;
+mod multisig_account_modification_transaction;
+mod namespace_metadata_transaction;
+mod namespace_registration_transaction;
+mod secret_lock_transaction;
+mod secret_proof_transaction;
+mod signed_transaction;
 mod transaction;
+mod transaction_announce_response;
+mod transaction_group;
+mod transaction_hash;
 mod transaction_info;
+mod transaction_mapping;
+mod transaction_status;
 mod transaction_type;
 mod transaction_version;
 mod transfer_transaction;
+mod voting_key_link_transaction;
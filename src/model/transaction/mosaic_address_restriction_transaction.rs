@@ -0,0 +1,341 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::mosaic::{MosaicFlags, UnresolvedMosaicId};
+use crate::network::{MosaicRestrictionNetworkProperties, NetworkType};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// Mosaic address restriction transaction struct, sets the restriction value of an account
+/// for a restrictable mosaic.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicAddressRestrictionTransaction {
+    pub common: CommonTransaction,
+    /// The unresolved mosaic id, a `MosaicId` or a `NamespaceId` alias.
+    pub mosaic_id: Box<dyn UnresolvedMosaicId>,
+    /// The restriction key, see `generate_uint64_key`.
+    pub restriction_key: Uint64,
+    /// The previous restriction value.
+    pub previous_restriction_value: Uint64,
+    /// The new restriction value.
+    pub new_restriction_value: Uint64,
+    /// The address of the restricted account.
+    pub target_address: Box<dyn UnresolvedAddress>,
+}
+
+impl MosaicAddressRestrictionTransaction {
+    /// The restriction value of an account without restriction value.
+    ///
+    pub const UNSET_RESTRICTION_VALUE: u64 = Uint64::MAX;
+
+    /// The size of the unresolved target address in bytes.
+    ///
+    const ADDRESS_SIZE: usize = 24;
+
+    /// The size of the mosaic address restriction transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + 8 + 8 + 8 + Self::ADDRESS_SIZE;
+
+    /// Create a mosaic address restriction transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `mosaic_id`: The unresolved mosaic id, the mosaic must be restrictable.
+    /// * `restriction_key`: The restriction key.
+    /// * `target_address`: The address of the restricted account.
+    /// * `new_restriction_value`: The new restriction value,
+    ///   `UNSET_RESTRICTION_VALUE` to remove the restriction value.
+    /// * `network_type`: The network type.
+    /// * `previous_restriction_value`: The previous restriction value,
+    ///   if none is set it is `UNSET_RESTRICTION_VALUE`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicAddressRestrictionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn create<M: 'static + UnresolvedMosaicId, R: 'static + UnresolvedAddress>(
+        deadline: Deadline,
+        mosaic_id: M,
+        restriction_key: Uint64,
+        target_address: R,
+        new_restriction_value: Uint64,
+        network_type: NetworkType,
+        previous_restriction_value: Option<Uint64>,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicAddressRestriction,
+            network_type,
+            TransactionVersion::MOSAIC_ADDRESS_RESTRICTION,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            mosaic_id: Box::new(mosaic_id),
+            restriction_key,
+            previous_restriction_value: previous_restriction_value
+                .unwrap_or_else(|| Uint64::from(Self::UNSET_RESTRICTION_VALUE)),
+            new_restriction_value,
+            target_address: Box::new(target_address),
+        })
+    }
+
+    /// The String notation for the set target address.
+    pub fn target_address_to_string(&self) -> String {
+        self.target_address.recipient_to_string()
+    }
+
+    /// Validates the restriction against the mosaic flags and the network mosaic restriction limits.
+    ///
+    /// # Inputs
+    ///
+    /// * `mosaic_flags`: The flags of the restricted mosaic.
+    /// * `properties`: The network `MosaicRestrictionNetworkProperties`.
+    /// * `current_values`: The number of restriction values of the account for the mosaic
+    ///   before the transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(
+        &self,
+        mosaic_flags: &MosaicFlags,
+        properties: &MosaicRestrictionNetworkProperties,
+        current_values: usize,
+    ) -> Result<()> {
+        ensure!(
+            mosaic_flags.restrictable,
+            "Mosaic {} is not restrictable.",
+            self.mosaic_id.to_uint64().to_hex()
+        );
+
+        let is_new_value = *self.previous_restriction_value == Self::UNSET_RESTRICTION_VALUE
+            && *self.new_restriction_value != Self::UNSET_RESTRICTION_VALUE;
+        let values = current_values + is_new_value as usize;
+        let max_values = properties.get_max_mosaic_restriction_values()?;
+        ensure!(
+            values as u64 <= max_values,
+            "The account would have {} restriction values, the network allows at most {}.",
+            values,
+            max_values
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `MosaicAddressRestrictionTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicAddressRestrictionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicAddressRestriction,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicAddressRestriction
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicAddressRestrictionTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid mosaic address restriction transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let restriction_key = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let previous_restriction_value = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let new_restriction_value = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let mut target_address = [0u8; Self::ADDRESS_SIZE];
+        cursor.read_exact(&mut target_address)?;
+
+        Ok(Self {
+            common,
+            mosaic_id: to_unresolved_mosaic(&mosaic_id.to_hex())?,
+            restriction_key,
+            previous_restriction_value,
+            new_restriction_value,
+            target_address: to_unresolved_address(&hex::encode(target_address))?,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicAddressRestrictionTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.mosaic_id.to_uint64().to_le_bytes());
+        buf.extend_from_slice(&self.restriction_key.to_le_bytes());
+        buf.extend_from_slice(&self.previous_restriction_value.to_le_bytes());
+        buf.extend_from_slice(&self.new_restriction_value.to_le_bytes());
+        buf.extend(
+            self.target_address
+                .unresolved_address_to_bytes(self.common.network_type),
+        );
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicAddressRestrictionTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::mosaic::{MosaicFlags, MosaicId};
+    use crate::network::{MosaicRestrictionNetworkProperties, NetworkType};
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicAddressRestrictionTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{generate_uint64_key, Deadline, Uint64};
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    fn mosaic_address_restriction_transaction(
+        previous_restriction_value: Option<Uint64>,
+    ) -> MosaicAddressRestrictionTransaction {
+        MosaicAddressRestrictionTransaction::create(
+            Deadline::from(1),
+            MosaicId::from(1),
+            generate_uint64_key("KYC").unwrap(),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            Uint64::from(8),
+            NetworkType::TestNet,
+            previous_restriction_value,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_mosaic_address_restriction_transaction() {
+        let transaction = mosaic_address_restriction_transaction(None);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicAddressRestriction
+        );
+        assert_eq!(transaction.target_address_to_string(), RAW_ADDRESS);
+        assert_eq!(transaction.size(), 184);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "010000000000000077DD7B907C631281FFFFFFFFFFFFFFFF0800000000000000\
+            9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_address_restriction_transaction() {
+        let transaction = mosaic_address_restriction_transaction(Some(Uint64::from(9)));
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicAddressRestrictionTransaction>();
+
+        assert_eq!(mapped.restriction_key, generate_uint64_key("KYC").unwrap());
+        assert_eq!(mapped.previous_restriction_value, Uint64::from(9));
+        assert_eq!(mapped.target_address_to_string(), RAW_ADDRESS);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_mosaic_flags_and_network_properties() {
+        let properties = MosaicRestrictionNetworkProperties {
+            max_mosaic_restriction_values: Some("20".to_owned()),
+        };
+        let restrictable = MosaicFlags::create(false, true, true);
+
+        let transaction = mosaic_address_restriction_transaction(None);
+        assert!(transaction.validate(&restrictable, &properties, 19).is_ok());
+        assert_eq!(
+            transaction
+                .validate(&restrictable, &properties, 20)
+                .unwrap_err()
+                .to_string(),
+            "The account would have 21 restriction values, the network allows at most 20."
+        );
+
+        let transaction = mosaic_address_restriction_transaction(Some(Uint64::from(9)));
+        assert!(transaction.validate(&restrictable, &properties, 20).is_ok());
+        assert!(transaction
+            .validate(&MosaicFlags::create(false, true, false), &properties, 0)
+            .is_err());
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Cursor;
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::mosaic::MosaicId;
+use crate::namespace::{AliasAction, NamespaceId};
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline};
+
+/// Mosaic alias transaction struct, links or unlinks a namespace to a mosaic.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicAliasTransaction {
+    pub common: CommonTransaction,
+    /// The alias action type.
+    pub alias_action: AliasAction,
+    /// The namespace id that will be an alias.
+    pub namespace_id: NamespaceId,
+    /// The aliased mosaic id.
+    pub mosaic_id: MosaicId,
+}
+
+impl MosaicAliasTransaction {
+    /// The size of the mosaic alias transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + 8 + 1;
+
+    /// Create a mosaic alias transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `alias_action`: The alias action type.
+    /// * `namespace_id`: The namespace id.
+    /// * `mosaic_id`: The mosaic id to link or unlink.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicAliasTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        deadline: Deadline,
+        alias_action: AliasAction,
+        namespace_id: NamespaceId,
+        mosaic_id: MosaicId,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicAlias,
+            network_type,
+            TransactionVersion::MOSAIC_ALIAS,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            alias_action,
+            namespace_id,
+            mosaic_id,
+        })
+    }
+
+    /// Creates a `MosaicAliasTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicAliasTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicAlias,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicAlias
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicAliasTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid mosaic alias transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let namespace_id = NamespaceId::from(cursor.read_u64::<LittleEndian>()?);
+        let mosaic_id = MosaicId::from(cursor.read_u64::<LittleEndian>()?);
+        let alias_action = AliasAction::try_from(cursor.read_u8()?)?;
+
+        Ok(Self {
+            common,
+            alias_action,
+            namespace_id,
+            mosaic_id,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicAliasTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.namespace_id.to_le_bytes());
+        buf.extend_from_slice(&self.mosaic_id.to_le_bytes());
+        buf.extend_from_slice(&self.alias_action.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicAliasTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::tests::TESTING_ACCOUNT;
+    use crate::mosaic::MosaicId;
+    use crate::namespace::{AliasAction, NamespaceId};
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicAliasTransaction, Transaction, TransactionType,
+    };
+    use crate::Deadline;
+
+    fn mosaic_alias_transaction() -> MosaicAliasTransaction {
+        MosaicAliasTransaction::create(
+            Deadline::from(1),
+            AliasAction::Link,
+            NamespaceId::from([33347626, 3779697293]),
+            MosaicId::from([2262289484, 3405110546]),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_mosaic_alias_transaction() {
+        let transaction = mosaic_alias_transaction();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicAlias
+        );
+        assert_eq!(transaction.size(), 145);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "2AD8FC018D9A49E14CCCD78612DDF5CA01"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_alias_transaction() {
+        let transaction = mosaic_alias_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicAliasTransaction>();
+
+        assert_eq!(mapped.alias_action, AliasAction::Link);
+        assert_eq!(mapped.namespace_id, transaction.namespace_id);
+        assert_eq!(mapped.mosaic_id, transaction.mosaic_id);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_serialize_embedded_mosaic_alias_transaction() {
+        let transaction = mosaic_alias_transaction().to_aggregate(TESTING_ACCOUNT.public_account);
+
+        assert_eq!(transaction.embedded_size(), 48 + 17);
+        assert_eq!(
+            &hex::encode_upper(transaction.to_embedded_bytes())[96..],
+            "2AD8FC018D9A49E14CCCD78612DDF5CA01"
+        );
+    }
+}
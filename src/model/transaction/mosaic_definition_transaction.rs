@@ -0,0 +1,281 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::mosaic::{MosaicFlags, MosaicId, MosaicNonce};
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// Mosaic definition transaction struct, creates a new mosaic.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicDefinitionTransaction {
+    pub common: CommonTransaction,
+    /// The mosaic nonce, used with the owner address to derive the mosaic id.
+    pub nonce: MosaicNonce,
+    /// The mosaic id.
+    pub mosaic_id: MosaicId,
+    /// The mosaic flags.
+    pub flags: MosaicFlags,
+    /// The mosaic divisibility, the number of decimal places.
+    pub divisibility: u8,
+    /// The number of blocks the mosaic is active, 0 for a mosaic that never expires.
+    pub duration: Uint64,
+}
+
+impl MosaicDefinitionTransaction {
+    /// The size of the mosaic definition transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + 8 + 4 + 1 + 1;
+
+    /// The maximum mosaic divisibility allowed by the network.
+    ///
+    pub const MAX_DIVISIBILITY: u8 = 6;
+
+    /// Create a mosaic definition transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `nonce`: The mosaic nonce.
+    /// * `mosaic_id`: The mosaic id, see `MosaicId::create_from_nonce`.
+    /// * `flags`: The mosaic flags.
+    /// * `divisibility`: The mosaic divisibility.
+    /// * `duration`: The mosaic duration in blocks.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicDefinitionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        deadline: Deadline,
+        nonce: MosaicNonce,
+        mosaic_id: MosaicId,
+        flags: MosaicFlags,
+        divisibility: u8,
+        duration: Uint64,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            divisibility <= Self::MAX_DIVISIBILITY,
+            "Invalid mosaic divisibility {}, the maximum is {}.",
+            divisibility,
+            Self::MAX_DIVISIBILITY
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicDefinition,
+            network_type,
+            TransactionVersion::MOSAIC_DEFINITION,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            nonce,
+            mosaic_id,
+            flags,
+            divisibility,
+            duration,
+        })
+    }
+
+    /// Creates a `MosaicDefinitionTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicDefinitionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicDefinition,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicDefinition
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicDefinitionTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid mosaic definition transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mosaic_id = MosaicId::from(cursor.read_u64::<LittleEndian>()?);
+        let duration = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let mut nonce = [0u8; 4];
+        cursor.read_exact(&mut nonce)?;
+        let flags = MosaicFlags::from(cursor.read_u8()?);
+        let divisibility = cursor.read_u8()?;
+
+        Ok(Self {
+            common,
+            nonce: MosaicNonce::from(nonce),
+            mosaic_id,
+            flags,
+            divisibility,
+            duration,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicDefinitionTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.mosaic_id.to_le_bytes());
+        buf.extend_from_slice(&self.duration.to_le_bytes());
+        buf.extend_from_slice(&self.nonce.to_dto().to_le_bytes());
+        buf.push(self.flags.get_value());
+        buf.push(self.divisibility);
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicDefinitionTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::tests::TESTING_ACCOUNT;
+    use crate::mosaic::{MosaicFlags, MosaicId, MosaicNonce};
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicDefinitionTransaction, Transaction, TransactionType,
+    };
+    use crate::{Deadline, Uint64};
+
+    fn mosaic_definition_transaction(flags: MosaicFlags) -> MosaicDefinitionTransaction {
+        let nonce = MosaicNonce::from([0xE6, 0xDE, 0x84, 0xB8]);
+        MosaicDefinitionTransaction::create(
+            Deadline::from(1),
+            nonce,
+            MosaicId::create_from_nonce(nonce, TESTING_ACCOUNT.public_account.address),
+            flags,
+            3,
+            Uint64::from(1000),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_mosaic_definition_transaction() {
+        let transaction = mosaic_definition_transaction(MosaicFlags::create(true, true, false));
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicDefinition
+        );
+        assert_eq!(transaction.size(), 150);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!(
+                "{}E803000000000000E6DE84B80303",
+                hex::encode_upper(transaction.mosaic_id.to_le_bytes())
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_definition_transaction() {
+        let transaction = mosaic_definition_transaction(MosaicFlags::create(true, false, true));
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicDefinitionTransaction>();
+
+        assert_eq!(mapped.nonce, transaction.nonce);
+        assert_eq!(mapped.mosaic_id, transaction.mosaic_id);
+        assert_eq!(mapped.flags, MosaicFlags::create(true, false, true));
+        assert_eq!(mapped.divisibility, 3);
+        assert_eq!(mapped.duration, Uint64::from(1000));
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid mosaic divisibility 7, the maximum is 6.")]
+    fn test_should_panic_if_divisibility_is_too_large() {
+        MosaicDefinitionTransaction::create(
+            Deadline::from(1),
+            MosaicNonce::from(0),
+            MosaicId::from(1),
+            MosaicFlags::create(false, true, false),
+            7,
+            Uint64::from(0),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,364 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Cursor;
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::core::utils::unresolved_mapping::to_unresolved_mosaic;
+use crate::mosaic::{MosaicFlags, MosaicId, UnresolvedMosaicId};
+use crate::network::{MosaicRestrictionNetworkProperties, NetworkType};
+use crate::restriction::MosaicRestrictionType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// Mosaic global restriction transaction struct, sets a restriction rule that accounts
+/// must satisfy to transact a restrictable mosaic.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicGlobalRestrictionTransaction {
+    pub common: CommonTransaction,
+    /// The unresolved mosaic id, a `MosaicId` or a `NamespaceId` alias.
+    pub mosaic_id: Box<dyn UnresolvedMosaicId>,
+    /// The mosaic id providing the restriction values, 0 when the mosaic itself is used.
+    pub reference_mosaic_id: Box<dyn UnresolvedMosaicId>,
+    /// The restriction key, see `generate_uint64_key`.
+    pub restriction_key: Uint64,
+    /// The previous restriction value.
+    pub previous_restriction_value: Uint64,
+    /// The new restriction value.
+    pub new_restriction_value: Uint64,
+    /// The previous restriction type.
+    pub previous_restriction_type: MosaicRestrictionType,
+    /// The new restriction type.
+    pub new_restriction_type: MosaicRestrictionType,
+}
+
+impl MosaicGlobalRestrictionTransaction {
+    /// The size of the mosaic global restriction transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// Create a mosaic global restriction transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `mosaic_id`: The unresolved mosaic id, the mosaic must be restrictable.
+    /// * `restriction_key`: The restriction key.
+    /// * `previous_restriction_value`: The previous restriction value.
+    /// * `previous_restriction_type`: The previous restriction type, `None` for a new restriction.
+    /// * `new_restriction_value`: The new restriction value.
+    /// * `new_restriction_type`: The new restriction type, `None` to remove the restriction.
+    /// * `network_type`: The network type.
+    /// * `reference_mosaic_id`: The mosaic id providing the restriction values,
+    ///   if none is set the mosaic itself is used.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicGlobalRestrictionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn create<M: 'static + UnresolvedMosaicId>(
+        deadline: Deadline,
+        mosaic_id: M,
+        restriction_key: Uint64,
+        previous_restriction_value: Uint64,
+        previous_restriction_type: MosaicRestrictionType,
+        new_restriction_value: Uint64,
+        new_restriction_type: MosaicRestrictionType,
+        network_type: NetworkType,
+        reference_mosaic_id: Option<Box<dyn UnresolvedMosaicId>>,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicGlobalRestriction,
+            network_type,
+            TransactionVersion::MOSAIC_GLOBAL_RESTRICTION,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            mosaic_id: Box::new(mosaic_id),
+            reference_mosaic_id: reference_mosaic_id.unwrap_or_else(|| Box::new(MosaicId::from(0))),
+            restriction_key,
+            previous_restriction_value,
+            new_restriction_value,
+            previous_restriction_type,
+            new_restriction_type,
+        })
+    }
+
+    /// Validates the restriction against the mosaic flags and the network mosaic restriction limits.
+    ///
+    /// # Inputs
+    ///
+    /// * `mosaic_flags`: The flags of the restricted mosaic.
+    /// * `properties`: The network `MosaicRestrictionNetworkProperties`.
+    /// * `current_values`: The number of global restrictions of the mosaic before the transaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(
+        &self,
+        mosaic_flags: &MosaicFlags,
+        properties: &MosaicRestrictionNetworkProperties,
+        current_values: usize,
+    ) -> Result<()> {
+        ensure!(
+            mosaic_flags.restrictable,
+            "Mosaic {} is not restrictable.",
+            self.mosaic_id.to_uint64().to_hex()
+        );
+
+        let is_new_restriction = self.previous_restriction_type == MosaicRestrictionType::None
+            && self.new_restriction_type != MosaicRestrictionType::None;
+        let values = current_values + is_new_restriction as usize;
+        let max_values = properties.get_max_mosaic_restriction_values()?;
+        ensure!(
+            values as u64 <= max_values,
+            "The mosaic would have {} restriction values, the network allows at most {}.",
+            values,
+            max_values
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `MosaicGlobalRestrictionTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicGlobalRestrictionTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicGlobalRestriction,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicGlobalRestriction
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicGlobalRestrictionTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid mosaic global restriction transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let reference_mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let restriction_key = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let previous_restriction_value = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let new_restriction_value = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let previous_restriction_type = MosaicRestrictionType::try_from(cursor.read_u8()?)?;
+        let new_restriction_type = MosaicRestrictionType::try_from(cursor.read_u8()?)?;
+
+        Ok(Self {
+            common,
+            mosaic_id: to_unresolved_mosaic(&mosaic_id.to_hex())?,
+            reference_mosaic_id: to_unresolved_mosaic(&reference_mosaic_id.to_hex())?,
+            restriction_key,
+            previous_restriction_value,
+            new_restriction_value,
+            previous_restriction_type,
+            new_restriction_type,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicGlobalRestrictionTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.mosaic_id.to_uint64().to_le_bytes());
+        buf.extend_from_slice(&self.reference_mosaic_id.to_uint64().to_le_bytes());
+        buf.extend_from_slice(&self.restriction_key.to_le_bytes());
+        buf.extend_from_slice(&self.previous_restriction_value.to_le_bytes());
+        buf.extend_from_slice(&self.new_restriction_value.to_le_bytes());
+        buf.extend_from_slice(&self.previous_restriction_type.to_bytes());
+        buf.extend_from_slice(&self.new_restriction_type.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicGlobalRestrictionTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mosaic::{MosaicFlags, MosaicId};
+    use crate::network::{MosaicRestrictionNetworkProperties, NetworkType};
+    use crate::restriction::MosaicRestrictionType;
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicGlobalRestrictionTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{generate_uint64_key, Deadline, Uint64};
+
+    fn mosaic_global_restriction_transaction(
+        previous_restriction_type: MosaicRestrictionType,
+    ) -> MosaicGlobalRestrictionTransaction {
+        MosaicGlobalRestrictionTransaction::create(
+            Deadline::from(1),
+            MosaicId::from(1),
+            Uint64::from(1),
+            Uint64::from(9),
+            previous_restriction_type,
+            Uint64::from(8),
+            MosaicRestrictionType::Ge,
+            NetworkType::TestNet,
+            Some(Box::new(MosaicId::from(2))),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_mosaic_global_restriction_transaction() {
+        let transaction = mosaic_global_restriction_transaction(MosaicRestrictionType::Eq);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicGlobalRestriction
+        );
+        assert_eq!(transaction.size(), 170);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "0100000000000000020000000000000001000000000000000900000000000000\
+            08000000000000000106"
+        );
+    }
+
+    #[test]
+    fn test_should_use_mosaic_itself_as_default_reference() {
+        let transaction = MosaicGlobalRestrictionTransaction::create(
+            Deadline::from(1),
+            MosaicId::from(1),
+            generate_uint64_key("KYC").unwrap(),
+            Uint64::from(0),
+            MosaicRestrictionType::None,
+            Uint64::from(1),
+            MosaicRestrictionType::Eq,
+            NetworkType::TestNet,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(*transaction.reference_mosaic_id.to_uint64(), 0);
+        assert_eq!(
+            &transaction.serialize()[256..304],
+            "0100000000000000000000000000000077DD7B907C631281"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_global_restriction_transaction() {
+        let transaction = mosaic_global_restriction_transaction(MosaicRestrictionType::Eq);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicGlobalRestrictionTransaction>();
+
+        assert_eq!(*mapped.reference_mosaic_id.to_uint64(), 2);
+        assert_eq!(mapped.previous_restriction_type, MosaicRestrictionType::Eq);
+        assert_eq!(mapped.new_restriction_type, MosaicRestrictionType::Ge);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_mosaic_flags_and_network_properties() {
+        let properties = MosaicRestrictionNetworkProperties {
+            max_mosaic_restriction_values: Some("20".to_owned()),
+        };
+        let restrictable = MosaicFlags::create(false, true, true);
+
+        let transaction = mosaic_global_restriction_transaction(MosaicRestrictionType::None);
+        assert!(transaction.validate(&restrictable, &properties, 19).is_ok());
+        assert_eq!(
+            transaction
+                .validate(&restrictable, &properties, 20)
+                .unwrap_err()
+                .to_string(),
+            "The mosaic would have 21 restriction values, the network allows at most 20."
+        );
+
+        let transaction = mosaic_global_restriction_transaction(MosaicRestrictionType::Eq);
+        assert!(transaction.validate(&restrictable, &properties, 20).is_ok());
+        assert_eq!(
+            transaction
+                .validate(&MosaicFlags::create(false, true, false), &properties, 0)
+                .unwrap_err()
+                .to_string(),
+            "Mosaic 0000000000000001 is not restrictable."
+        );
+    }
+}
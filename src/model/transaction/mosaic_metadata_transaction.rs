@@ -0,0 +1,335 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::metadata::validate_metadata_value_size;
+use crate::mosaic::UnresolvedMosaicId;
+use crate::network::{MetadataNetworkProperties, NetworkType};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{der_bytes_from_hex, hex_decode, is_hex, ser_to_hex_upper, Deadline, Uint64};
+
+/// Mosaic metadata transaction struct, associates a key-value state to a mosaic.
+///
+/// To update an existing value announce the XOR of the old and the new value,
+/// see `metadata_value_delta` and `metadata_value_size_delta`.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicMetadataTransaction {
+    pub common: CommonTransaction,
+    /// The address of the metadata target account.
+    pub target_address: Box<dyn UnresolvedAddress>,
+    /// The metadata key scoped to the source, target and type, see `scoped_metadata_key`.
+    pub scoped_metadata_key: Uint64,
+    /// The identifier of the metadata target mosaic.
+    pub target_mosaic_id: Box<dyn UnresolvedMosaicId>,
+    /// The change in value size in bytes.
+    pub value_size_delta: i16,
+    /// The value, or the XOR of the old and the new value when it is updated.
+    #[serde(
+        serialize_with = "ser_to_hex_upper",
+        deserialize_with = "der_bytes_from_hex"
+    )]
+    pub value: Vec<u8>,
+}
+
+impl MosaicMetadataTransaction {
+    /// The size of the unresolved target address in bytes.
+    ///
+    const ADDRESS_SIZE: usize = 24;
+
+    /// The size of the fixed part of the mosaic metadata transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = Self::ADDRESS_SIZE + 8 + 8 + 2 + 2;
+
+    /// Create a mosaic metadata transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `target_address`: The address of the metadata target account.
+    /// * `scoped_metadata_key`: The metadata key.
+    /// * `target_mosaic_id`: The identifier of the metadata target mosaic.
+    /// * `value_size_delta`: The change in value size in bytes.
+    /// * `value`: The value, or the XOR value delta when it is updated.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `MosaicMetadataTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn create<R: 'static + UnresolvedAddress, M: 'static + UnresolvedMosaicId>(
+        deadline: Deadline,
+        target_address: R,
+        scoped_metadata_key: Uint64,
+        target_mosaic_id: M,
+        value_size_delta: i16,
+        value: Vec<u8>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            value.len() <= u16::MAX as usize,
+            "Metadata value size {} exceeds the maximum of {} bytes.",
+            value.len(),
+            u16::MAX
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicMetadata,
+            network_type,
+            TransactionVersion::MOSAIC_METADATA,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            target_address: Box::new(target_address),
+            scoped_metadata_key,
+            target_mosaic_id: Box::new(target_mosaic_id),
+            value_size_delta,
+            value,
+        })
+    }
+
+    /// The String notation for the set target address.
+    pub fn target_address_to_string(&self) -> String {
+        self.target_address.recipient_to_string()
+    }
+
+    /// Validates the value size against the network metadata properties.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `MetadataNetworkProperties`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(&self, properties: &MetadataNetworkProperties) -> Result<()> {
+        validate_metadata_value_size(properties, self.value.len())
+    }
+
+    /// Creates a `MosaicMetadataTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `MosaicMetadataTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicMetadata,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicMetadata
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicMetadataTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid mosaic metadata transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut target_address = [0u8; Self::ADDRESS_SIZE];
+        cursor.read_exact(&mut target_address)?;
+        let scoped_metadata_key = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let target_mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let value_size_delta = cursor.read_i16::<LittleEndian>()?;
+        let value_size = cursor.read_u16::<LittleEndian>()? as usize;
+
+        ensure!(
+            body.len() == Self::BODY_FIXED_SIZE + value_size,
+            "Invalid mosaic metadata transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE + value_size
+        );
+        let mut value = vec![0u8; value_size];
+        cursor.read_exact(&mut value)?;
+
+        Ok(Self {
+            common,
+            target_address: to_unresolved_address(&hex::encode(target_address))?,
+            scoped_metadata_key,
+            target_mosaic_id: to_unresolved_mosaic(&target_mosaic_id.to_hex())?,
+            value_size_delta,
+            value,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicMetadataTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_FIXED_SIZE + self.value.len());
+        buf.extend(
+            self.target_address
+                .unresolved_address_to_bytes(self.common.network_type),
+        );
+        buf.extend_from_slice(&self.scoped_metadata_key.to_le_bytes());
+        buf.extend_from_slice(&self.target_mosaic_id.to_uint64().to_le_bytes());
+        buf.extend_from_slice(&self.value_size_delta.to_le_bytes());
+        buf.extend_from_slice(&(self.value.len() as u16).to_le_bytes());
+        buf.extend_from_slice(&self.value);
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicMetadataTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::metadata::{metadata_value_delta, metadata_value_size_delta, scoped_metadata_key};
+    use crate::mosaic::MosaicId;
+    use crate::network::{MetadataNetworkProperties, NetworkType};
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicMetadataTransaction, Transaction, TransactionType,
+    };
+    use crate::Deadline;
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    fn mosaic_metadata_transaction(
+        old_value: &[u8],
+        new_value: &[u8],
+    ) -> MosaicMetadataTransaction {
+        MosaicMetadataTransaction::create(
+            Deadline::from(1),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            scoped_metadata_key("KYC").unwrap(),
+            MosaicId::from(0x091F837E059AE13C),
+            metadata_value_size_delta(old_value, new_value).unwrap(),
+            metadata_value_delta(old_value, new_value),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_mosaic_metadata_transaction() {
+        let transaction = mosaic_metadata_transaction(b"", b"value");
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicMetadata
+        );
+        assert_eq!(transaction.target_address_to_string(), RAW_ADDRESS);
+        assert_eq!(transaction.size(), 128 + 44 + 5);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!(
+                "9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776\
+                77DD7B907C6312813CE19A057E831F0905000500{}",
+                hex::encode_upper("value")
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_metadata_transaction() {
+        let transaction = mosaic_metadata_transaction(b"old value", b"new");
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicMetadataTransaction>();
+
+        assert_eq!(mapped.value_size_delta, -6);
+        assert_eq!(mapped.value, metadata_value_delta(b"old value", b"new"));
+        assert_eq!(mapped.target_address_to_string(), RAW_ADDRESS);
+        assert_eq!(
+            mapped.target_mosaic_id.to_uint64().to_hex(),
+            "091F837E059AE13C"
+        );
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_network_properties() {
+        let properties = MetadataNetworkProperties {
+            max_value_size: Some("5".to_owned()),
+        };
+
+        assert!(mosaic_metadata_transaction(b"", b"value")
+            .validate(&properties)
+            .is_ok());
+        assert_eq!(
+            mosaic_metadata_transaction(b"", b"values")
+                .validate(&properties)
+                .unwrap_err()
+                .to_string(),
+            "Invalid metadata value size 6, the network allows at most 5 bytes."
+        );
+    }
+}
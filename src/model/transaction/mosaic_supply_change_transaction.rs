@@ -0,0 +1,239 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Cursor;
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::core::utils::unresolved_mapping::to_unresolved_mosaic;
+use crate::mosaic::{MosaicSupplyChangeAction, UnresolvedMosaicId};
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// Mosaic supply change transaction struct, increases or decreases the supply of a mosaic.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicSupplyChangeTransaction {
+    pub common: CommonTransaction,
+    /// The unresolved mosaic id, a `MosaicId` or a `NamespaceId` alias.
+    pub mosaic_id: Box<dyn UnresolvedMosaicId>,
+    /// The supply change action.
+    pub action: MosaicSupplyChangeAction,
+    /// The supply change amount in absolute units.
+    pub delta: Uint64,
+}
+
+impl MosaicSupplyChangeTransaction {
+    /// The size of the mosaic supply change transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = 8 + 8 + 1;
+
+    /// Create a mosaic supply change transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `mosaic_id`: The unresolved mosaic id.
+    /// * `action`: The supply change action.
+    /// * `delta`: The supply change amount in absolute units.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicSupplyChangeTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create<M: 'static + UnresolvedMosaicId>(
+        deadline: Deadline,
+        mosaic_id: M,
+        action: MosaicSupplyChangeAction,
+        delta: Uint64,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MosaicSupplyChange,
+            network_type,
+            TransactionVersion::MOSAIC_SUPPLY_CHANGE,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            mosaic_id: Box::new(mosaic_id),
+            action,
+            delta,
+        })
+    }
+
+    /// Creates a `MosaicSupplyChangeTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MosaicSupplyChangeTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MosaicSupplyChange,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MosaicSupplyChange
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MosaicSupplyChangeTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid mosaic supply change transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let delta = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let action = MosaicSupplyChangeAction::try_from(cursor.read_u8()?)?;
+
+        Ok(Self {
+            common,
+            mosaic_id: to_unresolved_mosaic(&mosaic_id.to_hex())?,
+            action,
+            delta,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MosaicSupplyChangeTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(&self.mosaic_id.to_uint64().to_le_bytes());
+        buf.extend_from_slice(&self.delta.to_le_bytes());
+        buf.extend_from_slice(&self.action.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MosaicSupplyChangeTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mosaic::{MosaicId, MosaicSupplyChangeAction};
+    use crate::namespace::NamespaceId;
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, MosaicSupplyChangeTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{Deadline, Uint64};
+
+    #[test]
+    fn test_should_create_mosaic_supply_change_transaction() {
+        let transaction = MosaicSupplyChangeTransaction::create(
+            Deadline::from(1),
+            MosaicId::from([2262289484, 3405110546]),
+            MosaicSupplyChangeAction::Increase,
+            Uint64::from(10),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MosaicSupplyChange
+        );
+        assert_eq!(transaction.size(), 145);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "4CCCD78612DDF5CA0A0000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_mosaic_supply_change_transaction() {
+        let transaction = MosaicSupplyChangeTransaction::create(
+            Deadline::from(1),
+            NamespaceId::create_from_name("loyalty").unwrap(),
+            MosaicSupplyChangeAction::Decrease,
+            Uint64::from(500),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MosaicSupplyChangeTransaction>();
+
+        assert!(mapped.mosaic_id.as_any().is::<NamespaceId>());
+        assert_eq!(
+            mapped.mosaic_id.to_uint64(),
+            transaction.mosaic_id.to_uint64()
+        );
+        assert_eq!(mapped.action, MosaicSupplyChangeAction::Decrease);
+        assert_eq!(mapped.delta, Uint64::from(500));
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+}
@@ -0,0 +1,449 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::ReadBytesExt;
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::to_unresolved_address;
+use crate::network::{MultisigNetworkProperties, NetworkType};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline};
+
+/// Modify multisig account transaction struct, converts an account into a multisig account
+/// or changes its cosignatories and minimum approval and removal values.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigAccountModificationTransaction {
+    pub common: CommonTransaction,
+    /// The number of signatures needed to approve a transaction.
+    /// If we are modifying an existing multisig account, this indicates the relative change
+    /// of the minimum cosignatories.
+    pub min_approval_delta: i8,
+    /// The number of signatures needed to remove a cosignatory.
+    /// If we are modifying an existing multisig account, this indicates the relative change
+    /// of the minimum cosignatories.
+    pub min_removal_delta: i8,
+    /// The cosignatory address additions.
+    pub address_additions: Vec<Box<dyn UnresolvedAddress>>,
+    /// The cosignatory address deletions.
+    pub address_deletions: Vec<Box<dyn UnresolvedAddress>>,
+}
+
+impl MultisigAccountModificationTransaction {
+    /// The size of the fixed part of the multisig account modification transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = 1 + 1 + 1 + 1 + 4;
+
+    /// The size of an unresolved address in bytes.
+    ///
+    const ADDRESS_SIZE: usize = 24;
+
+    /// Create a multisig account modification transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `min_approval_delta`: The min approval relative change.
+    /// * `min_removal_delta`: The min removal relative change.
+    /// * `address_additions`: The cosignatory address additions.
+    /// * `address_deletions`: The cosignatory address deletions.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MultisigAccountModificationTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        deadline: Deadline,
+        min_approval_delta: i8,
+        min_removal_delta: i8,
+        address_additions: Vec<Box<dyn UnresolvedAddress>>,
+        address_deletions: Vec<Box<dyn UnresolvedAddress>>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            address_additions.len() <= u8::MAX as usize
+                && address_deletions.len() <= u8::MAX as usize,
+            "Too many cosignatory modifications, at most {} additions and deletions are allowed.",
+            u8::MAX
+        );
+
+        let mut addresses = HashSet::new();
+        for address in address_additions.iter().chain(address_deletions.iter()) {
+            ensure!(
+                addresses.insert(address.unresolved_address_to_bytes(network_type)),
+                "Cosignatory {} is modified more than once.",
+                address.recipient_to_string()
+            );
+        }
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::MultisigAccountModify,
+            network_type,
+            TransactionVersion::MULTISIG_ACCOUNT_MODIFICATION,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            min_approval_delta,
+            min_removal_delta,
+            address_additions,
+            address_deletions,
+        })
+    }
+
+    /// Validates the modification against the network multisig limits.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `MultisigNetworkProperties`.
+    /// * `current_cosignatories`: The number of cosignatories of the account before the modification,
+    /// 0 when the account is converted into a multisig account.
+    /// * `current_min_approval`: The min approval of the account before the modification.
+    /// * `current_min_removal`: The min removal of the account before the modification.
+    /// * `multisig_depth`: The caller computed number of multisig levels after the modification.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(
+        &self,
+        properties: &MultisigNetworkProperties,
+        current_cosignatories: usize,
+        current_min_approval: u8,
+        current_min_removal: u8,
+        multisig_depth: usize,
+    ) -> Result<()> {
+        ensure!(
+            self.address_deletions.len() <= current_cosignatories,
+            "Cannot remove {} cosignatories, the account has {}.",
+            self.address_deletions.len(),
+            current_cosignatories
+        );
+
+        let cosignatories =
+            current_cosignatories + self.address_additions.len() - self.address_deletions.len();
+        let max_cosignatories = properties.get_max_cosignatories_per_account()?;
+        ensure!(
+            cosignatories as u64 <= max_cosignatories,
+            "The account would have {} cosignatories, the network allows at most {}.",
+            cosignatories,
+            max_cosignatories
+        );
+
+        // an account without cosignatories is no longer multisig and has no min settings.
+        if cosignatories > 0 {
+            for (setting, current, delta) in [
+                ("approval", current_min_approval, self.min_approval_delta),
+                ("removal", current_min_removal, self.min_removal_delta),
+            ] {
+                let value = i16::from(current) + i16::from(delta);
+                ensure!(
+                    value >= 1 && value as usize <= cosignatories,
+                    "The min {} would be {}, expected a value between 1 and {}.",
+                    setting,
+                    value,
+                    cosignatories
+                );
+            }
+        }
+
+        let max_multisig_depth = properties.get_max_multisig_depth()?;
+        ensure!(
+            multisig_depth as u64 <= max_multisig_depth,
+            "The multisig account graph would have {} levels, the network allows at most {}.",
+            multisig_depth,
+            max_multisig_depth
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `MultisigAccountModificationTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `MultisigAccountModificationTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::MultisigAccountModify,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::MultisigAccountModify
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `MultisigAccountModificationTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid multisig account modification transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let min_removal_delta = cursor.read_i8()?;
+        let min_approval_delta = cursor.read_i8()?;
+        let additions_count = cursor.read_u8()? as usize;
+        let deletions_count = cursor.read_u8()? as usize;
+        cursor.set_position(Self::BODY_FIXED_SIZE as u64);
+
+        let expected_size =
+            Self::BODY_FIXED_SIZE + (additions_count + deletions_count) * Self::ADDRESS_SIZE;
+        ensure!(
+            body.len() == expected_size,
+            "Invalid multisig account modification transaction body size {}, expected {} bytes.",
+            body.len(),
+            expected_size
+        );
+
+        let mut read_addresses = |count: usize| -> Result<Vec<Box<dyn UnresolvedAddress>>> {
+            let mut addresses = Vec::with_capacity(count);
+            for _ in 0..count {
+                let mut address = [0u8; Self::ADDRESS_SIZE];
+                cursor.read_exact(&mut address)?;
+                addresses.push(to_unresolved_address(&hex::encode(address))?);
+            }
+            Ok(addresses)
+        };
+        let address_additions = read_addresses(additions_count)?;
+        let address_deletions = read_addresses(deletions_count)?;
+
+        Ok(Self {
+            common,
+            min_approval_delta,
+            min_removal_delta,
+            address_additions,
+            address_deletions,
+        })
+    }
+
+    fn addresses_to_bytes(&self, addresses: &[Box<dyn UnresolvedAddress>]) -> Vec<u8> {
+        addresses
+            .iter()
+            .flat_map(|address| address.unresolved_address_to_bytes(self.common.network_type))
+            .collect()
+    }
+}
+
+#[typetag::serde]
+impl Transaction for MultisigAccountModificationTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            Self::BODY_FIXED_SIZE
+                + (self.address_additions.len() + self.address_deletions.len())
+                    * Self::ADDRESS_SIZE,
+        );
+        buf.extend_from_slice(&self.min_removal_delta.to_le_bytes());
+        buf.extend_from_slice(&self.min_approval_delta.to_le_bytes());
+        buf.push(self.address_additions.len() as u8);
+        buf.push(self.address_deletions.len() as u8);
+        // multisig_account_modification_transaction_body_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        buf.extend(self.addresses_to_bytes(&self.address_additions));
+        buf.extend(self.addresses_to_bytes(&self.address_deletions));
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for MultisigAccountModificationTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::tests::{COSIGNATORY2_ACCOUNT, COSIGNATORY_ACCOUNT};
+    use crate::account::{Address, UnresolvedAddress};
+    use crate::network::{MultisigNetworkProperties, NetworkType};
+    use crate::transaction::{
+        create_transaction_from_payload, MultisigAccountModificationTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::Deadline;
+
+    fn address(raw_address: &str) -> Box<dyn UnresolvedAddress> {
+        Box::new(Address::from_raw(raw_address).unwrap())
+    }
+
+    fn multisig_account_modification_transaction() -> MultisigAccountModificationTransaction {
+        MultisigAccountModificationTransaction::create(
+            Deadline::from(1),
+            2,
+            1,
+            vec![
+                Box::new(COSIGNATORY_ACCOUNT.public_account.address),
+                Box::new(COSIGNATORY2_ACCOUNT.public_account.address),
+            ],
+            vec![address("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ")],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn multisig_network_properties() -> MultisigNetworkProperties {
+        MultisigNetworkProperties {
+            max_multisig_depth: Some("3".to_owned()),
+            max_cosignatories_per_account: Some("2".to_owned()),
+            max_cosigned_accounts_per_account: Some("25".to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_should_create_multisig_account_modification_transaction() {
+        let transaction = multisig_account_modification_transaction();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::MultisigAccountModify
+        );
+        assert_eq!(transaction.size(), 128 + 8 + 3 * 24);
+        assert_eq!(&transaction.serialize()[256..272], "0102020100000000");
+        assert_eq!(
+            &transaction.serialize()[368..],
+            "A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C536"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_multisig_account_modification_transaction() {
+        let transaction = multisig_account_modification_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<MultisigAccountModificationTransaction>();
+
+        assert_eq!(mapped.min_approval_delta, 2);
+        assert_eq!(mapped.min_removal_delta, 1);
+        assert_eq!(mapped.address_additions.len(), 2);
+        assert_eq!(
+            mapped.address_additions[1].recipient_to_string(),
+            COSIGNATORY2_ACCOUNT.public_account.address.address_str()
+        );
+        assert_eq!(
+            mapped.address_deletions[0].recipient_to_string(),
+            "VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ"
+        );
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_network_properties() {
+        let transaction = multisig_account_modification_transaction();
+        let properties = multisig_network_properties();
+
+        assert!(transaction.validate(&properties, 1, 0, 1, 1).is_ok());
+        assert!(transaction.validate(&properties, 1, 0, 1, 4).is_err());
+        assert_eq!(
+            transaction
+                .validate(&properties, 2, 0, 1, 1)
+                .unwrap_err()
+                .to_string(),
+            "The account would have 3 cosignatories, the network allows at most 2."
+        );
+    }
+
+    #[test]
+    fn test_should_validate_min_settings_against_cosignatories() {
+        let transaction = multisig_account_modification_transaction();
+        let properties = multisig_network_properties();
+
+        assert_eq!(
+            transaction
+                .validate(&properties, 1, 1, 1, 1)
+                .unwrap_err()
+                .to_string(),
+            "The min approval would be 3, expected a value between 1 and 2."
+        );
+        assert_eq!(
+            transaction
+                .validate(&properties, 1, 0, 2, 1)
+                .unwrap_err()
+                .to_string(),
+            "The min removal would be 3, expected a value between 1 and 2."
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cosignatory VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ is modified more than once."
+    )]
+    fn test_should_panic_if_cosignatory_is_added_and_removed() {
+        MultisigAccountModificationTransaction::create(
+            Deadline::from(1),
+            1,
+            1,
+            vec![address("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ")],
+            vec![address("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ")],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,394 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::namespace::{
+    generate_namespace_id, validate_namespace_name, NamespaceId, NamespaceRegistrationType,
+};
+use crate::network::NetworkType;
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64};
+
+/// Register a namespace transaction struct.
+///
+/// A root namespace is rented for a `duration` in blocks,
+/// a child namespace inherits the duration of its `parent_id` root namespace.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceRegistrationTransaction {
+    pub common: CommonTransaction,
+    /// The namespace type could be root or sub namespace.
+    pub registration_type: NamespaceRegistrationType,
+    /// The namespace name, a single namespace part.
+    pub namespace_name: String,
+    /// The id of the namespace derived from namespaceName.
+    /// When creating a sub namespace the namespaceId is derived from namespaceName and parentId.
+    pub id: NamespaceId,
+    /// The number of blocks a root namespace is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Uint64>,
+    /// The id of the parent namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<NamespaceId>,
+}
+
+impl NamespaceRegistrationTransaction {
+    /// The size of the fixed part of the namespace registration transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = 8 + 8 + 1 + 1;
+
+    /// Create a root namespace registration transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `namespace_name`: The root namespace name.
+    /// * `duration`: The duration of the namespace in blocks.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `NamespaceRegistrationTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create_root_namespace(
+        deadline: Deadline,
+        namespace_name: &str,
+        duration: Uint64,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        validate_namespace_name(namespace_name)?;
+
+        let id = NamespaceId::from(generate_namespace_id(Uint64::default(), namespace_name)?);
+
+        Ok(Self {
+            common: Self::create_common(deadline, network_type, max_fee),
+            registration_type: NamespaceRegistrationType::RootNamespace,
+            namespace_name: namespace_name.to_owned(),
+            id,
+            duration: Some(duration),
+            parent_id: None,
+        })
+    }
+
+    /// Create a child namespace registration transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `namespace_name`: The child namespace name, without the parent namespace path.
+    /// * `parent_id`: The parent namespace id.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `NamespaceRegistrationTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create_sub_namespace(
+        deadline: Deadline,
+        namespace_name: &str,
+        parent_id: NamespaceId,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        validate_namespace_name(namespace_name)?;
+
+        let id = NamespaceId::from(generate_namespace_id(parent_id.id, namespace_name)?);
+
+        Ok(Self {
+            common: Self::create_common(deadline, network_type, max_fee),
+            registration_type: NamespaceRegistrationType::SubNamespace,
+            namespace_name: namespace_name.to_owned(),
+            id,
+            duration: None,
+            parent_id: Some(parent_id),
+        })
+    }
+
+    /// Creates a `NamespaceRegistrationTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `NamespaceRegistrationTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::RegisterNamespace,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::RegisterNamespace
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `NamespaceRegistrationTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid namespace registration transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let duration_or_parent_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let id = NamespaceId::from(cursor.read_u64::<LittleEndian>()?);
+        let registration_type = NamespaceRegistrationType::try_from(cursor.read_u8()?)?;
+        let name_size = cursor.read_u8()? as usize;
+        ensure!(
+            body.len() == Self::BODY_FIXED_SIZE + name_size,
+            "Invalid namespace registration transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE + name_size
+        );
+
+        let mut name = vec![0u8; name_size];
+        cursor.read_exact(&mut name)?;
+        let namespace_name = String::from_utf8(name)?;
+
+        let (duration, parent_id) = match registration_type {
+            NamespaceRegistrationType::RootNamespace => (Some(duration_or_parent_id), None),
+            NamespaceRegistrationType::SubNamespace => {
+                (None, Some(NamespaceId::from(duration_or_parent_id)))
+            }
+        };
+
+        Ok(Self {
+            common,
+            registration_type,
+            namespace_name,
+            id,
+            duration,
+            parent_id,
+        })
+    }
+
+    fn create_common(
+        deadline: Deadline,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> CommonTransaction {
+        CommonTransaction::create_from_type(
+            TransactionType::RegisterNamespace,
+            network_type,
+            TransactionVersion::NAMESPACE_REGISTRATION,
+            deadline,
+            max_fee.unwrap_or_default(),
+        )
+    }
+}
+
+#[typetag::serde]
+impl Transaction for NamespaceRegistrationTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let name = self.namespace_name.as_bytes();
+
+        let duration_or_parent_id = match self.registration_type {
+            NamespaceRegistrationType::RootNamespace => self.duration.unwrap_or_default(),
+            NamespaceRegistrationType::SubNamespace => {
+                self.parent_id.as_ref().map(|id| id.id).unwrap_or_default()
+            }
+        };
+
+        let mut buf = Vec::with_capacity(Self::BODY_FIXED_SIZE + name.len());
+        buf.extend_from_slice(&duration_or_parent_id.to_le_bytes());
+        buf.extend_from_slice(&self.id.to_le_bytes());
+        buf.extend_from_slice(&self.registration_type.to_bytes());
+        buf.push(name.len() as u8);
+        buf.extend_from_slice(name);
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for NamespaceRegistrationTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::tests::TESTING_ACCOUNT;
+    use crate::namespace::{NamespaceId, NamespaceRegistrationType};
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_payload, NamespaceRegistrationTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{Deadline, Uint64};
+
+    #[test]
+    fn test_should_create_root_namespace_registration_transaction() {
+        let transaction = NamespaceRegistrationTransaction::create_root_namespace(
+            Deadline::from(1),
+            "root-test-namespace",
+            Uint64::from(1000),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::RegisterNamespace
+        );
+        assert_eq!(
+            transaction.registration_type,
+            NamespaceRegistrationType::RootNamespace
+        );
+        assert_eq!(transaction.duration, Some(Uint64::from(1000)));
+        assert_eq!(transaction.parent_id, None);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "E803000000000000CFCBE72D994BE69B0013726F6F742D746573742D6E616D657370616365"
+        );
+    }
+
+    #[test]
+    fn test_should_create_sub_namespace_registration_transaction() {
+        let transaction = NamespaceRegistrationTransaction::create_sub_namespace(
+            Deadline::from(1),
+            "root-test-namespace",
+            NamespaceId::create_from_name("parent-test-namespace").unwrap(),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction.registration_type,
+            NamespaceRegistrationType::SubNamespace
+        );
+        assert_eq!(transaction.duration, None);
+        assert_eq!(
+            transaction.id.id,
+            NamespaceId::create_from_name("parent-test-namespace.root-test-namespace")
+                .unwrap()
+                .id
+        );
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "4DF55E7F6D8FB7FF924207DF2CA1BBF30113726F6F742D746573742D6E616D657370616365"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_namespace_registration_transactions() {
+        let root = NamespaceRegistrationTransaction::create_root_namespace(
+            Deadline::from(1),
+            "root-test-namespace",
+            Uint64::from(1000),
+            NetworkType::PrivateTest,
+            Some(100),
+        )
+        .unwrap();
+        let sub = NamespaceRegistrationTransaction::create_sub_namespace(
+            Deadline::from(1),
+            "sub",
+            root.id.clone(),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        let deserialized =
+            NamespaceRegistrationTransaction::from_payload(&root.serialize()).unwrap();
+        assert_eq!(deserialized.namespace_name, "root-test-namespace");
+        assert_eq!(deserialized.duration, Some(Uint64::from(1000)));
+        assert_eq!(deserialized.serialize(), root.serialize());
+
+        let embedded = sub.to_aggregate(TESTING_ACCOUNT.public_account);
+        let mapped = create_transaction_from_payload(&sub.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<NamespaceRegistrationTransaction>();
+        assert_eq!(mapped.parent_id, Some(root.id));
+        assert_eq!(mapped.id, sub.id);
+        assert_eq!(embedded.embedded_size(), 48 + 18 + 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid part name root.sub")]
+    fn test_should_panic_if_namespace_name_contains_a_dot() {
+        NamespaceRegistrationTransaction::create_root_namespace(
+            Deadline::from(1),
+            "root.sub",
+            Uint64::from(1000),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid namespace name size 65, expected between 1 and 64 characters."
+    )]
+    fn test_should_panic_if_namespace_name_is_too_long() {
+        NamespaceRegistrationTransaction::create_root_namespace(
+            Deadline::from(1),
+            &"a".repeat(65),
+            Uint64::from(1000),
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+    }
+}
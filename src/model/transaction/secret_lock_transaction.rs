@@ -0,0 +1,363 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::time::Duration;
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::mosaic::Mosaic;
+use crate::network::{NetworkType, SecretLockNetworkProperties};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{LockHashAlgorithm, Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline, Uint64, H256};
+
+/// Secret lock transaction struct, locks funds until a proof for the secret is announced.
+///
+/// The funds are sent to the recipient when a `SecretProofTransaction` with the matching
+/// proof is confirmed, or returned to the signer once the duration expires.
+/// Use it to perform cross-chain atomic swaps.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretLockTransaction {
+    pub common: CommonTransaction,
+    /// The locked mosaic.
+    pub mosaic: Mosaic,
+    /// The number of blocks the funds are locked.
+    pub duration: Uint64,
+    /// The algorithm used to hash the proof.
+    pub hash_algorithm: LockHashAlgorithm,
+    /// The proof hashed.
+    pub secret: H256,
+    /// The address that receives the funds once unlocked.
+    pub recipient: Box<dyn UnresolvedAddress>,
+}
+
+impl SecretLockTransaction {
+    /// The size of the unresolved recipient address in bytes.
+    ///
+    const RECIPIENT_SIZE: usize = 24;
+
+    /// The size of the secret lock transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = Self::RECIPIENT_SIZE + 32 + 16 + 8 + 1;
+
+    /// Create a secret lock transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `mosaic`: The locked mosaic.
+    /// * `duration`: The number of blocks the funds are locked.
+    /// * `hash_algorithm`: The algorithm used to hash the proof.
+    /// * `secret`: The proof hashed, see `LockHashAlgorithm::create_secret`.
+    /// * `recipient`: The address that receives the funds once unlocked.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SecretLockTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn create<R: 'static + UnresolvedAddress>(
+        deadline: Deadline,
+        mosaic: Mosaic,
+        duration: Uint64,
+        hash_algorithm: LockHashAlgorithm,
+        secret: H256,
+        recipient: R,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        let common = CommonTransaction::create_from_type(
+            TransactionType::SecretLock,
+            network_type,
+            TransactionVersion::SECRET_LOCK,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            mosaic,
+            duration,
+            hash_algorithm,
+            secret,
+            recipient: Box::new(recipient),
+        })
+    }
+
+    /// The String notation for the set recipient.
+    pub fn recipient_to_string(&self) -> String {
+        self.recipient.recipient_to_string()
+    }
+
+    /// Validates the lock against the network secret lock properties.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `SecretLockNetworkProperties`.
+    /// * `block_generation_target_time`: The targeted time between blocks,
+    ///   see `ChainProperties::get_block_generation_target_time`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(
+        &self,
+        properties: &SecretLockNetworkProperties,
+        block_generation_target_time: Duration,
+    ) -> Result<()> {
+        let max_duration = properties.get_max_secret_lock_duration()?;
+        let max_blocks = max_duration.as_millis() / block_generation_target_time.as_millis().max(1);
+        ensure!(
+            *self.duration > 0 && u128::from(*self.duration) <= max_blocks,
+            "Invalid secret lock duration {}, expected between 1 and {} blocks.",
+            self.duration,
+            max_blocks
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `SecretLockTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SecretLockTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::SecretLock,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::SecretLock
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `SecretLockTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid secret lock transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut recipient = [0u8; Self::RECIPIENT_SIZE];
+        cursor.read_exact(&mut recipient)?;
+        let mut secret = [0u8; 32];
+        cursor.read_exact(&mut secret)?;
+        let mosaic_id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let amount = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let duration = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+        let hash_algorithm = LockHashAlgorithm::try_from(cursor.read_u8()?)?;
+
+        Ok(Self {
+            common,
+            mosaic: Mosaic {
+                id: to_unresolved_mosaic(&mosaic_id.to_hex())?,
+                amount,
+            },
+            duration,
+            hash_algorithm,
+            secret: H256::from(secret),
+            recipient: to_unresolved_address(&hex::encode(recipient))?,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for SecretLockTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend(
+            self.recipient
+                .unresolved_address_to_bytes(self.common.network_type),
+        );
+        buf.extend_from_slice(self.secret.as_bytes());
+        buf.extend(self.mosaic.to_vec());
+        buf.extend_from_slice(&self.duration.to_le_bytes());
+        buf.extend_from_slice(&self.hash_algorithm.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for SecretLockTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::account::Address;
+    use crate::mosaic::{Mosaic, MosaicId};
+    use crate::namespace::NamespaceId;
+    use crate::network::{NetworkType, SecretLockNetworkProperties};
+    use crate::transaction::{
+        create_transaction_from_payload, LockHashAlgorithm, SecretLockTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{Deadline, Uint64};
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    const PROOF: &str = "B778A39A3663719DFC5E48C9D78431B1E45C2AF9DF538782BF199C189DABEAC7";
+
+    fn secret_lock_transaction(duration: u64) -> SecretLockTransaction {
+        let algorithm = LockHashAlgorithm::Sha3_256;
+        SecretLockTransaction::create(
+            Deadline::from(1),
+            Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), 10).unwrap(),
+            Uint64::from(duration),
+            algorithm,
+            algorithm.create_secret(&hex::decode(PROOF).unwrap()),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_secret_lock_transaction() {
+        let transaction = secret_lock_transaction(100);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::SecretLock
+        );
+        assert_eq!(transaction.recipient_to_string(), RAW_ADDRESS);
+        assert_eq!(transaction.size(), 209);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            "9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776\
+            9B3155B37159DA50AA52D5967C509B410F5A36A3B1E31ECB5AC76675D79B4A5E\
+            3CE19A057E831F090A000000000000006400000000000000\
+            00"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_secret_lock_transaction() {
+        let transaction = secret_lock_transaction(100);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<SecretLockTransaction>();
+
+        assert_eq!(mapped.secret, transaction.secret);
+        assert_eq!(mapped.hash_algorithm, LockHashAlgorithm::Sha3_256);
+        assert_eq!(mapped.recipient_to_string(), RAW_ADDRESS);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_serialize_namespace_id_as_recipient() {
+        let algorithm = LockHashAlgorithm::Hash_160;
+        let transaction = SecretLockTransaction::create(
+            Deadline::from(1),
+            Mosaic::create(MosaicId::from_hex("091F837E059AE13C").unwrap(), 10).unwrap(),
+            Uint64::from(100),
+            algorithm,
+            algorithm.create_secret(&hex::decode(PROOF).unwrap()),
+            NamespaceId::create_from_name("nem").unwrap(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            &transaction.serialize()[256..304],
+            "994BFA5F372D55B384000000000000000000000000000000"
+        );
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<SecretLockTransaction>();
+        assert_eq!(mapped.recipient_to_string(), "84B3552D375FFA4B");
+        assert_eq!(mapped.hash_algorithm, LockHashAlgorithm::Hash_160);
+    }
+
+    #[test]
+    fn test_should_validate_against_network_properties() {
+        let properties = SecretLockNetworkProperties {
+            max_secret_lock_duration: Some("30d".to_owned()),
+            min_proof_size: Some("20".to_owned()),
+            max_proof_size: Some("1'024".to_owned()),
+        };
+        let block_time = Duration::from_secs(30);
+
+        assert!(secret_lock_transaction(86400)
+            .validate(&properties, block_time)
+            .is_ok());
+        assert_eq!(
+            secret_lock_transaction(0)
+                .validate(&properties, block_time)
+                .unwrap_err()
+                .to_string(),
+            "Invalid secret lock duration 0, expected between 1 and 86400 blocks."
+        );
+    }
+}
@@ -0,0 +1,366 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::account::UnresolvedAddress;
+use crate::core::utils::unresolved_mapping::to_unresolved_address;
+use crate::network::{NetworkType, SecretLockNetworkProperties};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{LockHashAlgorithm, Transaction, TransactionType, TransactionVersion};
+use crate::{der_bytes_from_hex, hex_decode, is_hex, ser_to_hex_upper, Deadline, H256};
+
+/// Secret proof transaction struct, unlocks the funds of a `SecretLockTransaction`.
+///
+/// The proof hashed with the lock hash algorithm must match the secret of the lock.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretProofTransaction {
+    pub common: CommonTransaction,
+    /// The algorithm used to hash the proof.
+    pub hash_algorithm: LockHashAlgorithm,
+    /// The proof hashed.
+    pub secret: H256,
+    /// The address that receives the funds once unlocked.
+    pub recipient: Box<dyn UnresolvedAddress>,
+    /// The original random set of bytes.
+    #[serde(
+        serialize_with = "ser_to_hex_upper",
+        deserialize_with = "der_bytes_from_hex"
+    )]
+    pub proof: Vec<u8>,
+}
+
+impl SecretProofTransaction {
+    /// The size of the unresolved recipient address in bytes.
+    ///
+    const RECIPIENT_SIZE: usize = 24;
+
+    /// The size of the fixed part of the secret proof transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = Self::RECIPIENT_SIZE + 32 + 2 + 1;
+
+    /// Create a secret proof transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `hash_algorithm`: The algorithm used to hash the proof.
+    /// * `secret`: The secret of the lock to unlock.
+    /// * `recipient`: The address that receives the funds once unlocked.
+    /// * `proof`: The original random set of bytes.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SecretProofTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create<R: 'static + UnresolvedAddress>(
+        deadline: Deadline,
+        hash_algorithm: LockHashAlgorithm,
+        secret: H256,
+        recipient: R,
+        proof: Vec<u8>,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            proof.len() <= u16::MAX as usize,
+            "Proof size {} exceeds the maximum of {} bytes.",
+            proof.len(),
+            u16::MAX
+        );
+        ensure!(
+            hash_algorithm.verify_proof(&proof, &secret),
+            "Proof does not match the secret {:X} for algorithm {}.",
+            secret,
+            hash_algorithm
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::SecretProof,
+            network_type,
+            TransactionVersion::SECRET_PROOF,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            hash_algorithm,
+            secret,
+            recipient: Box::new(recipient),
+            proof,
+        })
+    }
+
+    /// The String notation for the set recipient.
+    pub fn recipient_to_string(&self) -> String {
+        self.recipient.recipient_to_string()
+    }
+
+    /// Validates the proof size against the network secret lock properties.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `SecretLockNetworkProperties`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(&self, properties: &SecretLockNetworkProperties) -> Result<()> {
+        let min_proof_size = properties.get_min_proof_size()?;
+        let max_proof_size = properties.get_max_proof_size()?;
+        let proof_size = self.proof.len() as u64;
+        ensure!(
+            proof_size >= min_proof_size && proof_size <= max_proof_size,
+            "Invalid proof size {}, expected between {} and {} bytes.",
+            proof_size,
+            min_proof_size,
+            max_proof_size
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `SecretProofTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `SecretProofTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::SecretProof,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::SecretProof
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `SecretProofTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid secret proof transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut recipient = [0u8; Self::RECIPIENT_SIZE];
+        cursor.read_exact(&mut recipient)?;
+        let mut secret = [0u8; 32];
+        cursor.read_exact(&mut secret)?;
+        let proof_size = cursor.read_u16::<LittleEndian>()? as usize;
+        let hash_algorithm = LockHashAlgorithm::try_from(cursor.read_u8()?)?;
+
+        ensure!(
+            body.len() == Self::BODY_FIXED_SIZE + proof_size,
+            "Invalid secret proof transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE + proof_size
+        );
+        let mut proof = vec![0u8; proof_size];
+        cursor.read_exact(&mut proof)?;
+
+        Ok(Self {
+            common,
+            hash_algorithm,
+            secret: H256::from(secret),
+            recipient: to_unresolved_address(&hex::encode(recipient))?,
+            proof,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for SecretProofTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_FIXED_SIZE + self.proof.len());
+        buf.extend(
+            self.recipient
+                .unresolved_address_to_bytes(self.common.network_type),
+        );
+        buf.extend_from_slice(self.secret.as_bytes());
+        buf.extend_from_slice(&(self.proof.len() as u16).to_le_bytes());
+        buf.extend_from_slice(&self.hash_algorithm.to_bytes());
+        buf.extend_from_slice(&self.proof);
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for SecretProofTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::account::Address;
+    use crate::network::{NetworkType, SecretLockNetworkProperties};
+    use crate::transaction::{
+        create_transaction_from_payload, LockHashAlgorithm, SecretProofTransaction, Transaction,
+        TransactionType,
+    };
+    use crate::{Deadline, H256};
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    const PROOF: &str = "B778A39A3663719DFC5E48C9D78431B1E45C2AF9DF538782BF199C189DABEAC7";
+
+    fn secret_proof_transaction(
+        algorithm: LockHashAlgorithm,
+        proof: &[u8],
+    ) -> SecretProofTransaction {
+        SecretProofTransaction::create(
+            Deadline::from(1),
+            algorithm,
+            algorithm.create_secret(proof),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            proof.to_vec(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_secret_proof_transaction() {
+        let proof = hex::decode(PROOF).unwrap();
+        let transaction = secret_proof_transaction(LockHashAlgorithm::Sha3_256, &proof);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::SecretProof
+        );
+        assert_eq!(transaction.size(), 219);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!(
+                "9826D27E1D0A26CA4E316F901E23E55C8711DB20DFD26776\
+                9B3155B37159DA50AA52D5967C509B410F5A36A3B1E31ECB5AC76675D79B4A5E\
+                200000{}",
+                PROOF
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_secret_proof_transaction() {
+        let proof = hex::decode(PROOF).unwrap();
+        let transaction = secret_proof_transaction(LockHashAlgorithm::Hash_256, &proof);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<SecretProofTransaction>();
+
+        assert_eq!(mapped.proof, proof);
+        assert_eq!(mapped.secret, transaction.secret);
+        assert_eq!(mapped.hash_algorithm, LockHashAlgorithm::Hash_256);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_proof_size_against_network_properties() {
+        let properties = SecretLockNetworkProperties {
+            max_secret_lock_duration: Some("30d".to_owned()),
+            min_proof_size: Some("20".to_owned()),
+            max_proof_size: Some("1'024".to_owned()),
+        };
+
+        assert!(
+            secret_proof_transaction(LockHashAlgorithm::Hash_160, &[1u8; 1024])
+                .validate(&properties)
+                .is_ok()
+        );
+        assert_eq!(
+            secret_proof_transaction(LockHashAlgorithm::Hash_160, &[1u8; 1025])
+                .validate(&properties)
+                .unwrap_err()
+                .to_string(),
+            "Invalid proof size 1025, expected between 20 and 1024 bytes."
+        );
+        assert_eq!(
+            secret_proof_transaction(LockHashAlgorithm::Hash_160, &[1u8; 19])
+                .validate(&properties)
+                .unwrap_err()
+                .to_string(),
+            "Invalid proof size 19, expected between 20 and 1024 bytes."
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Proof does not match the secret")]
+    fn test_should_panic_if_proof_does_not_match_secret() {
+        SecretProofTransaction::create(
+            Deadline::from(1),
+            LockHashAlgorithm::Sha3_256,
+            H256::zero(),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            hex::decode(PROOF).unwrap(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::account::{Address, PublicAccount};
+use crate::network::NetworkType;
+use crate::transaction::TransactionType;
+use crate::TransactionHash;
+
+/// The `SignedTransaction` struct is used to transfer the transaction data and the signature to the server
+/// or to an external signer.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedTransaction {
+    /// Transaction serialized data in hex.
+    pub payload: String,
+    /// Transaction hash.
+    pub hash: TransactionHash,
+    /// Transaction signer.
+    pub signer: PublicAccount,
+    /// Transaction type.
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    /// Signer network type.
+    pub network_type: NetworkType,
+}
+
+impl SignedTransaction {
+    /// Returns the signer `Address`.
+    ///
+    pub fn get_signer_address(&self) -> Address {
+        self.signer.address
+    }
+}
+
+impl fmt::Display for SignedTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// The `CosignatureSignedTransaction` struct holds the signature of a cosigner
+/// of an aggregate transaction.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosignatureSignedTransaction {
+    /// The hash of the aggregate transaction that is signed by the cosigner.
+    pub parent_hash: TransactionHash,
+    /// The signature of the parent hash in hex.
+    pub signature: String,
+    /// The cosigner public key in hex.
+    pub signer_public_key: String,
+    /// The cosignature version.
+    pub version: u64,
+}
+
+impl fmt::Display for CosignatureSignedTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -8,13 +8,131 @@
  * // except according to those terms.
  */
 
+use std::any::Any;
 use std::fmt;
 
+use anyhow::{anyhow, Result};
+
+use crate::account::PublicAccount;
+use crate::transaction::CommonTransaction;
+
 /// An abstract transaction trait that serves as the base of all transaction types.
 ///
+/// A transaction only provides its `CommonTransaction` and the serialized body,
+/// the full and embedded serialization are shared by all transaction types.
+///
 #[typetag::serde]
 pub trait Transaction: Sync + Send
 where
     Self: fmt::Debug,
 {
+    /// Returns the common transaction properties.
+    ///
+    fn common(&self) -> &CommonTransaction;
+
+    /// Returns a mutable reference to the common transaction properties.
+    ///
+    fn common_mut(&mut self) -> &mut CommonTransaction;
+
+    /// Serializes the transaction body, the part that follows the transaction header.
+    ///
+    fn body_to_bytes(&self) -> Vec<u8>;
+
+    fn box_clone(&self) -> Box<dyn Transaction>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    /// Returns the size of the serialized transaction in bytes.
+    ///
+    fn size(&self) -> usize {
+        CommonTransaction::HEADER_SIZE + self.body_to_bytes().len()
+    }
+
+    /// Serializes the transaction into its catbuffer binary layout.
+    ///
+    fn to_bytes(&self) -> Vec<u8> {
+        let body = self.body_to_bytes();
+        let mut buf = self
+            .common()
+            .header_to_bytes(CommonTransaction::HEADER_SIZE + body.len());
+        buf.extend(body);
+        buf
+    }
+
+    /// Serializes the transaction into an uppercase hex payload.
+    ///
+    fn serialize(&self) -> String {
+        hex::encode_upper(self.to_bytes())
+    }
+
+    /// Returns the size of the serialized embedded transaction in bytes.
+    ///
+    fn embedded_size(&self) -> usize {
+        CommonTransaction::EMBEDDED_HEADER_SIZE + self.body_to_bytes().len()
+    }
+
+    /// Serializes the transaction into its embedded form, as it is included in an aggregate transaction.
+    ///
+    /// The embedded form only carries the signer public key,
+    /// the signature, max fee and deadline are defined by the aggregate transaction.
+    ///
+    fn to_embedded_bytes(&self) -> Vec<u8> {
+        let body = self.body_to_bytes();
+        let mut buf = self
+            .common()
+            .embedded_header_to_bytes(CommonTransaction::EMBEDDED_HEADER_SIZE + body.len());
+        buf.extend(body);
+        buf
+    }
+
+    /// Converts the transaction into an inner transaction of an aggregate transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `signer`: The inner transaction signer.
+    ///
+    fn to_aggregate(&self, signer: PublicAccount) -> Box<dyn Transaction> {
+        let mut transaction = self.box_clone();
+        transaction.common_mut().signer = Some(signer);
+        transaction
+    }
+}
+
+impl Clone for Box<dyn Transaction + 'static> {
+    fn clone(&self) -> Box<dyn Transaction + 'static> {
+        self.box_clone()
+    }
+}
+
+impl dyn Transaction {
+    /// Downcast a reference to this generic `Transaction` to a specific type.
+    ///
+    /// # Panics
+    ///
+    /// If the type is not the correct specific type.
+    /// In other cases, use `try_downcast_ref`.
+    ///
+    pub fn downcast_ref<T: 'static + Transaction>(&self) -> &T {
+        self.try_downcast_ref::<T>()
+            .unwrap_or_else(|| panic!("downcast to wrong Transaction type"))
+    }
+
+    /// Downcast a reference to this generic `Transaction` to a specific type.
+    ///
+    #[inline]
+    pub fn try_downcast_ref<T: 'static + Transaction>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+
+    /// Downcast this generic `Transaction` to a specific type.
+    ///
+    pub fn try_downcast<T: 'static + Transaction>(self: Box<Self>) -> Result<Box<T>> {
+        if self.as_ref().as_any().is::<T>() {
+            Ok(self.into_any().downcast().unwrap())
+        } else {
+            Err(anyhow!("downcast to wrong Transaction type"))
+        }
+    }
 }
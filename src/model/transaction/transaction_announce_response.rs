@@ -0,0 +1,28 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// The node response to an announced transaction.
+///
+/// The node only acknowledges the announcement, use the transaction status
+/// to follow the transaction.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionAnnounceResponse {
+    /// The node message.
+    pub message: String,
+}
+
+impl fmt::Display for TransactionAnnounceResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
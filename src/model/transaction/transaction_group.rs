@@ -0,0 +1,45 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+/// The group a transaction belongs to on the node.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionGroup {
+    /// The transaction is waiting to be included in a block.
+    Unconfirmed,
+    /// The transaction is included in a block.
+    Confirmed,
+    /// The transaction was rejected by the node.
+    Failed,
+    /// The aggregate bonded transaction is waiting for cosignatures.
+    Partial,
+}
+
+impl TransactionGroup {
+    /// Returns the group name, as it is used by the REST routes.
+    ///
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionGroup::Unconfirmed => "unconfirmed",
+            TransactionGroup::Confirmed => "confirmed",
+            TransactionGroup::Failed => "failed",
+            TransactionGroup::Partial => "partial",
+        }
+    }
+}
+
+impl fmt::Display for TransactionGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,215 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{ensure, Result};
+use sha3::{Digest, Sha3_256};
+
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::TransactionType;
+use crate::{hex_decode, is_hex, GenerationHash, TransactionHash, H256};
+
+/// The offset of the signed (verifiable) part of a transaction payload.
+///
+const VERIFIABLE_DATA_OFFSET: usize =
+    8 + CommonTransaction::SIGNATURE_SIZE + CommonTransaction::SIGNER_SIZE + 4;
+
+/// The size of the signed part of an aggregate transaction payload
+/// (header remainder and transactions hash).
+///
+const AGGREGATE_VERIFIABLE_DATA_SIZE: usize = 52;
+
+/// Generates the transaction hash for a serialized transaction payload.
+///
+/// The hash is the SHA3-256 of the signature R, the signer public key, the generation hash
+/// and the verifiable data of the transaction. For aggregate transactions only the header part
+/// is hashed, so the hash does not change when cosignatures are attached.
+///
+/// # Inputs
+///
+/// * `payload`: The serialized transaction in hex.
+/// * `generation_hash`: The network generation hash.
+///
+/// # Example
+///
+/// ```
+/// use std::str::FromStr;
+/// use symbol_sdk::GenerationHash;
+/// use symbol_sdk::transaction::create_transaction_hash;
+///
+/// #
+/// # fn main() {
+/// #
+/// let generation_hash =
+///     GenerationHash::from_str("1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B")
+///         .unwrap();
+/// let payload = format!("80000000{}01A85441{}", "0".repeat(208), "0".repeat(32));
+/// let hash = create_transaction_hash(&payload, generation_hash).unwrap();
+/// # println!("{:X}", hash);
+/// # }
+/// ```
+///
+/// # Returns
+///
+/// A `Result` whose okay value is an `TransactionHash` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn create_transaction_hash(
+    payload: &str,
+    generation_hash: GenerationHash,
+) -> Result<TransactionHash> {
+    ensure!(
+        is_hex(payload),
+        "Input string is not in valid hexadecimal notation."
+    );
+
+    create_transaction_hash_from_bytes(&hex_decode(payload), generation_hash)
+}
+
+/// Generates the transaction hash for a serialized transaction.
+///
+/// # Inputs
+///
+/// * `payload`: The serialized transaction bytes.
+/// * `generation_hash`: The network generation hash.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is an `TransactionHash` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn create_transaction_hash_from_bytes(
+    payload: &[u8],
+    generation_hash: GenerationHash,
+) -> Result<TransactionHash> {
+    let signing_bytes = signing_bytes(payload, generation_hash)?;
+
+    let mut hash = Sha3_256::default();
+    // signature R
+    hash.update(&payload[8..40]);
+    // signer public key
+    hash.update(&payload[72..104]);
+    hash.update(&signing_bytes);
+
+    Ok(H256::from_slice(&hash.finalize()))
+}
+
+/// Returns true when the payload is an aggregate transaction.
+///
+pub(crate) fn is_aggregate_payload(payload: &[u8]) -> bool {
+    if payload.len() < CommonTransaction::HEADER_SIZE {
+        return false;
+    }
+
+    let entity_type = u16::from_le_bytes([payload[110], payload[111]]);
+    entity_type == TransactionType::AggregateComplete.value()
+        || entity_type == TransactionType::AggregateBonded.value()
+}
+
+/// Returns the data that is signed for the given transaction payload:
+/// the generation hash followed by the verifiable part of the transaction.
+///
+/// For aggregate transactions only the header part is signed,
+/// the cosignatures are excluded.
+///
+pub(crate) fn signing_bytes(payload: &[u8], generation_hash: GenerationHash) -> Result<Vec<u8>> {
+    ensure!(
+        payload.len() >= CommonTransaction::HEADER_SIZE,
+        "Invalid transaction payload size {}, the header requires {} bytes.",
+        payload.len(),
+        CommonTransaction::HEADER_SIZE
+    );
+
+    let verifiable_data = if is_aggregate_payload(payload) {
+        ensure!(
+            payload.len() >= VERIFIABLE_DATA_OFFSET + AGGREGATE_VERIFIABLE_DATA_SIZE,
+            "Invalid aggregate transaction payload size {}.",
+            payload.len()
+        );
+        &payload[VERIFIABLE_DATA_OFFSET..VERIFIABLE_DATA_OFFSET + AGGREGATE_VERIFIABLE_DATA_SIZE]
+    } else {
+        &payload[VERIFIABLE_DATA_OFFSET..]
+    };
+
+    let mut buf = Vec::with_capacity(generation_hash.as_bytes().len() + verifiable_data.len());
+    buf.extend_from_slice(generation_hash.as_bytes());
+    buf.extend_from_slice(verifiable_data);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::transaction::{create_transaction_hash, create_transaction_hash_from_bytes};
+    use crate::{hex_decode, GenerationHash};
+
+    const GENERATION_HASH: &str =
+        "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+    const SIGNED_TRANSFER_PAYLOAD: &str =
+        "AD00000000000000A873E7C5818FA8FA8FC9D338DEEFF15A5F3DEE2EE879DEB35E3D517410669BA2\
+         CFD01ABA1FC91A3BD9945F73FC68FA3AE0418F52313F50177BECDFF1A9CBB50B9801508C58666C74\
+         6F471538E43002B85B1CD542F9874B2861183919BA8787B60000000001A854410000000000000000\
+         0100000000000000A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C5360D00000000000000\
+         00746573742D6D657373616765";
+
+    fn generation_hash() -> GenerationHash {
+        GenerationHash::from_str(GENERATION_HASH).unwrap()
+    }
+
+    #[test]
+    fn test_should_create_transaction_hash() {
+        let hash = create_transaction_hash(SIGNED_TRANSFER_PAYLOAD, generation_hash()).unwrap();
+
+        assert_eq!(
+            format!("{:X}", hash),
+            "654CDABBE1D00F911AC59120B3A04FBDF1CD15188D252EF48EBC618AEA30E53A"
+        );
+    }
+
+    #[test]
+    fn test_should_depend_on_generation_hash() {
+        let hash = create_transaction_hash(SIGNED_TRANSFER_PAYLOAD, generation_hash()).unwrap();
+        let other_hash =
+            create_transaction_hash(SIGNED_TRANSFER_PAYLOAD, GenerationHash::zero()).unwrap();
+
+        assert_ne!(hash, other_hash);
+    }
+
+    #[test]
+    fn test_should_hash_only_aggregate_header() {
+        let mut payload = vec![0u8; 168];
+        payload[0] = 168;
+        payload[8] = 1;
+        payload[72] = 2;
+        payload[108] = 1;
+        payload[109] = 0xA8;
+        payload[110..112].copy_from_slice(&[0x41, 0x41]);
+        payload[128] = 3;
+
+        let hash = create_transaction_hash_from_bytes(&payload, generation_hash()).unwrap();
+
+        payload.extend_from_slice(&[7u8; 104]);
+        let hash_with_cosignature =
+            create_transaction_hash_from_bytes(&payload, generation_hash()).unwrap();
+
+        assert_eq!(hash, hash_with_cosignature);
+        assert_eq!(
+            format!("{:X}", hash),
+            "4528258C0CB8B155981B17EE234176F5FF89142FD579DE966C27ADE6B156F834"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid transaction payload size 4, the header requires 128 bytes.")]
+    fn test_should_panic_if_payload_is_truncated() {
+        create_transaction_hash_from_bytes(&hex_decode("00000000"), generation_hash()).unwrap();
+    }
+}
@@ -0,0 +1,299 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use anyhow::{bail, ensure, Result};
+
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{
+    AccountAddressRestrictionTransaction, AccountKeyLinkTransaction, AccountMetadataTransaction,
+    AccountMosaicRestrictionTransaction, AccountOperationRestrictionTransaction,
+    AddressAliasTransaction, AggregateTransaction, HashLockTransaction,
+    MosaicAddressRestrictionTransaction, MosaicAliasTransaction, MosaicDefinitionTransaction,
+    MosaicGlobalRestrictionTransaction, MosaicMetadataTransaction, MosaicSupplyChangeTransaction,
+    MultisigAccountModificationTransaction, NamespaceMetadataTransaction,
+    NamespaceRegistrationTransaction, NodeKeyLinkTransaction, SecretLockTransaction,
+    SecretProofTransaction, Transaction, TransactionType, TransferTransaction,
+    VotingKeyLinkTransaction, VrfKeyLinkTransaction,
+};
+use crate::{hex_decode, is_hex};
+
+/// Creates a transaction from its hex payload.
+///
+/// The concrete transaction type is resolved from the entity type of the transaction header,
+/// use `downcast_ref` or `try_downcast` on the result to access the concrete transaction.
+///
+/// # Inputs
+///
+/// * `payload`: The serialized transaction in hex.
+///
+/// # Example
+///
+/// ```
+/// use symbol_sdk::transaction::{create_transaction_from_payload, TransactionType};
+///
+/// #
+/// # fn main() {
+/// #
+/// let payload = "AD00000000000000A873E7C5818FA8FA8FC9D338DEEFF15A5F3DEE2EE879DEB35E3D517410669BA2\
+///                CFD01ABA1FC91A3BD9945F73FC68FA3AE0418F52313F50177BECDFF1A9CBB50B9801508C58666C74\
+///                6F471538E43002B85B1CD542F9874B2861183919BA8787B60000000001A854410000000000000000\
+///                0100000000000000A826D27E1D0A26CA4E316F901E23E55C8711DB20DF45C5360D00000000000000\
+///                00746573742D6D657373616765";
+/// let transaction = create_transaction_from_payload(payload).unwrap();
+/// assert_eq!(transaction.common().transaction_type, TransactionType::Transfer);
+/// # }
+/// ```
+///
+/// # Returns
+///
+/// A `Result` whose okay value is a `Box<dyn Transaction>` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn create_transaction_from_payload(payload: &str) -> Result<Box<dyn Transaction>> {
+    ensure!(
+        is_hex(payload),
+        "Input string is not in valid hexadecimal notation."
+    );
+
+    create_transaction_from_bytes(&hex_decode(payload))
+}
+
+/// Creates a transaction from its catbuffer binary layout.
+///
+/// # Inputs
+///
+/// * `payload`: The serialized transaction bytes.
+///
+/// # Returns
+///
+/// A `Result` whose okay value is a `Box<dyn Transaction>` or whose error value
+/// is an `Error` describing the error that occurred.
+///
+pub fn create_transaction_from_bytes(payload: &[u8]) -> Result<Box<dyn Transaction>> {
+    let (common, body) = CommonTransaction::from_bytes(payload)?;
+    create_transaction_from_body(common, body)
+}
+
+/// Creates an embedded (aggregate inner) transaction from its catbuffer binary layout.
+///
+pub(crate) fn create_embedded_transaction_from_bytes(
+    payload: &[u8],
+) -> Result<Box<dyn Transaction>> {
+    let (common, body) = CommonTransaction::from_embedded_bytes(payload)?;
+    ensure!(
+        common.transaction_type != TransactionType::AggregateComplete
+            && common.transaction_type != TransactionType::AggregateBonded,
+        "Aggregate transactions cannot be embedded in an aggregate transaction."
+    );
+
+    create_transaction_from_body(common, body)
+}
+
+fn create_transaction_from_body(
+    common: CommonTransaction,
+    body: &[u8],
+) -> Result<Box<dyn Transaction>> {
+    let transaction: Box<dyn Transaction> = match common.transaction_type {
+        TransactionType::Transfer => Box::new(TransferTransaction::from_body(common, body)?),
+        TransactionType::AggregateComplete | TransactionType::AggregateBonded => {
+            Box::new(AggregateTransaction::from_body(common, body)?)
+        }
+        TransactionType::RegisterNamespace => {
+            Box::new(NamespaceRegistrationTransaction::from_body(common, body)?)
+        }
+        TransactionType::AddressAlias => {
+            Box::new(AddressAliasTransaction::from_body(common, body)?)
+        }
+        TransactionType::MosaicAlias => Box::new(MosaicAliasTransaction::from_body(common, body)?),
+        TransactionType::MosaicDefinition => {
+            Box::new(MosaicDefinitionTransaction::from_body(common, body)?)
+        }
+        TransactionType::MosaicSupplyChange => {
+            Box::new(MosaicSupplyChangeTransaction::from_body(common, body)?)
+        }
+        TransactionType::MultisigAccountModify => Box::new(
+            MultisigAccountModificationTransaction::from_body(common, body)?,
+        ),
+        TransactionType::Hash_Lock => Box::new(HashLockTransaction::from_body(common, body)?),
+        TransactionType::SecretLock => Box::new(SecretLockTransaction::from_body(common, body)?),
+        TransactionType::SecretProof => Box::new(SecretProofTransaction::from_body(common, body)?),
+        TransactionType::AccountRestrictionAddress => Box::new(
+            AccountAddressRestrictionTransaction::from_body(common, body)?,
+        ),
+        TransactionType::AccountRestrictionMosaic => Box::new(
+            AccountMosaicRestrictionTransaction::from_body(common, body)?,
+        ),
+        TransactionType::AccountRestrictionOperation => Box::new(
+            AccountOperationRestrictionTransaction::from_body(common, body)?,
+        ),
+        TransactionType::MosaicGlobalRestriction => {
+            Box::new(MosaicGlobalRestrictionTransaction::from_body(common, body)?)
+        }
+        TransactionType::MosaicAddressRestriction => Box::new(
+            MosaicAddressRestrictionTransaction::from_body(common, body)?,
+        ),
+        TransactionType::AccountMetadata => {
+            Box::new(AccountMetadataTransaction::from_body(common, body)?)
+        }
+        TransactionType::MosaicMetadata => {
+            Box::new(MosaicMetadataTransaction::from_body(common, body)?)
+        }
+        TransactionType::NamespaceMetadata => {
+            Box::new(NamespaceMetadataTransaction::from_body(common, body)?)
+        }
+        TransactionType::AccountKeyLink => {
+            Box::new(AccountKeyLinkTransaction::from_body(common, body)?)
+        }
+        TransactionType::NodeKeyLink => Box::new(NodeKeyLinkTransaction::from_body(common, body)?),
+        TransactionType::VrfKeyLink => Box::new(VrfKeyLinkTransaction::from_body(common, body)?),
+        TransactionType::VotingKeyLink => {
+            Box::new(VotingKeyLinkTransaction::from_body(common, body)?)
+        }
+        transaction_type => bail!(
+            "Transaction type {} is not supported yet.",
+            transaction_type
+        ),
+    };
+
+    Ok(transaction)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::account::tests::{COSIGNATORY_ACCOUNT, TESTING_ACCOUNT};
+    use crate::account::Address;
+    use crate::message::PlainMessage;
+    use crate::network::NetworkType;
+    use crate::transaction::{
+        create_transaction_from_bytes, create_transaction_from_payload, AggregateTransaction,
+        AggregateTransactionCosignature, Transaction, TransactionType, TransferTransaction,
+    };
+    use crate::{hex_decode, Deadline, GenerationHash};
+
+    const GENERATION_HASH: &str =
+        "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B";
+
+    fn transfer_transaction() -> TransferTransaction {
+        TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw("VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ").unwrap(),
+            vec![],
+            PlainMessage::create("test-message"),
+            NetworkType::PrivateTest,
+            Some(1),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_transfer_transaction_from_payload() {
+        let transaction = transfer_transaction();
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+
+        assert_eq!(mapped.common().transaction_type, TransactionType::Transfer);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+        assert_eq!(
+            mapped
+                .downcast_ref::<TransferTransaction>()
+                .recipient_to_string(),
+            "VATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA35C4KNQ"
+        );
+    }
+
+    #[test]
+    fn test_should_create_aggregate_transaction_from_payload() {
+        let generation_hash = GenerationHash::from_str(GENERATION_HASH).unwrap();
+
+        let mut aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![
+                transfer_transaction().to_aggregate(TESTING_ACCOUNT.public_account),
+                transfer_transaction().to_aggregate(COSIGNATORY_ACCOUNT.public_account),
+            ],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+
+        let signed_transaction = TESTING_ACCOUNT
+            .sign_transaction(aggregate_transaction.to_bytes(), generation_hash)
+            .unwrap();
+        let cosignature = COSIGNATORY_ACCOUNT
+            .sign_cosignature_transaction(hex_decode(&signed_transaction.payload), generation_hash)
+            .unwrap();
+        aggregate_transaction.add_cosignatures(vec![
+            AggregateTransactionCosignature::from_cosignature_signed_transaction(
+                &cosignature,
+                NetworkType::PrivateTest,
+            )
+            .unwrap(),
+        ]);
+
+        let mapped = create_transaction_from_bytes(&aggregate_transaction.to_bytes()).unwrap();
+        assert_eq!(mapped.serialize(), aggregate_transaction.serialize());
+
+        let mapped = mapped.try_downcast::<AggregateTransaction>().unwrap();
+        assert_eq!(mapped.inner_transactions.len(), 2);
+        assert_eq!(
+            mapped.inner_transactions[1].common().signer,
+            Some(COSIGNATORY_ACCOUNT.public_account)
+        );
+        assert!(mapped.inner_transactions[1]
+            .try_downcast_ref::<TransferTransaction>()
+            .is_some());
+        assert_eq!(mapped.cosignatures.len(), 1);
+        assert_eq!(mapped.cosignatures[0].signature, cosignature.signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown transaction type")]
+    fn test_should_panic_if_transaction_type_is_unknown() {
+        let mut payload = transfer_transaction().to_bytes();
+        payload[110..112].copy_from_slice(&[0xFF, 0xFF]);
+
+        create_transaction_from_bytes(&payload).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid transaction size 173, the payload has 150 bytes.")]
+    fn test_should_panic_if_payload_is_truncated() {
+        let payload = transfer_transaction().to_bytes();
+
+        create_transaction_from_bytes(&payload[..150]).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Input string is not in valid hexadecimal notation.")]
+    fn test_should_panic_if_payload_is_not_hex() {
+        create_transaction_from_payload("not a payload").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid aggregate transaction payload size")]
+    fn test_should_panic_if_aggregate_body_is_truncated() {
+        let aggregate_transaction = AggregateTransaction::create_complete(
+            Deadline::from(1),
+            vec![transfer_transaction().to_aggregate(TESTING_ACCOUNT.public_account)],
+            vec![],
+            NetworkType::PrivateTest,
+            None,
+        )
+        .unwrap();
+        let mut payload = aggregate_transaction.to_bytes();
+        payload.truncate(200);
+        payload[0..4].copy_from_slice(&200u32.to_le_bytes());
+
+        create_transaction_from_bytes(&payload).unwrap();
+    }
+}
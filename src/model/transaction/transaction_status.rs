@@ -0,0 +1,58 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::fmt;
+
+use crate::transaction::TransactionGroup;
+use crate::{Deadline, TransactionHash};
+
+/// The status of a transaction announced to the node.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatus {
+    /// The transaction group.
+    pub group: TransactionGroup,
+    /// The transaction status code, "Success" or the reason the transaction failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The transaction hash.
+    pub hash: TransactionHash,
+    /// The transaction deadline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<Deadline>,
+    /// The height of the block that includes the transaction, when it is confirmed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+}
+
+impl TransactionStatus {
+    /// Returns true when the transaction is included in a block.
+    ///
+    pub fn is_confirmed(&self) -> bool {
+        self.group == TransactionGroup::Confirmed
+    }
+
+    /// Returns true when the transaction was rejected by the node.
+    ///
+    pub fn is_failed(&self) -> bool {
+        self.group == TransactionGroup::Failed
+    }
+}
+
+impl fmt::Display for TransactionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -132,6 +132,12 @@ impl Deref for TransactionVersion {
     }
 }
 
+impl From<u8> for TransactionVersion {
+    fn from(value: u8) -> Self {
+        TransactionVersion(value)
+    }
+}
+
 impl fmt::Display for TransactionVersion {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", self.0)
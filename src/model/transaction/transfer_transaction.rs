@@ -8,17 +8,21 @@
  * // except according to those terms.
  */
 
+use std::any::Any;
 use std::fmt;
+use std::io::{Cursor, Read};
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
 
 use crate::account::UnresolvedAddress;
-use crate::message::Message;
+use crate::core::utils::unresolved_mapping::{to_unresolved_address, to_unresolved_mosaic};
+use crate::message::{create_message_from_buffer, Message, PlainMessage, RawMessage};
 use crate::mosaic::Mosaic;
 use crate::network::NetworkType;
 use crate::transaction::common_transaction::CommonTransaction;
 use crate::transaction::{Transaction, TransactionType, TransactionVersion};
-use crate::Deadline;
+use crate::{hex_decode, is_hex, Deadline, Uint64};
 
 /// Create a transfer transaction struct.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,6 +38,22 @@ pub struct TransferTransaction {
 }
 
 impl TransferTransaction {
+    /// The size of the unresolved recipient address in bytes.
+    ///
+    const RECIPIENT_SIZE: usize = 24;
+
+    /// The size of the fixed part of the transfer transaction body in bytes.
+    ///
+    const BODY_FIXED_SIZE: usize = Self::RECIPIENT_SIZE + 2 + 1 + 4 + 1;
+
+    /// The size of a serialized mosaic (id and amount) in bytes.
+    ///
+    const MOSAIC_SIZE: usize = 16;
+
+    /// The maximum size of the serialized message in bytes, message type included.
+    ///
+    const MAX_MESSAGE_SIZE: usize = 2048;
+
     /// Create a transfer transaction object.
     pub fn create<M: 'static + Message, R: 'static + UnresolvedAddress>(
         deadline: Deadline,
@@ -43,6 +63,21 @@ impl TransferTransaction {
         network_type: NetworkType,
         max_fee: Option<u64>,
     ) -> Result<Self> {
+        ensure!(
+            mosaics.len() <= u8::MAX as usize,
+            "Too many mosaics {}, a transfer transaction supports at most {}.",
+            mosaics.len(),
+            u8::MAX
+        );
+
+        let message_size = message.to_dto().len() / 2;
+        ensure!(
+            message_size <= Self::MAX_MESSAGE_SIZE,
+            "Message size {} exceeds the maximum of {} bytes.",
+            message_size,
+            Self::MAX_MESSAGE_SIZE
+        );
+
         let max_fee = max_fee.unwrap_or_default();
 
         let common = CommonTransaction::create_from_type(
@@ -63,7 +98,7 @@ impl TransferTransaction {
 
     /// The String notation for the set recipient.
     pub fn recipient_to_string(&self) -> String {
-        todo!()
+        self.recipient.recipient_to_string()
     }
 
     /// Sorted mosaic vec.
@@ -76,10 +111,152 @@ impl TransferTransaction {
 
         self.mosaics.clone()
     }
+
+    /// Creates a `TransferTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is an `TransferTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::Transfer,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::Transfer
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `TransferTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() >= Self::BODY_FIXED_SIZE,
+            "Invalid transfer transaction body size {}, expected at least {} bytes.",
+            body.len(),
+            Self::BODY_FIXED_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut recipient = [0u8; Self::RECIPIENT_SIZE];
+        cursor.read_exact(&mut recipient)?;
+        let message_size = cursor.read_u16::<LittleEndian>()? as usize;
+        let mosaics_count = cursor.read_u8()? as usize;
+        cursor.set_position(Self::BODY_FIXED_SIZE as u64);
+
+        let expected_size =
+            Self::BODY_FIXED_SIZE + mosaics_count * Self::MOSAIC_SIZE + message_size;
+        ensure!(
+            body.len() == expected_size,
+            "Invalid transfer transaction body size {}, expected {} bytes.",
+            body.len(),
+            expected_size
+        );
+
+        let mut mosaics = Vec::with_capacity(mosaics_count);
+        for _ in 0..mosaics_count {
+            let id = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+            let amount = Uint64::from(cursor.read_u64::<LittleEndian>()?);
+            mosaics.push(Mosaic {
+                id: to_unresolved_mosaic(&id.to_hex())?,
+                amount,
+            });
+        }
+
+        let mut message = vec![0u8; message_size];
+        cursor.read_exact(&mut message)?;
+
+        Ok(Self {
+            common,
+            recipient: to_unresolved_address(&hex::encode(recipient))?,
+            mosaics,
+            message: message_from_bytes(&message),
+        })
+    }
+
+    fn message_to_bytes(&self) -> Vec<u8> {
+        hex_decode(&self.message.to_dto())
+    }
 }
 
 #[typetag::serde]
-impl Transaction for TransferTransaction {}
+impl Transaction for TransferTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        // mosaics are always serialized sorted by id, as required by the network.
+        let mut mosaics = self.mosaics.clone();
+        mosaics.sort_by_key(|mosaic| *mosaic.id.to_uint64());
+
+        let message = self.message_to_bytes();
+
+        let mut buf = Vec::with_capacity(
+            Self::BODY_FIXED_SIZE + mosaics.len() * Self::MOSAIC_SIZE + message.len(),
+        );
+        buf.extend(
+            self.recipient
+                .unresolved_address_to_bytes(self.common.network_type),
+        );
+        buf.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        buf.push(mosaics.len() as u8);
+        // transfer_transaction_body_reserved_1
+        buf.extend_from_slice(&[0u8; 4]);
+        // transfer_transaction_body_reserved_2
+        buf.push(0);
+        for mosaic in mosaics.iter() {
+            buf.extend(mosaic.to_vec());
+        }
+        buf.extend(message);
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Creates the transfer message from its serialized bytes.
+///
+/// Messages that can not be decoded as their declared type are kept as `RawMessage`.
+///
+pub(crate) fn message_from_bytes(bytes: &[u8]) -> Box<dyn Message> {
+    if bytes.is_empty() {
+        return Box::new(PlainMessage::empty());
+    }
+
+    create_message_from_buffer(bytes).unwrap_or_else(|| Box::new(RawMessage::new(bytes)))
+}
 
 impl fmt::Display for TransferTransaction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -93,7 +270,248 @@ impl fmt::Display for TransferTransaction {
 
 #[cfg(test)]
 pub mod tests {
-    // fn test_should_create_transfer_transaction() {
-    //     todo!()
-    // }
+    use std::str::FromStr;
+
+    use crate::account::tests::TESTING_ACCOUNT;
+    use crate::account::Address;
+    use crate::message::PlainMessage;
+    use crate::mosaic::{Mosaic, MosaicId};
+    use crate::namespace::NamespaceId;
+    use crate::network::NetworkType;
+    use crate::transaction::{Transaction, TransactionType, TransferTransaction};
+    use crate::{Deadline, GenerationHash};
+
+    const RAW_ADDRESS: &str = "TATNE7Q5BITMUTRRN6IB4I7FLSDRDWZA37JGO5Q";
+
+    fn transfer_transaction(mosaics: Vec<Mosaic>) -> TransferTransaction {
+        TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            mosaics,
+            PlainMessage::create("test-message"),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_transfer_transaction() {
+        let transaction = transfer_transaction(vec![]);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::Transfer
+        );
+        assert_eq!(transaction.common.max_fee, 0);
+        assert_eq!(transaction.recipient_to_string(), RAW_ADDRESS);
+        assert_eq!(transaction.message.payload(), "test-message");
+    }
+
+    #[test]
+    fn test_should_return_namespace_id_as_recipient_string() {
+        let namespace_id = NamespaceId::create_from_name("nem").unwrap();
+        let transaction = TransferTransaction::create(
+            Deadline::from(1),
+            namespace_id,
+            vec![],
+            PlainMessage::empty(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(transaction.recipient_to_string(), "84B3552D375FFA4B");
+    }
+
+    #[test]
+    fn test_should_serialize_transfer_transaction() {
+        let mosaic = Mosaic::create(MosaicId::from_hex("85BBEA6CC462B244").unwrap(), 100).unwrap();
+        let transaction = transfer_transaction(vec![mosaic]);
+
+        assert_eq!(transaction.size(), 189);
+        assert_eq!(
+            transaction.serialize(),
+            format!(
+                "BD00000000000000{}{}00000000019854410000000000000000010000000000000098\
+                 26D27E1D0A26CA4E316F901E23E55C8711DB20DFD267760D00010000000000\
+                 44B262C46CEABB85640000000000000000746573742D6D657373616765",
+                "0".repeat(128),
+                "0".repeat(64)
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_sign_transfer_transaction() {
+        let generation_hash = GenerationHash::from_str(
+            "1DFB2FAA9E7F054168B0C5FCB84F4DEB62CC2B4D317D861F3168D161F54EA78B",
+        )
+        .unwrap();
+
+        let transaction = TransferTransaction::create(
+            Deadline::from(34186329840),
+            NamespaceId::create_from_name("nem").unwrap(),
+            vec![
+                Mosaic::create(MosaicId::from_hex("85BBEA6CC462B244").unwrap(), 1000000).unwrap(),
+                Mosaic::create(NamespaceId::create_from_name("symbol.xym").unwrap(), 250).unwrap(),
+                Mosaic::create(MosaicId::from_hex("0DC67FBE1CAD29E3").unwrap(), 3).unwrap(),
+            ],
+            PlainMessage::create("test-message"),
+            NetworkType::TestNet,
+            Some(17600),
+        )
+        .unwrap();
+
+        let signed_transaction = TESTING_ACCOUNT
+            .sign_transaction(transaction.to_bytes(), generation_hash)
+            .unwrap();
+
+        assert_eq!(
+            signed_transaction.payload,
+            "DD00000000000000816FEFC2D198B085FD2D66D7E5FEF92C4225F325596140C4010BCAD1A34B392A\
+             D574B25F48416E224CF24FBBC166E1348151BC4E192E25E2B951BFDEDEBDC50F9801508C58666C74\
+             6F471538E43002B85B1CD542F9874B2861183919BA8787B60000000001985441C044000000000000\
+             F0FEA9F507000000994BFA5F372D55B3840000000000000000000000000000000D00030000000000\
+             E329AD1CBE7FC60D030000000000000044B262C46CEABB8540420F0000000000EEAFF441BA994BE7\
+             FA0000000000000000746573742D6D657373616765"
+        );
+        assert_eq!(
+            format!("{:X}", signed_transaction.hash),
+            "BA7CE883E20C6B48549D50F7C74B1907F248DC20D227865E6384E18CA5A50D8B"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many mosaics 256, a transfer transaction supports at most 255.")]
+    fn test_try_create_transfer_transaction_with_too_many_mosaics() {
+        let mosaic = Mosaic::create(MosaicId::from_hex("85BBEA6CC462B244").unwrap(), 1).unwrap();
+        transfer_transaction(vec![mosaic; 256]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Message size 2049 exceeds the maximum of 2048 bytes.")]
+    fn test_try_create_transfer_transaction_with_too_large_message() {
+        TransferTransaction::create(
+            Deadline::from(1),
+            Address::from_raw(RAW_ADDRESS).unwrap(),
+            vec![],
+            PlainMessage::create(&"z".repeat(2048)),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_should_serialize_namespace_id_as_recipient() {
+        let transaction = TransferTransaction::create(
+            Deadline::from(1),
+            NamespaceId::create_from_name("nem").unwrap(),
+            vec![],
+            PlainMessage::empty(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!("994BFA5F372D55B384{}", "0".repeat(46))
+        );
+    }
+
+    #[test]
+    fn test_should_serialize_mosaics_sorted_by_id() {
+        let transaction = transfer_transaction(vec![
+            Mosaic::create(MosaicId::from_hex("85BBEA6CC462B244").unwrap(), 1).unwrap(),
+            Mosaic::create(MosaicId::from_hex("0DC67FBE1CAD29E3").unwrap(), 2).unwrap(),
+        ]);
+
+        let serialized = transaction.serialize();
+        assert_eq!(
+            &serialized[320..384],
+            "E329AD1CBE7FC60D020000000000000044B262C46CEABB850100000000000000"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_transfer_transaction() {
+        let transaction = transfer_transaction(vec![
+            Mosaic::create(MosaicId::from_hex("85BBEA6CC462B244").unwrap(), 1).unwrap(),
+            Mosaic::create(NamespaceId::create_from_name("nem").unwrap(), 2).unwrap(),
+        ]);
+
+        let payload = transaction.serialize();
+        let deserialized = TransferTransaction::from_payload(&payload).unwrap();
+
+        assert_eq!(deserialized.serialize(), payload);
+        assert_eq!(deserialized.common, transaction.common);
+        assert_eq!(deserialized.recipient_to_string(), RAW_ADDRESS);
+        assert_eq!(deserialized.message.payload(), "test-message");
+        assert_eq!(deserialized.mosaics.len(), 2);
+        assert_eq!(
+            deserialized.mosaics[0].id.to_uint64().to_hex(),
+            "84B3552D375FFA4B"
+        );
+        assert_eq!(
+            deserialized.mosaics[1].id.to_uint64().to_hex(),
+            "85BBEA6CC462B244"
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_namespace_id_recipient() {
+        let transaction = TransferTransaction::create(
+            Deadline::from(1),
+            NamespaceId::create_from_name("nem").unwrap(),
+            vec![],
+            PlainMessage::empty(),
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap();
+
+        let deserialized = TransferTransaction::from_bytes(&transaction.to_bytes()).unwrap();
+
+        assert_eq!(deserialized.recipient_to_string(), "84B3552D375FFA4B");
+        assert_eq!(deserialized.message.payload(), "");
+    }
+
+    #[test]
+    fn test_should_serialize_embedded_transfer_transaction() {
+        let transaction = transfer_transaction(vec![]).to_aggregate(TESTING_ACCOUNT.public_account);
+
+        assert_eq!(
+            transaction.common().signer,
+            Some(TESTING_ACCOUNT.public_account)
+        );
+        assert_eq!(transaction.embedded_size(), 93);
+        assert_eq!(
+            hex::encode_upper(transaction.to_embedded_bytes()),
+            "5D000000000000009801508C58666C746F471538E43002B85B1CD542F9874B28\
+             61183919BA8787B60000000001985441982\
+             6D27E1D0A26CA4E316F901E23E55C8711DB20DFD267760D0000000000000000\
+             746573742D6D657373616765"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid transaction payload size 10, the header requires 128 bytes."
+    )]
+    fn test_should_panic_if_payload_is_truncated() {
+        TransferTransaction::from_bytes(&[0u8; 10]).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid transfer transaction body size 47, expected 45 bytes.")]
+    fn test_should_panic_if_body_has_trailing_bytes() {
+        let mut payload = transfer_transaction(vec![]).to_bytes();
+        payload.extend_from_slice(&[0u8; 2]);
+        let size = payload.len() as u32;
+        payload[..4].copy_from_slice(&size.to_le_bytes());
+
+        TransferTransaction::from_bytes(&payload).unwrap();
+    }
 }
@@ -0,0 +1,348 @@
+/*
+ * // Copyright 2021 BlockPuppets.
+ * //
+ * // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * // https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+ * // <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+ * // option. This file may not be copied, modified, or distributed
+ * // except according to those terms.
+ */
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Cursor, Read};
+
+use anyhow::{anyhow, ensure, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+use crypto::prelude::PublicKey;
+
+use crate::network::{ChainProperties, NetworkType};
+use crate::transaction::common_transaction::CommonTransaction;
+use crate::transaction::{LinkAction, Transaction, TransactionType, TransactionVersion};
+use crate::{hex_decode, is_hex, Deadline};
+
+/// Voting key link transaction struct, links a voting public key to an account
+/// for the finalization epochs it is registered for.
+///
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VotingKeyLinkTransaction {
+    pub common: CommonTransaction,
+    /// The voting public key.
+    pub linked_public_key: PublicKey,
+    /// The first finalization epoch the key is valid for.
+    pub start_epoch: u32,
+    /// The last finalization epoch the key is valid for.
+    pub end_epoch: u32,
+    /// The link action.
+    pub link_action: LinkAction,
+}
+
+impl VotingKeyLinkTransaction {
+    /// The size of the linked public key in bytes.
+    ///
+    const PUBLIC_KEY_SIZE: usize = 32;
+
+    /// The size of the voting key link transaction body in bytes.
+    ///
+    const BODY_SIZE: usize = Self::PUBLIC_KEY_SIZE + 4 + 4 + 1;
+
+    /// Create a voting key link transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `deadline`: The deadline to include the transaction.
+    /// * `linked_public_key`: The voting public key.
+    /// * `start_epoch`: The first finalization epoch the key is valid for.
+    /// * `end_epoch`: The last finalization epoch the key is valid for.
+    /// * `link_action`: The link action.
+    /// * `network_type`: The network type.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `VotingKeyLinkTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn create(
+        deadline: Deadline,
+        linked_public_key: PublicKey,
+        start_epoch: u32,
+        end_epoch: u32,
+        link_action: LinkAction,
+        network_type: NetworkType,
+        max_fee: Option<u64>,
+    ) -> Result<Self> {
+        ensure!(
+            start_epoch <= end_epoch,
+            "Invalid voting key epochs, start epoch {} is after end epoch {}.",
+            start_epoch,
+            end_epoch
+        );
+
+        let common = CommonTransaction::create_from_type(
+            TransactionType::VotingKeyLink,
+            network_type,
+            TransactionVersion::VOTING_KEY_LINK,
+            deadline,
+            max_fee.unwrap_or_default(),
+        );
+
+        Ok(Self {
+            common,
+            linked_public_key,
+            start_epoch,
+            end_epoch,
+            link_action,
+        })
+    }
+
+    /// Validates the voting key lifetime against the network chain properties.
+    ///
+    /// # Inputs
+    ///
+    /// * `properties`: The network `ChainProperties`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is `()` or whose error value
+    /// is an `Error` describing the limit that is exceeded.
+    ///
+    pub fn validate(&self, properties: &ChainProperties) -> Result<()> {
+        let min_lifetime = properties.get_min_voting_key_lifetime()?;
+        let max_lifetime = properties.get_max_voting_key_lifetime()?;
+        let lifetime = self
+            .end_epoch
+            .checked_sub(self.start_epoch)
+            .map(|epochs| u64::from(epochs) + 1)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Invalid voting key epochs, start epoch {} is after end epoch {}.",
+                    self.start_epoch,
+                    self.end_epoch
+                )
+            })?;
+        ensure!(
+            lifetime >= min_lifetime && lifetime <= max_lifetime,
+            "Invalid voting key lifetime {}, expected between {} and {} epochs.",
+            lifetime,
+            min_lifetime,
+            max_lifetime
+        );
+
+        Ok(())
+    }
+
+    /// Creates a `VotingKeyLinkTransaction` from its catbuffer binary layout.
+    ///
+    /// # Inputs
+    ///
+    /// * `payload`: The serialized transaction bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` whose okay value is a `VotingKeyLinkTransaction` or whose error value
+    /// is an `Error` describing the error that occurred.
+    ///
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        let (common, body) = CommonTransaction::from_bytes(payload)?;
+        ensure!(
+            common.transaction_type == TransactionType::VotingKeyLink,
+            "Invalid transaction type {}, expected {}.",
+            common.transaction_type,
+            TransactionType::VotingKeyLink
+        );
+
+        Self::from_body(common, body)
+    }
+
+    /// Creates a `VotingKeyLinkTransaction` from its hex payload.
+    ///
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        ensure!(
+            is_hex(payload),
+            "Input string is not in valid hexadecimal notation."
+        );
+
+        Self::from_bytes(&hex_decode(payload))
+    }
+
+    pub(crate) fn from_body(common: CommonTransaction, body: &[u8]) -> Result<Self> {
+        ensure!(
+            body.len() == Self::BODY_SIZE,
+            "Invalid voting key link transaction body size {}, expected {} bytes.",
+            body.len(),
+            Self::BODY_SIZE
+        );
+
+        let mut cursor = Cursor::new(body);
+        let mut linked_public_key = [0u8; Self::PUBLIC_KEY_SIZE];
+        cursor.read_exact(&mut linked_public_key)?;
+        let start_epoch = cursor.read_u32::<LittleEndian>()?;
+        let end_epoch = cursor.read_u32::<LittleEndian>()?;
+        let link_action = LinkAction::try_from(cursor.read_u8()?)?;
+        ensure!(
+            start_epoch <= end_epoch,
+            "Invalid voting key epochs, start epoch {} is after end epoch {}.",
+            start_epoch,
+            end_epoch
+        );
+
+        Ok(Self {
+            common,
+            linked_public_key: PublicKey::from(linked_public_key),
+            start_epoch,
+            end_epoch,
+            link_action,
+        })
+    }
+}
+
+#[typetag::serde]
+impl Transaction for VotingKeyLinkTransaction {
+    fn common(&self) -> &CommonTransaction {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut CommonTransaction {
+        &mut self.common
+    }
+
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::BODY_SIZE);
+        buf.extend_from_slice(self.linked_public_key.as_bytes());
+        buf.extend_from_slice(&self.start_epoch.to_le_bytes());
+        buf.extend_from_slice(&self.end_epoch.to_le_bytes());
+        buf.extend_from_slice(&self.link_action.to_bytes());
+        buf
+    }
+
+    fn box_clone(&self) -> Box<dyn Transaction + 'static> {
+        Box::new((*self).clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl fmt::Display for VotingKeyLinkTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crypto::prelude::PublicKey;
+
+    use crate::network::{ChainProperties, NetworkType};
+    use crate::transaction::{
+        create_transaction_from_payload, LinkAction, Transaction, TransactionType,
+        VotingKeyLinkTransaction,
+    };
+    use crate::Deadline;
+
+    const PUBLIC_KEY: &str = "9801508C58666C746F471538E43002B85B1CD542F9874B2861183919BA8787B6";
+
+    fn voting_key_link_transaction(start_epoch: u32, end_epoch: u32) -> VotingKeyLinkTransaction {
+        VotingKeyLinkTransaction::create(
+            Deadline::from(1),
+            PublicKey::from_str(PUBLIC_KEY).unwrap(),
+            start_epoch,
+            end_epoch,
+            LinkAction::Link,
+            NetworkType::TestNet,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_should_create_voting_key_link_transaction() {
+        let transaction = voting_key_link_transaction(1, 3);
+
+        assert_eq!(
+            transaction.common.transaction_type,
+            TransactionType::VotingKeyLink
+        );
+        assert_eq!(transaction.size(), 128 + 41);
+        assert_eq!(
+            &transaction.serialize()[256..],
+            format!("{}010000000300000001", PUBLIC_KEY)
+        );
+    }
+
+    #[test]
+    fn test_should_deserialize_voting_key_link_transaction() {
+        let transaction = voting_key_link_transaction(360, 720);
+
+        let mapped = create_transaction_from_payload(&transaction.serialize()).unwrap();
+        let mapped = mapped.downcast_ref::<VotingKeyLinkTransaction>();
+
+        assert_eq!(mapped.start_epoch, 360);
+        assert_eq!(mapped.end_epoch, 720);
+        assert_eq!(mapped.link_action, LinkAction::Link);
+        assert_eq!(mapped.serialize(), transaction.serialize());
+    }
+
+    #[test]
+    fn test_should_validate_against_chain_properties() {
+        let properties: ChainProperties = serde_json::from_str(
+            r#"{"minVotingKeyLifetime": "'112", "maxVotingKeyLifetime": "'360"}"#,
+        )
+        .unwrap();
+
+        assert!(voting_key_link_transaction(1, 360)
+            .validate(&properties)
+            .is_ok());
+        assert_eq!(
+            voting_key_link_transaction(1, 100)
+                .validate(&properties)
+                .unwrap_err()
+                .to_string(),
+            "Invalid voting key lifetime 100, expected between 112 and 360 epochs."
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "start epoch 3 is after end epoch 2")]
+    fn test_should_panic_for_inverted_epochs() {
+        voting_key_link_transaction(3, 2);
+    }
+
+    #[test]
+    fn test_should_reject_inverted_epochs_of_decoded_or_mutated_transaction() {
+        let payload = voting_key_link_transaction(2, 3).serialize();
+        let inverted_payload = format!("{}0300000002000000{}", &payload[..320], &payload[336..]);
+
+        assert_eq!(
+            VotingKeyLinkTransaction::from_payload(&inverted_payload)
+                .unwrap_err()
+                .to_string(),
+            "Invalid voting key epochs, start epoch 3 is after end epoch 2."
+        );
+
+        let properties: ChainProperties = serde_json::from_str(
+            r#"{"minVotingKeyLifetime": "'112", "maxVotingKeyLifetime": "'360"}"#,
+        )
+        .unwrap();
+        let mut transaction = voting_key_link_transaction(2, 3);
+        transaction.start_epoch = 4;
+
+        assert_eq!(
+            transaction.validate(&properties).unwrap_err().to_string(),
+            "Invalid voting key epochs, start epoch 4 is after end epoch 3."
+        );
+    }
+}